@@ -19,470 +19,4865 @@ abstract class RustCore {
 
   FlutterRustBridgeTaskConstMeta get kRustInitializeVaultConstMeta;
 
-  Future<SatyaIdentity> rustCreateIdentity(
-      {required String label, dynamic hint});
+  /// Unlocks an existing vault with any enrolled combination of factors
+  /// (PIN, recovery code, key file; the hardware binding is implicit).
+  Future<bool> rustUnlockVault(
+      {required String storagePath,
+      required String hwId,
+      String? pin,
+      String? recoveryCode,
+      String? keyFilePath,
+      dynamic hint});
 
-  FlutterRustBridgeTaskConstMeta get kRustCreateIdentityConstMeta;
+  FlutterRustBridgeTaskConstMeta get kRustUnlockVaultConstMeta;
 
-  Future<List<SatyaIdentity>> rustGetIdentities({dynamic hint});
+  /// Creates a vault on a new device from a 24-word mnemonic backup. The seed is
+  /// identical, so identities re-created in the same order get the same SLIP-0010
+  /// keys. New identities never use the pre-11.1 `legacy/i` scheme: those come
+  /// back through `rust_discover_identities`, which also scans legacy paths.
+  /// `mask_passphrase` is the one given to `rust_export_mnemonic`.
+  Future<bool> rustRestoreFromMnemonic(
+      {required String mnemonic,
+      String? maskPassphrase,
+      required String pin,
+      required String hwId,
+      required String storagePath,
+      dynamic hint});
 
-  FlutterRustBridgeTaskConstMeta get kRustGetIdentitiesConstMeta;
+  FlutterRustBridgeTaskConstMeta get kRustRestoreFromMnemonicConstMeta;
 
-  Future<String> rustScanQr({required String rawQrString, dynamic hint});
+  /// The relay set stored in the vault (the defaults until one is configured).
+  Future<List<RelayConfig>> rustListRelays({dynamic hint});
 
-  FlutterRustBridgeTaskConstMeta get kRustScanQrConstMeta;
+  FlutterRustBridgeTaskConstMeta get kRustListRelaysConstMeta;
 
-  Future<String> rustSignIntent(
-      {required String identityId, required String upiUrl, dynamic hint});
+  /// Adds a relay, or updates its read/write flags if it is already present.
+  Future<bool> rustAddRelay(
+      {required String url,
+      required bool read,
+      required bool write,
+      dynamic hint});
 
-  FlutterRustBridgeTaskConstMeta get kRustSignIntentConstMeta;
+  FlutterRustBridgeTaskConstMeta get kRustAddRelayConstMeta;
 
-  Future<bool> rustPublishToNostr({required String signedJson, dynamic hint});
+  Future<bool> rustRemoveRelay({required String url, dynamic hint});
 
-  FlutterRustBridgeTaskConstMeta get kRustPublishToNostrConstMeta;
+  FlutterRustBridgeTaskConstMeta get kRustRemoveRelayConstMeta;
 
-  Future<List<String>> rustFetchInteractionHistory({dynamic hint});
+  /// Connection state, latency, last error and write-set membership per relay.
+  Future<List<RelayHealth>> rustGetRelayStatus({dynamic hint});
 
-  FlutterRustBridgeTaskConstMeta get kRustFetchInteractionHistoryConstMeta;
+  FlutterRustBridgeTaskConstMeta get kRustGetRelayStatusConstMeta;
 
-  Future<bool> rustResetVault({required String storagePath, dynamic hint});
+  /// Approves `identity_id` to answer `url`'s NIP-42 AUTH challenges, or
+  /// withdraws the approval with `None`. A pending challenge is answered right
+  /// away; on withdrawal an authenticated connection is dropped so the relay
+  /// forgets the identity.
+  Future<bool> rustSetRelayAuth(
+      {required String url, String? identityId, dynamic hint});
 
-  FlutterRustBridgeTaskConstMeta get kRustResetVaultConstMeta;
-}
+  FlutterRustBridgeTaskConstMeta get kRustSetRelayAuthConstMeta;
 
-class SatyaIdentity {
-  final String id;
-  final String label;
-  final String did;
+  /// Identities approved for relay authentication, one per relay at most.
+  Future<List<RelayAuthGrant>> rustListRelayAuth({dynamic hint});
 
-  const SatyaIdentity({
-    required this.id,
-    required this.label,
-    required this.did,
-  });
-}
+  FlutterRustBridgeTaskConstMeta get kRustListRelayAuthConstMeta;
 
-class RustCoreImpl implements RustCore {
-  final RustCorePlatform _platform;
-  factory RustCoreImpl(ExternalLibrary dylib) =>
-      RustCoreImpl.raw(RustCorePlatform(dylib));
+  /// Starts the embedded relay on `bind_address` (`127.0.0.1:0` picks a free
+  /// port, `0.0.0.0:<port>` serves the LAN). With `storage_path` its events
+  /// are kept in `satya_relay/events.jsonl` there, otherwise only in memory.
+  /// Returns the relay URL; `rust_add_relay` puts it in the relay set.
+  Future<String> rustStartLocalRelay(
+      {required String bindAddress, String? storagePath, dynamic hint});
 
-  /// Only valid on web/WASM platforms.
-  factory RustCoreImpl.wasm(FutureOr<WasmModule> module) =>
-      RustCoreImpl(module as ExternalLibrary);
-  RustCoreImpl.raw(this._platform);
-  Future<bool> rustInitializeVault(
-      {required String pin,
-      required String hwId,
-      required String storagePath,
-      dynamic hint}) {
-    var arg0 = _platform.api2wire_String(pin);
-    var arg1 = _platform.api2wire_String(hwId);
-    var arg2 = _platform.api2wire_String(storagePath);
-    return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) =>
-          _platform.inner.wire_rust_initialize_vault(port_, arg0, arg1, arg2),
-      parseSuccessData: _wire2api_bool,
-      parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kRustInitializeVaultConstMeta,
-      argValues: [pin, hwId, storagePath],
-      hint: hint,
-    ));
-  }
+  FlutterRustBridgeTaskConstMeta get kRustStartLocalRelayConstMeta;
 
-  FlutterRustBridgeTaskConstMeta get kRustInitializeVaultConstMeta =>
-      const FlutterRustBridgeTaskConstMeta(
-        debugName: "rust_initialize_vault",
-        argNames: ["pin", "hwId", "storagePath"],
-      );
+  /// Stops the embedded relay. Returns whether one was running.
+  Future<bool> rustStopLocalRelay({dynamic hint});
 
-  Future<SatyaIdentity> rustCreateIdentity(
-      {required String label, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(label);
-    return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) =>
-          _platform.inner.wire_rust_create_identity(port_, arg0),
-      parseSuccessData: _wire2api_satya_identity,
-      parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kRustCreateIdentityConstMeta,
-      argValues: [label],
-      hint: hint,
-    ));
-  }
+  FlutterRustBridgeTaskConstMeta get kRustStopLocalRelayConstMeta;
 
-  FlutterRustBridgeTaskConstMeta get kRustCreateIdentityConstMeta =>
-      const FlutterRustBridgeTaskConstMeta(
-        debugName: "rust_create_identity",
-        argNames: ["label"],
-      );
+  /// Issues a new printable recovery code and enrolls it (with the PIN) as a
+  /// re-binding slot. Any previously issued code stops working.
+  Future<String> rustGenerateRecoveryCode({required String pin, dynamic hint});
 
-  Future<List<SatyaIdentity>> rustGetIdentities({dynamic hint}) {
-    return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_rust_get_identities(port_),
-      parseSuccessData: _wire2api_list_satya_identity,
-      parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kRustGetIdentitiesConstMeta,
-      argValues: [],
-      hint: hint,
-    ));
-  }
+  FlutterRustBridgeTaskConstMeta get kRustGenerateRecoveryCodeConstMeta;
 
-  FlutterRustBridgeTaskConstMeta get kRustGetIdentitiesConstMeta =>
-      const FlutterRustBridgeTaskConstMeta(
-        debugName: "rust_get_identities",
-        argNames: [],
-      );
+  /// Enrolls new unlock factors (`pin`, `recovery_code`, `key_file`, `hardware`).
+  /// With `require_all` the factors form one combined slot, otherwise each
+  /// factor opens the vault on its own. Returns the recovery code if one was issued.
+  Future<String?> rustEnrollUnlockFactors(
+      {required String pin,
+      required List<String> factors,
+      required bool requireAll,
+      String? keyFilePath,
+      dynamic hint});
 
-  Future<String> rustScanQr({required String rawQrString, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(rawQrString);
-    return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_rust_scan_qr(port_, arg0),
-      parseSuccessData: _wire2api_String,
-      parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kRustScanQrConstMeta,
-      argValues: [rawQrString],
-      hint: hint,
-    ));
-  }
+  FlutterRustBridgeTaskConstMeta get kRustEnrollUnlockFactorsConstMeta;
 
-  FlutterRustBridgeTaskConstMeta get kRustScanQrConstMeta =>
-      const FlutterRustBridgeTaskConstMeta(
-        debugName: "rust_scan_qr",
-        argNames: ["rawQrString"],
-      );
+  /// Removes every key slot that depends on `factor`.
+  Future<bool> rustRevokeUnlockFactor(
+      {required String pin,
+      required String factor,
+      String? keyFilePath,
+      dynamic hint});
 
-  Future<String> rustSignIntent(
-      {required String identityId, required String upiUrl, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(identityId);
-    var arg1 = _platform.api2wire_String(upiUrl);
-    return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) =>
-          _platform.inner.wire_rust_sign_intent(port_, arg0, arg1),
-      parseSuccessData: _wire2api_String,
-      parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kRustSignIntentConstMeta,
-      argValues: [identityId, upiUrl],
-      hint: hint,
-    ));
-  }
+  FlutterRustBridgeTaskConstMeta get kRustRevokeUnlockFactorConstMeta;
 
-  FlutterRustBridgeTaskConstMeta get kRustSignIntentConstMeta =>
-      const FlutterRustBridgeTaskConstMeta(
-        debugName: "rust_sign_intent",
-        argNames: ["identityId", "upiUrl"],
-      );
+  /// Lists the factor combinations able to open the vault, e.g. `pin+hardware`.
+  Future<List<String>> rustListUnlockFactors({dynamic hint});
 
-  Future<bool> rustPublishToNostr({required String signedJson, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(signedJson);
-    return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) =>
-          _platform.inner.wire_rust_publish_to_nostr(port_, arg0),
-      parseSuccessData: _wire2api_bool,
-      parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kRustPublishToNostrConstMeta,
-      argValues: [signedJson],
-      hint: hint,
-    ));
-  }
+  FlutterRustBridgeTaskConstMeta get kRustListUnlockFactorsConstMeta;
 
-  FlutterRustBridgeTaskConstMeta get kRustPublishToNostrConstMeta =>
-      const FlutterRustBridgeTaskConstMeta(
-        debugName: "rust_publish_to_nostr",
-        argNames: ["signedJson"],
-      );
+  /// Changes the vault PIN. The old PIN is verified, the new one is derived with
+  /// fresh salt and KDF parameters, and the current session is closed: the
+  /// caller must unlock again with the new PIN. Every slot that includes the
+  /// PIN is re-wrapped, so slots that also need a recovery code or key file
+  /// require those too.
+  Future<bool> rustChangePin(
+      {required String oldPin,
+      required String newPin,
+      String? recoveryCode,
+      String? keyFilePath,
+      dynamic hint});
 
-  Future<List<String>> rustFetchInteractionHistory({dynamic hint}) {
-    return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) =>
-          _platform.inner.wire_rust_fetch_interaction_history(port_),
-      parseSuccessData: _wire2api_StringList,
-      parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kRustFetchInteractionHistoryConstMeta,
-      argValues: [],
-      hint: hint,
-    ));
-  }
+  FlutterRustBridgeTaskConstMeta get kRustChangePinConstMeta;
 
-  FlutterRustBridgeTaskConstMeta get kRustFetchInteractionHistoryConstMeta =>
-      const FlutterRustBridgeTaskConstMeta(
-        debugName: "rust_fetch_interaction_history",
-        argNames: [],
-      );
+  /// Configures a duress PIN that opens a separate decoy vault through the
+  /// normal unlock path. Replaces any existing decoy.
+  Future<bool> rustConfigureDuressPin(
+      {required String pin, required String duressPin, dynamic hint});
 
-  Future<bool> rustResetVault({required String storagePath, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(storagePath);
-    return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_rust_reset_vault(port_, arg0),
-      parseSuccessData: _wire2api_bool,
-      parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kRustResetVaultConstMeta,
-      argValues: [storagePath],
-      hint: hint,
-    ));
-  }
+  FlutterRustBridgeTaskConstMeta get kRustConfigureDuressPinConstMeta;
 
-  FlutterRustBridgeTaskConstMeta get kRustResetVaultConstMeta =>
-      const FlutterRustBridgeTaskConstMeta(
-        debugName: "rust_reset_vault",
-        argNames: ["storagePath"],
-      );
+  /// Removes the decoy vault; the shadow region is refilled with noise.
+  Future<bool> rustClearDuressPin({required String pin, dynamic hint});
 
-  void dispose() {
-    _platform.dispose();
-  }
-// Section: wire2api
+  FlutterRustBridgeTaskConstMeta get kRustClearDuressPinConstMeta;
 
-  FrbAnyhowException _wire2api_FrbAnyhowException(dynamic raw) {
-    return FrbAnyhowException(raw as String);
-  }
+  /// Exports the master seed as a 24-word BIP39 mnemonic, optionally masked
+  /// with a passphrase that must also be supplied on restore. The mask is
+  /// Satya's own scheme, not a BIP39 passphrase: other wallets cannot restore
+  /// masked words.
+  Future<String> rustExportMnemonic(
+      {required String pin, String? maskPassphrase, dynamic hint});
 
-  String _wire2api_String(dynamic raw) {
-    return raw as String;
-  }
+  FlutterRustBridgeTaskConstMeta get kRustExportMnemonicConstMeta;
 
-  List<String> _wire2api_StringList(dynamic raw) {
-    return (raw as List<dynamic>).cast<String>();
-  }
+  /// Confirms a user-typed mnemonic (and mask passphrase) matches the vault seed.
+  Future<bool> rustVerifyMnemonic(
+      {required String mnemonic, String? maskPassphrase, dynamic hint});
 
-  bool _wire2api_bool(dynamic raw) {
-    return raw as bool;
-  }
+  FlutterRustBridgeTaskConstMeta get kRustVerifyMnemonicConstMeta;
 
-  List<SatyaIdentity> _wire2api_list_satya_identity(dynamic raw) {
-    return (raw as List<dynamic>).map(_wire2api_satya_identity).toList();
-  }
+  /// Failed attempts and remaining backoff, for the lock screen.
+  Future<UnlockStatus> rustGetUnlockStatus(
+      {required String storagePath, required String hwId, dynamic hint});
 
-  SatyaIdentity _wire2api_satya_identity(dynamic raw) {
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return SatyaIdentity(
-      id: _wire2api_String(arr[0]),
-      label: _wire2api_String(arr[1]),
-      did: _wire2api_String(arr[2]),
-    );
-  }
+  FlutterRustBridgeTaskConstMeta get kRustGetUnlockStatusConstMeta;
 
-  int _wire2api_u8(dynamic raw) {
-    return raw as int;
-  }
+  /// Crypto-erases the vault after `limit` consecutive failures; `None` disables.
+  Future<bool> rustSetWipeAfter(
+      {required String pin, int? limit, dynamic hint});
 
-  Uint8List _wire2api_uint_8_list(dynamic raw) {
-    return raw as Uint8List;
-  }
-}
+  FlutterRustBridgeTaskConstMeta get kRustSetWipeAfterConstMeta;
 
-// Section: api2wire
+  /// Switches the open vault to another hardware binding strategy:
+  /// `none`, `device_id`, `key_file` (needs `key_file_path`) or `keystore`.
+  Future<bool> rustSetHardwareBinding(
+      {required String pin,
+      required String strategy,
+      String? keyFilePath,
+      dynamic hint});
 
-@protected
-int api2wire_u8(int raw) {
-  return raw;
-}
+  FlutterRustBridgeTaskConstMeta get kRustSetHardwareBindingConstMeta;
 
-// Section: finalizer
+  /// Re-binds a vault whose hardware binding no longer matches (new device id,
+  /// lost key file) using the PIN plus recovery code, then unlocks it.
+  Future<bool> rustRebindDevice(
+      {required String pin,
+      required String recoveryCode,
+      required String hwId,
+      required String storagePath,
+      required String strategy,
+      String? keyFilePath,
+      dynamic hint});
 
-class RustCorePlatform extends FlutterRustBridgeBase<RustCoreWire> {
-  RustCorePlatform(ffi.DynamicLibrary dylib) : super(RustCoreWire(dylib));
+  FlutterRustBridgeTaskConstMeta get kRustRebindDeviceConstMeta;
 
-// Section: api2wire
+  Future<SatyaIdentity> rustCreateIdentity(
+      {required String label, dynamic hint});
 
-  @protected
-  ffi.Pointer<wire_uint_8_list> api2wire_String(String raw) {
-    return api2wire_uint_8_list(utf8.encoder.convert(raw));
-  }
+  FlutterRustBridgeTaskConstMeta get kRustCreateIdentityConstMeta;
 
-  @protected
-  ffi.Pointer<wire_uint_8_list> api2wire_uint_8_list(Uint8List raw) {
-    final ans = inner.new_uint_8_list_0(raw.length);
-    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
-    return ans;
-  }
-// Section: finalizer
+  /// (Re-)publishes the encrypted profile of an identity so that a seed
+  /// restore on another device can rediscover it and its label.
+  Future<bool> rustPublishIdentityProfile(
+      {required String identityId, dynamic hint});
 
-// Section: api_fill_to_wire
-}
+  FlutterRustBridgeTaskConstMeta get kRustPublishIdentityProfileConstMeta;
 
-// ignore_for_file: camel_case_types, non_constant_identifier_names, avoid_positional_boolean_parameters, annotate_overrides, constant_identifier_names
+  /// Scans derivation indices (up to `gap_limit` empty ones in a row, default
+  /// 20) against the relays and adds every identity whose profile is found.
+  /// Intended right after `rust_restore_from_mnemonic`. Returns the new identities.
+  Future<List<SatyaIdentity>> rustDiscoverIdentities(
+      {int? gapLimit, dynamic hint});
 
-// AUTO GENERATED FILE, DO NOT EDIT.
-//
+  FlutterRustBridgeTaskConstMeta get kRustDiscoverIdentitiesConstMeta;
+
+  /// This vault's guardian public key (`npub`), given to friends who want the
+  /// owner to hold a share of their seed.
+  Future<String> rustGetGuardianPubkey({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustGetGuardianPubkeyConstMeta;
+
+  /// Splits the master seed into `threshold`-of-n shares, one per guardian
+  /// public key. Each share is sealed to its guardian, sent as a DM (best
+  /// effort) and returned for export in guardian order.
+  Future<List<String>> rustCreateSocialRecovery(
+      {required String pin,
+      required List<String> guardians,
+      required int threshold,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustCreateSocialRecoveryConstMeta;
+
+  /// Share envelopes other users have entrusted to this vault, read from relays.
+  Future<List<String>> rustFetchGuardianShares({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustFetchGuardianSharesConstMeta;
+
+  /// Guardian side: opens a held share and re-seals it to the recovering
+  /// device's `requester_pubkey`. The result is also sent as a DM.
+  Future<String> rustReleaseGuardianShare(
+      {required String pin,
+      required String envelope,
+      required String requesterPubkey,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustReleaseGuardianShareConstMeta;
+
+  /// Recovering device: starts a recovery and returns the public key to give
+  /// to guardians. Valid until the app restarts.
+  Future<String> rustBeginSocialRecovery({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustBeginSocialRecoveryConstMeta;
+
+  /// Released share envelopes that guardians have sent to this device so far.
+  Future<List<String>> rustCollectRecoveryShares({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustCollectRecoverySharesConstMeta;
+
+  /// Rebuilds the vault from released shares (DM'd, pasted or scanned).
+  Future<bool> rustRecoverFromShares(
+      {required List<String> envelopes,
+      required String pin,
+      required String hwId,
+      required String storagePath,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustRecoverFromSharesConstMeta;
+
+  /// Renames an identity and refreshes its discovery profile.
+  Future<SatyaIdentity> rustRenameIdentity(
+      {required String identityId, required String label, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustRenameIdentityConstMeta;
+
+  /// Hides an identity from everyday use. Its key is kept; it cannot sign
+  /// intents until unarchived.
+  Future<SatyaIdentity> rustArchiveIdentity(
+      {required String identityId, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustArchiveIdentityConstMeta;
+
+  Future<SatyaIdentity> rustUnarchiveIdentity(
+      {required String identityId, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustUnarchiveIdentityConstMeta;
+
+  /// Deletes an identity: its key is wiped and a tombstone keeps its
+  /// derivation path and DID reserved. The discovery profile is replaced by a
+  /// retired one, so a seed restore does not bring it back. With
+  /// `publish_retirement`, a retirement statement signed by the identity's
+  /// key is published first. Returns the statement JSON, if any.
+  Future<String?> rustDeleteIdentity(
+      {required String pin,
+      required String identityId,
+      required bool publishRetirement,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustDeleteIdentityConstMeta;
+
+  /// Deleted identities kept as tombstones.
+  Future<List<IdentityTombstone>> rustGetTombstones({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustGetTombstonesConstMeta;
+
+  /// Replaces a (possibly compromised) identity key with a freshly derived
+  /// one. The DID stays the same; a rotation statement signed by both keys is
+  /// published and the key history recorded. Returns the statement JSON.
+  Future<String> rustRotateIdentityKey(
+      {required String pin, required String identityId, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustRotateIdentityKeyConstMeta;
+
+  /// Every key the identity has signed with, oldest first.
+  Future<List<KeyRecord>> rustGetKeyHistory(
+      {required String identityId, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustGetKeyHistoryConstMeta;
+
+  /// Revokes keys of an identity (`public_keys` as hex; empty for none) and/or
+  /// the whole identity, effective from `since` (default: now). The list is
+  /// signed with the identity key and its seed-derived recovery key, cached
+  /// and published. Returns the list JSON.
+  Future<String> rustPublishRevocationList(
+      {required String pin,
+      required String identityId,
+      required List<String> publicKeys,
+      required bool revokeIdentity,
+      int? since,
+      required String reason,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustPublishRevocationListConstMeta;
+
+  /// Cached revocation state of any DID.
+  Future<RevocationState> rustGetRevocationStatus(
+      {required String did, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustGetRevocationStatusConstMeta;
+
+  /// Verifies a signed intent JSON (e.g. scanned from a customer) against the
+  /// signer's published rotations and revocations. A foreign did:satya only
+  /// verifies once one of its keys is pinned (`rust_pin_did_key`).
+  Future<bool> rustVerifyIntent({required String signedJson, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustVerifyIntentConstMeta;
+
+  /// The identity's W3C DID Document as JSON.
+  Future<String> rustGetDidDocument({required String identityId, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustGetDidDocumentConstMeta;
+
+  /// Publishes the identity's DID Document to the relays (replaceable, `d`-tagged with the DID).
+  Future<bool> rustPublishDidDocument(
+      {required String identityId, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustPublishDidDocumentConstMeta;
+
+  /// Resolves any supported DID (did:satya, did:key, did:web) to its document JSON.
+  /// Without a network client only local sources are consulted.
+  Future<String> rustResolveDid({required String did, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustResolveDidConstMeta;
+
+  /// Pins `public_key_hex` as a key of the foreign did:satya `did`, e.g. read
+  /// from its DID Document in person. Its published rotations are trusted
+  /// from that key on; without a pin the DID's intents do not verify.
+  Future<bool> rustPinDidKey(
+      {required String did, required String publicKeyHex, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustPinDidKeyConstMeta;
+
+  Future<bool> rustUnpinDidKey({required String did, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustUnpinDidKeyConstMeta;
+
+  Future<List<PinnedKey>> rustListPinnedKeys({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustListPinnedKeysConstMeta;
+
+  Future<List<SatyaIdentity>> rustGetIdentities({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustGetIdentitiesConstMeta;
+
+  Future<String> rustScanQr({required String rawQrString, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustScanQrConstMeta;
+
+  /// Collects a scanned (or typed) SLIP-39 paper share for
+  /// `rust_restore_from_paper_shares` and reports the restore progress.
+  Future<ShareScan> rustScanPaperShare(
+      {required String rawQrString, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustScanPaperShareConstMeta;
+
+  /// Exports the master seed as `threshold`-of-`count` SLIP-39 shares, each
+  /// with a printable QR code. The optional passphrase is needed on restore.
+  Future<List<PaperShare>> rustExportPaperShares(
+      {required String pin,
+      required int threshold,
+      required int count,
+      String? passphrase,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustExportPaperSharesConstMeta;
+
+  /// Restores a vault from SLIP-39 shares: the typed `shares` plus any
+  /// collected through `rust_scan_paper_share`.
+  Future<bool> rustRestoreFromPaperShares(
+      {required List<String> shares,
+      String? passphrase,
+      required String pin,
+      required String hwId,
+      required String storagePath,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustRestoreFromPaperSharesConstMeta;
+
+  Future<String> rustSignIntent(
+      {required String identityId, required String upiUrl, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustSignIntentConstMeta;
+
+  /// Like `rust_sign_intent`, but signs as the identity's pairwise DID for the
+  /// payee VPA, so payments to different merchants cannot be correlated.
+  Future<String> rustSignPairwiseIntent(
+      {required String identityId, required String upiUrl, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustSignPairwiseIntentConstMeta;
+
+  /// The pairwise DID an identity uses with `counterparty` (VPA or DID),
+  /// derived and remembered on first use.
+  Future<PairwiseLink> rustGetPairwiseDid(
+      {required String identityId, required String counterparty, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustGetPairwiseDidConstMeta;
+
+  /// Every pairwise DID handed out by an identity.
+  Future<List<PairwiseLink>> rustListPairwiseDids(
+      {required String identityId, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustListPairwiseDidsConstMeta;
+
+  /// Proves to `counterparty` that its pairwise DID belongs to the parent
+  /// identity. Only issued on request; returns the proof JSON.
+  Future<String> rustProveLinkage(
+      {required String pin,
+      required String identityId,
+      required String counterparty,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustProveLinkageConstMeta;
+
+  /// Verifies a linkage proof against the parent DID's key history and revocations.
+  Future<bool> rustVerifyLinkage({required String proofJson, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustVerifyLinkageConstMeta;
+
+  /// Publishes a signed intent; true only if enough relays acknowledged it
+  /// under the publish policy. `rust_publish_intent` has the per-relay detail.
+  Future<bool> rustPublishToNostr({required String signedJson, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustPublishToNostrConstMeta;
+
+  /// Publishes a signed intent to the write relays and reports each relay's
+  /// answer. Intents of own identities (and their pairwise DIDs) are authored
+  /// by that identity's Nostr key; foreign ones by the session key.
+  Future<PublishResult> rustPublishIntent(
+      {required String signedJson, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustPublishIntentConstMeta;
+
+  /// Sends a signed intent of an own identity (or pairwise DID) only to
+  /// `recipient` (npub or hex Nostr key): sealed with NIP-44 and gift wrapped
+  /// per NIP-59, so relays see neither the content nor the sender.
+  Future<PublishResult> rustPublishPrivateIntent(
+      {required String signedJson, required String recipient, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustPublishPrivateIntentConstMeta;
+
+  /// Gift-wrapped intents addressed to the identity or its pairwise DIDs,
+  /// newest first. Wraps that do not open are skipped; `intent.is_verified`
+  /// reports the signer check done after decryption.
+  Future<List<PrivateIntent>> rustFetchPrivateIntents(
+      {required String identityId, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustFetchPrivateIntentsConstMeta;
+
+  /// Opens and verifies a gift wrap event JSON received out of band.
+  Future<PrivateIntent> rustOpenPrivateIntent(
+      {required String identityId, required String wrapJson, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustOpenPrivateIntentConstMeta;
+
+  /// Publishes only a salted hash commitment to the intent's payload, as
+  /// timestamped proof it existed. The salt and intent stay in the local
+  /// ledger for `rust_open_commitment`.
+  Future<PublishResult> rustPublishIntentCommitment(
+      {required String signedJson, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustPublishIntentCommitmentConstMeta;
+
+  /// Commitments published from this vault, oldest first.
+  Future<List<LedgerEntry>> rustListCommitments({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustListCommitmentsConstMeta;
+
+  /// The opening JSON for a published commitment, to hand to a verifier.
+  Future<String> rustOpenCommitment({required String eventId, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustOpenCommitmentConstMeta;
+
+  /// Verifier side: fetches the commitment event, checks the opening against
+  /// it and that the event was published by the intent's signer, then verifies
+  /// that signer against its DID.
+  Future<VerifiedOpening> rustVerifyCommitmentOpening(
+      {required String openingJson, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustVerifyCommitmentOpeningConstMeta;
+
+  /// Events waiting in the outbox, oldest first.
+  Future<List<OutboxItem>> rustGetOutbox({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustGetOutboxConstMeta;
+
+  /// Retries every queued event now, ignoring backoff. Returns how many were published.
+  Future<int> rustRetryOutbox({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustRetryOutboxConstMeta;
+
+  /// Gives up on a queued event.
+  Future<bool> rustDropOutboxItem({required String eventId, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustDropOutboxItemConstMeta;
+
+  /// Sets how many relay acknowledgments a publish needs to count as a success
+  /// (default: at least one). `policy` is `majority`, `all` or `at_least:<n>`.
+  /// Applies for the rest of the app session.
+  Future<bool> rustSetPublishPolicy({required String policy, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustSetPublishPolicyConstMeta;
+
+  /// The npub an identity publishes its intents under. Derived from the
+  /// master seed, so it is the same on every device and after a restore.
+  Future<String> rustGetNostrPublicKey(
+      {required String identityId, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustGetNostrPublicKeyConstMeta;
+
+  /// The latest page of everyone's intents, verified; see
+  /// `rust_query_interaction_history` for filters and paging.
+  Future<List<String>> rustFetchInteractionHistory({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustFetchInteractionHistoryConstMeta;
+
+  /// Fetches one page of intents, newest first, merged and deduplicated across
+  /// relays. Each is verified against the key its signer held at the intent's
+  /// timestamp and the signer's revocations before the filters apply.
+  Future<HistoryPage> rustQueryInteractionHistory(
+      {required HistoryQuery query, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustQueryInteractionHistoryConstMeta;
+
+  /// Streams verified Satya events as they arrive: own intents as relays store
+  /// them, intents addressed to own identities, and rotations and revocations
+  /// of own DIDs plus `watch_dids`. The first message carries the handle.
+  /// Relays resubscribe on reconnect; the stream ends on unsubscribe.
+  Stream<String> rustSubscribeEvents(
+      {required List<String> watchDids, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustSubscribeEventsConstMeta;
+
+  /// Ends a live subscription and closes its stream.
+  Future<bool> rustUnsubscribeEvents({required String handle, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustUnsubscribeEventsConstMeta;
+
+  Future<bool> rustResetVault({required String storagePath, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRustResetVaultConstMeta;
+}
+
+class HistoryItem {
+  final String eventId;
+  final int createdAt;
+  /// Relays the event was received from.
+  final List<String> seenOn;
+  final SignedIntent intent;
+
+  const HistoryItem({
+    required this.eventId,
+    required this.createdAt,
+    required this.seenOn,
+    required this.intent,
+  });
+}
+
+class HistoryPage {
+  final List<HistoryItem> items;
+  /// Pass back in `HistoryQuery::cursor` for the next page; `None` at the end.
+  final String? nextCursor;
+
+  const HistoryPage({
+    required this.items,
+    this.nextCursor,
+  });
+}
+
+/// What to fetch. Every filter is optional; the default is the latest page
+/// of everyone's intents.
+class HistoryQuery {
+  /// Only intents signed by these own identities or their pairwise DIDs.
+  final List<String> identityIds;
+  /// Counterparty DID (matched against the signer) or VPA (the payee).
+  final String? counterparty;
+  /// Bounds on the intent timestamp, inclusive.
+  final int? since;
+  final int? until;
+  final InteractionType? interactionType;
+  final bool? verified;
+  final int? pageSize;
+  /// `next_cursor` of the previous page.
+  final String? cursor;
+  final int? timeoutSecs;
+
+  const HistoryQuery({
+    required this.identityIds,
+    this.counterparty,
+    this.since,
+    this.until,
+    this.interactionType,
+    this.verified,
+    this.pageSize,
+    this.cursor,
+    this.timeoutSecs,
+  });
+}
+
+enum IdentityStatus {
+  Active,
+  /// Hidden from everyday use; keys are kept and it can be restored.
+  Archived,
+}
+
+/// What remains of a deleted identity: enough to never hand out its
+/// derivation path or DID again. The key itself is gone.
+class IdentityTombstone {
+  final String id;
+  final String did;
+  final String derivationPath;
+  final int deletedAt;
+
+  const IdentityTombstone({
+    required this.id,
+    required this.did,
+    required this.derivationPath,
+    required this.deletedAt,
+  });
+}
+
+class IntentPayload {
+  final String version;
+  final InteractionType interactionType;
+  final int timestamp;
+  final UpiIntent upiData;
+
+  const IntentPayload({
+    required this.version,
+    required this.interactionType,
+    required this.timestamp,
+    required this.upiData,
+  });
+}
+
+enum InteractionType {
+  PaymentIntent,
+  IdentityVerification,
+}
+
+/// One key an identity has signed with, and when.
+class KeyRecord {
+  final String publicKeyHex;
+  /// Own keys only; empty for histories rebuilt from public statements.
+  final String derivationPath;
+  final int validFrom;
+  /// Set once the key has been rotated away.
+  final int? validUntil;
+
+  const KeyRecord({
+    required this.publicKeyHex,
+    required this.derivationPath,
+    required this.validFrom,
+    this.validUntil,
+  });
+}
+
+/// A commitment this device published, with what is needed to open it.
+class LedgerEntry {
+  final String eventId;
+  final String commitmentHex;
+  final String saltHex;
+  final SignedIntent intent;
+  final int committedAt;
+  final String authorBindingHex;
+
+  const LedgerEntry({
+    required this.eventId,
+    required this.commitmentHex,
+    required this.saltHex,
+    required this.intent,
+    required this.committedAt,
+    required this.authorBindingHex,
+  });
+}
+
+class OutboxItem {
+  final String eventId;
+  final int kind;
+  /// The signed Nostr event, republished unchanged.
+  final String eventJson;
+  final int queuedAt;
+  final int attempts;
+  final int nextAttemptAt;
+  final String? lastError;
+
+  const OutboxItem({
+    required this.eventId,
+    required this.kind,
+    required this.eventJson,
+    required this.queuedAt,
+    required this.attempts,
+    required this.nextAttemptAt,
+    this.lastError,
+  });
+}
+
+/// A per-counterparty did:peer identity derived below a parent identity.
+class PairwiseLink {
+  final String identityId;
+  /// Normalized VPA or DID of the counterparty.
+  final String counterparty;
+  final String did;
+  final String derivationPath;
+  final int createdAt;
+
+  const PairwiseLink({
+    required this.identityId,
+    required this.counterparty,
+    required this.did,
+    required this.derivationPath,
+    required this.createdAt,
+  });
+}
+
+/// One printable share: the words and an SVG QR code of the same words.
+class PaperShare {
+  final int index;
+  final String mnemonic;
+  final String qrSvg;
+
+  const PaperShare({
+    required this.index,
+    required this.mnemonic,
+    required this.qrSvg,
+  });
+}
+
+/// A key the user vouched for as belonging to a foreign did:satya, e.g. read
+/// from its DID Document in person. Rotation chains are trusted from it.
+class PinnedKey {
+  final String did;
+  final String publicKeyHex;
+
+  const PinnedKey({
+    required this.did,
+    required this.publicKeyHex,
+  });
+}
+
+/// An intent received through a gift wrap.
+class PrivateIntent {
+  /// Id of the gift wrap, the only event relays saw.
+  final String eventId;
+  /// Sender's Nostr public key (hex), authenticated by the seal signature
+  /// and bound to the intent's signer key.
+  final String senderPublicKey;
+  /// When the sender created the message; the wrap's own timestamp is randomized.
+  final int sentAt;
+  final SignedIntent intent;
+
+  const PrivateIntent({
+    required this.eventId,
+    required this.senderPublicKey,
+    required this.sentAt,
+    required this.intent,
+  });
+}
+
+class PublishResult {
+  final String eventId;
+  final int acknowledgments;
+  /// Whether the acknowledgments satisfy the publish policy.
+  final bool success;
+  final List<RelayOutcome> outcomes;
+  /// Set when the event went to the outbox for a later retry.
+  final bool queued;
+
+  const PublishResult({
+    required this.eventId,
+    required this.acknowledgments,
+    required this.success,
+    required this.outcomes,
+    required this.queued,
+  });
+}
+
+enum PublishStatus {
+  Ok,
+  /// The relay already had the event; counts as an acknowledgment.
+  Duplicate,
+  Blocked,
+  RateLimited,
+  Invalid,
+  /// The relay wants NIP-42 AUTH first and no approved identity has answered.
+  AuthRequired,
+  /// Refused for any other reason.
+  Rejected,
+  /// No `OK` within the publish timeout.
+  Timeout,
+  /// The event could not be sent at all.
+  Failed,
+}
+
+/// An identity the user approved to answer a relay's NIP-42 AUTH challenges.
+class RelayAuthGrant {
+  final String url;
+  final String identityId;
+
+  const RelayAuthGrant({
+    required this.url,
+    required this.identityId,
+  });
+}
+
+/// One relay of the user's relay set.
+class RelayConfig {
+  final String url;
+  final bool read;
+  final bool write;
+
+  const RelayConfig({
+    required this.url,
+    required this.read,
+    required this.write,
+  });
+}
+
+/// Health of one configured relay, as reported to the app.
+class RelayHealth {
+  final String url;
+  final bool read;
+  final bool write;
+  final bool connected;
+  final int? latencyMs;
+  final String? lastError;
+  final int consecutiveFailures;
+  /// Unix time of the next reconnect attempt while disconnected.
+  final int nextRetryAt;
+  /// False for write relays demoted after repeated failures.
+  final bool inWriteSet;
+  /// The relay sent a NIP-42 challenge on the current connection.
+  final bool authRequested;
+  /// Identity id the connection is authenticated as.
+  final String? authenticatedAs;
+
+  const RelayHealth({
+    required this.url,
+    required this.read,
+    required this.write,
+    required this.connected,
+    this.latencyMs,
+    this.lastError,
+    required this.consecutiveFailures,
+    required this.nextRetryAt,
+    required this.inWriteSet,
+    required this.authRequested,
+    this.authenticatedAs,
+  });
+}
+
+class RelayOutcome {
+  final String url;
+  final PublishStatus status;
+  /// The relay's `OK` message, or the local error.
+  final String message;
+
+  const RelayOutcome({
+    required this.url,
+    required this.status,
+    required this.message,
+  });
+}
+
+/// What is known to be revoked for one DID.
+class RevocationState {
+  final String did;
+  /// Pinned from the first accepted list; may sign later lists on its own.
+  final String recoveryPublicKeyHex;
+  final List<RevokedKey> revokedKeys;
+  final int? identityRevokedAt;
+
+  const RevocationState({
+    required this.did,
+    required this.recoveryPublicKeyHex,
+    required this.revokedKeys,
+    this.identityRevokedAt,
+  });
+}
+
+class RevokedKey {
+  final String publicKeyHex;
+  /// Signatures made at or after this time are rejected.
+  final int revokedAt;
+  final String reason;
+
+  const RevokedKey({
+    required this.publicKeyHex,
+    required this.revokedAt,
+    required this.reason,
+  });
+}
+
+class SatyaIdentity {
+  final String id;
+  final String label;
+  final String did;
+  /// Persisted derivation path, e.g. `m/44'/7327'/0'` (or `legacy/0`).
+  final String derivationPath;
+  final IdentityStatus status;
+
+  const SatyaIdentity({
+    required this.id,
+    required this.label,
+    required this.did,
+    required this.derivationPath,
+    required this.status,
+  });
+}
+
+/// What a scanned share contributes towards a restore.
+class ShareScan {
+  final int collected;
+  final int required;
+
+  const ShareScan({
+    required this.collected,
+    required this.required,
+  });
+}
+
+class SignedIntent {
+  final IntentPayload payload;
+  final String signatureHex;
+  final String signerDid;
+  /// Key that produced `signature_hex`; checked against the DID's key history.
+  final String signerPublicKeyHex;
+  final bool isVerified;
+
+  const SignedIntent({
+    required this.payload,
+    required this.signatureHex,
+    required this.signerDid,
+    required this.signerPublicKeyHex,
+    required this.isVerified,
+  });
+}
+
+/// Snapshot of the brute-force guard for the lock screen.
+class UnlockStatus {
+  final int failedAttempts;
+  final int retryAfterSecs;
+  /// Failures left before a crypto-erase, if one is configured.
+  final int? attemptsBeforeWipe;
+
+  const UnlockStatus({
+    required this.failedAttempts,
+    required this.retryAfterSecs,
+    this.attemptsBeforeWipe,
+  });
+}
+
+class UpiIntent {
+  final String vpa;
+  final String name;
+  final String amount;
+  final String currency;
+
+  const UpiIntent({
+    required this.vpa,
+    required this.name,
+    required this.amount,
+    required this.currency,
+  });
+}
+
+/// What a verifier learns from an opening that matches a commitment
+/// published by the intent's signer. `intent.is_verified` reports whether
+/// that signer key belongs to the signer's DID.
+class VerifiedOpening {
+  final String eventId;
+  /// `created_at` of the commitment event. Asserted by its author, not by
+  /// the relays: it dates the intent only as far as the signer is trusted.
+  final int committedAt;
+  final SignedIntent intent;
+
+  const VerifiedOpening({
+    required this.eventId,
+    required this.committedAt,
+    required this.intent,
+  });
+}
+
+class RustCoreImpl implements RustCore {
+  final RustCorePlatform _platform;
+  factory RustCoreImpl(ExternalLibrary dylib) =>
+      RustCoreImpl.raw(RustCorePlatform(dylib));
+
+  /// Only valid on web/WASM platforms.
+  factory RustCoreImpl.wasm(FutureOr<WasmModule> module) =>
+      RustCoreImpl(module as ExternalLibrary);
+  RustCoreImpl.raw(this._platform);
+  Future<bool> rustInitializeVault(
+      {required String pin,
+      required String hwId,
+      required String storagePath,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(pin);
+    var arg1 = _platform.api2wire_String(hwId);
+    var arg2 = _platform.api2wire_String(storagePath);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_initialize_vault(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_bool,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustInitializeVaultConstMeta,
+      argValues: [pin, hwId, storagePath],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustInitializeVaultConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_initialize_vault",
+        argNames: ["pin", "hwId", "storagePath"],
+      );
+
+  Future<bool> rustUnlockVault(
+      {required String storagePath,
+      required String hwId,
+      String? pin,
+      String? recoveryCode,
+      String? keyFilePath,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(storagePath);
+    var arg1 = _platform.api2wire_String(hwId);
+    var arg2 = _platform.api2wire_opt_String(pin);
+    var arg3 = _platform.api2wire_opt_String(recoveryCode);
+    var arg4 = _platform.api2wire_opt_String(keyFilePath);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rust_unlock_vault(
+          port_, arg0, arg1, arg2, arg3, arg4),
+      parseSuccessData: _wire2api_bool,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustUnlockVaultConstMeta,
+      argValues: [storagePath, hwId, pin, recoveryCode, keyFilePath],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustUnlockVaultConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_unlock_vault",
+        argNames: ["storagePath", "hwId", "pin", "recoveryCode", "keyFilePath"],
+      );
+
+  Future<bool> rustRestoreFromMnemonic(
+      {required String mnemonic,
+      String? maskPassphrase,
+      required String pin,
+      required String hwId,
+      required String storagePath,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(mnemonic);
+    var arg1 = _platform.api2wire_opt_String(maskPassphrase);
+    var arg2 = _platform.api2wire_String(pin);
+    var arg3 = _platform.api2wire_String(hwId);
+    var arg4 = _platform.api2wire_String(storagePath);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rust_restore_from_mnemonic(
+          port_, arg0, arg1, arg2, arg3, arg4),
+      parseSuccessData: _wire2api_bool,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustRestoreFromMnemonicConstMeta,
+      argValues: [mnemonic, maskPassphrase, pin, hwId, storagePath],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustRestoreFromMnemonicConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_restore_from_mnemonic",
+        argNames: ["mnemonic", "maskPassphrase", "pin", "hwId", "storagePath"],
+      );
+
+  Future<List<RelayConfig>> rustListRelays({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rust_list_relays(port_),
+      parseSuccessData: _wire2api_list_relay_config,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustListRelaysConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustListRelaysConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_list_relays",
+        argNames: [],
+      );
+
+  Future<bool> rustAddRelay(
+      {required String url,
+      required bool read,
+      required bool write,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(url);
+    var arg1 = api2wire_bool(read);
+    var arg2 = api2wire_bool(write);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_add_relay(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_bool,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustAddRelayConstMeta,
+      argValues: [url, read, write],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustAddRelayConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_add_relay",
+        argNames: ["url", "read", "write"],
+      );
+
+  Future<bool> rustRemoveRelay({required String url, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(url);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rust_remove_relay(port_, arg0),
+      parseSuccessData: _wire2api_bool,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustRemoveRelayConstMeta,
+      argValues: [url],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustRemoveRelayConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_remove_relay",
+        argNames: ["url"],
+      );
+
+  Future<List<RelayHealth>> rustGetRelayStatus({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rust_get_relay_status(port_),
+      parseSuccessData: _wire2api_list_relay_health,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustGetRelayStatusConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustGetRelayStatusConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_get_relay_status",
+        argNames: [],
+      );
+
+  Future<bool> rustSetRelayAuth(
+      {required String url, String? identityId, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(url);
+    var arg1 = _platform.api2wire_opt_String(identityId);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_set_relay_auth(port_, arg0, arg1),
+      parseSuccessData: _wire2api_bool,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustSetRelayAuthConstMeta,
+      argValues: [url, identityId],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustSetRelayAuthConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_set_relay_auth",
+        argNames: ["url", "identityId"],
+      );
+
+  Future<List<RelayAuthGrant>> rustListRelayAuth({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rust_list_relay_auth(port_),
+      parseSuccessData: _wire2api_list_relay_auth_grant,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustListRelayAuthConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustListRelayAuthConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_list_relay_auth",
+        argNames: [],
+      );
+
+  Future<String> rustStartLocalRelay(
+      {required String bindAddress, String? storagePath, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(bindAddress);
+    var arg1 = _platform.api2wire_opt_String(storagePath);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_start_local_relay(port_, arg0, arg1),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustStartLocalRelayConstMeta,
+      argValues: [bindAddress, storagePath],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustStartLocalRelayConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_start_local_relay",
+        argNames: ["bindAddress", "storagePath"],
+      );
+
+  Future<bool> rustStopLocalRelay({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rust_stop_local_relay(port_),
+      parseSuccessData: _wire2api_bool,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustStopLocalRelayConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustStopLocalRelayConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_stop_local_relay",
+        argNames: [],
+      );
+
+  Future<String> rustGenerateRecoveryCode({required String pin, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(pin);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_generate_recovery_code(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustGenerateRecoveryCodeConstMeta,
+      argValues: [pin],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustGenerateRecoveryCodeConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_generate_recovery_code",
+        argNames: ["pin"],
+      );
+
+  Future<String?> rustEnrollUnlockFactors(
+      {required String pin,
+      required List<String> factors,
+      required bool requireAll,
+      String? keyFilePath,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(pin);
+    var arg1 = _platform.api2wire_StringList(factors);
+    var arg2 = api2wire_bool(requireAll);
+    var arg3 = _platform.api2wire_opt_String(keyFilePath);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rust_enroll_unlock_factors(
+          port_, arg0, arg1, arg2, arg3),
+      parseSuccessData: _wire2api_opt_String,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustEnrollUnlockFactorsConstMeta,
+      argValues: [pin, factors, requireAll, keyFilePath],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustEnrollUnlockFactorsConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_enroll_unlock_factors",
+        argNames: ["pin", "factors", "requireAll", "keyFilePath"],
+      );
+
+  Future<bool> rustRevokeUnlockFactor(
+      {required String pin,
+      required String factor,
+      String? keyFilePath,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(pin);
+    var arg1 = _platform.api2wire_String(factor);
+    var arg2 = _platform.api2wire_opt_String(keyFilePath);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rust_revoke_unlock_factor(
+          port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_bool,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustRevokeUnlockFactorConstMeta,
+      argValues: [pin, factor, keyFilePath],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustRevokeUnlockFactorConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_revoke_unlock_factor",
+        argNames: ["pin", "factor", "keyFilePath"],
+      );
+
+  Future<List<String>> rustListUnlockFactors({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rust_list_unlock_factors(port_),
+      parseSuccessData: _wire2api_StringList,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustListUnlockFactorsConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustListUnlockFactorsConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_list_unlock_factors",
+        argNames: [],
+      );
+
+  Future<bool> rustChangePin(
+      {required String oldPin,
+      required String newPin,
+      String? recoveryCode,
+      String? keyFilePath,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(oldPin);
+    var arg1 = _platform.api2wire_String(newPin);
+    var arg2 = _platform.api2wire_opt_String(recoveryCode);
+    var arg3 = _platform.api2wire_opt_String(keyFilePath);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_change_pin(port_, arg0, arg1, arg2, arg3),
+      parseSuccessData: _wire2api_bool,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustChangePinConstMeta,
+      argValues: [oldPin, newPin, recoveryCode, keyFilePath],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustChangePinConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_change_pin",
+        argNames: ["oldPin", "newPin", "recoveryCode", "keyFilePath"],
+      );
+
+  Future<bool> rustConfigureDuressPin(
+      {required String pin, required String duressPin, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(pin);
+    var arg1 = _platform.api2wire_String(duressPin);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_configure_duress_pin(port_, arg0, arg1),
+      parseSuccessData: _wire2api_bool,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustConfigureDuressPinConstMeta,
+      argValues: [pin, duressPin],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustConfigureDuressPinConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_configure_duress_pin",
+        argNames: ["pin", "duressPin"],
+      );
+
+  Future<bool> rustClearDuressPin({required String pin, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(pin);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_clear_duress_pin(port_, arg0),
+      parseSuccessData: _wire2api_bool,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustClearDuressPinConstMeta,
+      argValues: [pin],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustClearDuressPinConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_clear_duress_pin",
+        argNames: ["pin"],
+      );
+
+  Future<String> rustExportMnemonic(
+      {required String pin, String? maskPassphrase, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(pin);
+    var arg1 = _platform.api2wire_opt_String(maskPassphrase);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_export_mnemonic(port_, arg0, arg1),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustExportMnemonicConstMeta,
+      argValues: [pin, maskPassphrase],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustExportMnemonicConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_export_mnemonic",
+        argNames: ["pin", "maskPassphrase"],
+      );
+
+  Future<bool> rustVerifyMnemonic(
+      {required String mnemonic, String? maskPassphrase, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(mnemonic);
+    var arg1 = _platform.api2wire_opt_String(maskPassphrase);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_verify_mnemonic(port_, arg0, arg1),
+      parseSuccessData: _wire2api_bool,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustVerifyMnemonicConstMeta,
+      argValues: [mnemonic, maskPassphrase],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustVerifyMnemonicConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_verify_mnemonic",
+        argNames: ["mnemonic", "maskPassphrase"],
+      );
+
+  Future<UnlockStatus> rustGetUnlockStatus(
+      {required String storagePath, required String hwId, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(storagePath);
+    var arg1 = _platform.api2wire_String(hwId);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_get_unlock_status(port_, arg0, arg1),
+      parseSuccessData: _wire2api_unlock_status,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustGetUnlockStatusConstMeta,
+      argValues: [storagePath, hwId],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustGetUnlockStatusConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_get_unlock_status",
+        argNames: ["storagePath", "hwId"],
+      );
+
+  Future<bool> rustSetWipeAfter(
+      {required String pin, int? limit, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(pin);
+    var arg1 = _platform.api2wire_opt_box_autoadd_u32(limit);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_set_wipe_after(port_, arg0, arg1),
+      parseSuccessData: _wire2api_bool,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustSetWipeAfterConstMeta,
+      argValues: [pin, limit],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustSetWipeAfterConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_set_wipe_after",
+        argNames: ["pin", "limit"],
+      );
+
+  Future<bool> rustSetHardwareBinding(
+      {required String pin,
+      required String strategy,
+      String? keyFilePath,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(pin);
+    var arg1 = _platform.api2wire_String(strategy);
+    var arg2 = _platform.api2wire_opt_String(keyFilePath);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rust_set_hardware_binding(
+          port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_bool,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustSetHardwareBindingConstMeta,
+      argValues: [pin, strategy, keyFilePath],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustSetHardwareBindingConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_set_hardware_binding",
+        argNames: ["pin", "strategy", "keyFilePath"],
+      );
+
+  Future<bool> rustRebindDevice(
+      {required String pin,
+      required String recoveryCode,
+      required String hwId,
+      required String storagePath,
+      required String strategy,
+      String? keyFilePath,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(pin);
+    var arg1 = _platform.api2wire_String(recoveryCode);
+    var arg2 = _platform.api2wire_String(hwId);
+    var arg3 = _platform.api2wire_String(storagePath);
+    var arg4 = _platform.api2wire_String(strategy);
+    var arg5 = _platform.api2wire_opt_String(keyFilePath);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rust_rebind_device(
+          port_, arg0, arg1, arg2, arg3, arg4, arg5),
+      parseSuccessData: _wire2api_bool,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustRebindDeviceConstMeta,
+      argValues: [pin, recoveryCode, hwId, storagePath, strategy, keyFilePath],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustRebindDeviceConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_rebind_device",
+        argNames: [
+          "pin",
+          "recoveryCode",
+          "hwId",
+          "storagePath",
+          "strategy",
+          "keyFilePath",
+        ],
+      );
+
+  Future<SatyaIdentity> rustCreateIdentity(
+      {required String label, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(label);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_create_identity(port_, arg0),
+      parseSuccessData: _wire2api_satya_identity,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustCreateIdentityConstMeta,
+      argValues: [label],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustCreateIdentityConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_create_identity",
+        argNames: ["label"],
+      );
+
+  Future<bool> rustPublishIdentityProfile(
+      {required String identityId, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(identityId);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_publish_identity_profile(port_, arg0),
+      parseSuccessData: _wire2api_bool,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustPublishIdentityProfileConstMeta,
+      argValues: [identityId],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustPublishIdentityProfileConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_publish_identity_profile",
+        argNames: ["identityId"],
+      );
+
+  Future<List<SatyaIdentity>> rustDiscoverIdentities(
+      {int? gapLimit, dynamic hint}) {
+    var arg0 = _platform.api2wire_opt_box_autoadd_u32(gapLimit);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_discover_identities(port_, arg0),
+      parseSuccessData: _wire2api_list_satya_identity,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustDiscoverIdentitiesConstMeta,
+      argValues: [gapLimit],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustDiscoverIdentitiesConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_discover_identities",
+        argNames: ["gapLimit"],
+      );
+
+  Future<String> rustGetGuardianPubkey({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rust_get_guardian_pubkey(port_),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustGetGuardianPubkeyConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustGetGuardianPubkeyConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_get_guardian_pubkey",
+        argNames: [],
+      );
+
+  Future<List<String>> rustCreateSocialRecovery(
+      {required String pin,
+      required List<String> guardians,
+      required int threshold,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(pin);
+    var arg1 = _platform.api2wire_StringList(guardians);
+    var arg2 = api2wire_u32(threshold);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rust_create_social_recovery(
+          port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_StringList,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustCreateSocialRecoveryConstMeta,
+      argValues: [pin, guardians, threshold],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustCreateSocialRecoveryConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_create_social_recovery",
+        argNames: ["pin", "guardians", "threshold"],
+      );
+
+  Future<List<String>> rustFetchGuardianShares({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_fetch_guardian_shares(port_),
+      parseSuccessData: _wire2api_StringList,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustFetchGuardianSharesConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustFetchGuardianSharesConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_fetch_guardian_shares",
+        argNames: [],
+      );
+
+  Future<String> rustReleaseGuardianShare(
+      {required String pin,
+      required String envelope,
+      required String requesterPubkey,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(pin);
+    var arg1 = _platform.api2wire_String(envelope);
+    var arg2 = _platform.api2wire_String(requesterPubkey);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rust_release_guardian_share(
+          port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustReleaseGuardianShareConstMeta,
+      argValues: [pin, envelope, requesterPubkey],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustReleaseGuardianShareConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_release_guardian_share",
+        argNames: ["pin", "envelope", "requesterPubkey"],
+      );
+
+  Future<String> rustBeginSocialRecovery({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_begin_social_recovery(port_),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustBeginSocialRecoveryConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustBeginSocialRecoveryConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_begin_social_recovery",
+        argNames: [],
+      );
+
+  Future<List<String>> rustCollectRecoveryShares({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_collect_recovery_shares(port_),
+      parseSuccessData: _wire2api_StringList,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustCollectRecoverySharesConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustCollectRecoverySharesConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_collect_recovery_shares",
+        argNames: [],
+      );
+
+  Future<bool> rustRecoverFromShares(
+      {required List<String> envelopes,
+      required String pin,
+      required String hwId,
+      required String storagePath,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_StringList(envelopes);
+    var arg1 = _platform.api2wire_String(pin);
+    var arg2 = _platform.api2wire_String(hwId);
+    var arg3 = _platform.api2wire_String(storagePath);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rust_recover_from_shares(
+          port_, arg0, arg1, arg2, arg3),
+      parseSuccessData: _wire2api_bool,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustRecoverFromSharesConstMeta,
+      argValues: [envelopes, pin, hwId, storagePath],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustRecoverFromSharesConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_recover_from_shares",
+        argNames: ["envelopes", "pin", "hwId", "storagePath"],
+      );
+
+  Future<SatyaIdentity> rustRenameIdentity(
+      {required String identityId, required String label, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(identityId);
+    var arg1 = _platform.api2wire_String(label);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_rename_identity(port_, arg0, arg1),
+      parseSuccessData: _wire2api_satya_identity,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustRenameIdentityConstMeta,
+      argValues: [identityId, label],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustRenameIdentityConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_rename_identity",
+        argNames: ["identityId", "label"],
+      );
+
+  Future<SatyaIdentity> rustArchiveIdentity(
+      {required String identityId, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(identityId);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_archive_identity(port_, arg0),
+      parseSuccessData: _wire2api_satya_identity,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustArchiveIdentityConstMeta,
+      argValues: [identityId],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustArchiveIdentityConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_archive_identity",
+        argNames: ["identityId"],
+      );
+
+  Future<SatyaIdentity> rustUnarchiveIdentity(
+      {required String identityId, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(identityId);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_unarchive_identity(port_, arg0),
+      parseSuccessData: _wire2api_satya_identity,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustUnarchiveIdentityConstMeta,
+      argValues: [identityId],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustUnarchiveIdentityConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_unarchive_identity",
+        argNames: ["identityId"],
+      );
+
+  Future<String?> rustDeleteIdentity(
+      {required String pin,
+      required String identityId,
+      required bool publishRetirement,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(pin);
+    var arg1 = _platform.api2wire_String(identityId);
+    var arg2 = api2wire_bool(publishRetirement);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_delete_identity(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_opt_String,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustDeleteIdentityConstMeta,
+      argValues: [pin, identityId, publishRetirement],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustDeleteIdentityConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_delete_identity",
+        argNames: ["pin", "identityId", "publishRetirement"],
+      );
+
+  Future<List<IdentityTombstone>> rustGetTombstones({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rust_get_tombstones(port_),
+      parseSuccessData: _wire2api_list_identity_tombstone,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustGetTombstonesConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustGetTombstonesConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_get_tombstones",
+        argNames: [],
+      );
+
+  Future<String> rustRotateIdentityKey(
+      {required String pin, required String identityId, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(pin);
+    var arg1 = _platform.api2wire_String(identityId);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_rotate_identity_key(port_, arg0, arg1),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustRotateIdentityKeyConstMeta,
+      argValues: [pin, identityId],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustRotateIdentityKeyConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_rotate_identity_key",
+        argNames: ["pin", "identityId"],
+      );
+
+  Future<List<KeyRecord>> rustGetKeyHistory(
+      {required String identityId, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(identityId);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_get_key_history(port_, arg0),
+      parseSuccessData: _wire2api_list_key_record,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustGetKeyHistoryConstMeta,
+      argValues: [identityId],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustGetKeyHistoryConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_get_key_history",
+        argNames: ["identityId"],
+      );
+
+  Future<String> rustPublishRevocationList(
+      {required String pin,
+      required String identityId,
+      required List<String> publicKeys,
+      required bool revokeIdentity,
+      int? since,
+      required String reason,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(pin);
+    var arg1 = _platform.api2wire_String(identityId);
+    var arg2 = _platform.api2wire_StringList(publicKeys);
+    var arg3 = api2wire_bool(revokeIdentity);
+    var arg4 = _platform.api2wire_opt_box_autoadd_u64(since);
+    var arg5 = _platform.api2wire_String(reason);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rust_publish_revocation_list(
+          port_, arg0, arg1, arg2, arg3, arg4, arg5),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustPublishRevocationListConstMeta,
+      argValues: [pin, identityId, publicKeys, revokeIdentity, since, reason],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustPublishRevocationListConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_publish_revocation_list",
+        argNames: [
+          "pin",
+          "identityId",
+          "publicKeys",
+          "revokeIdentity",
+          "since",
+          "reason",
+        ],
+      );
+
+  Future<RevocationState> rustGetRevocationStatus(
+      {required String did, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(did);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_get_revocation_status(port_, arg0),
+      parseSuccessData: _wire2api_revocation_state,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustGetRevocationStatusConstMeta,
+      argValues: [did],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustGetRevocationStatusConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_get_revocation_status",
+        argNames: ["did"],
+      );
+
+  Future<bool> rustVerifyIntent({required String signedJson, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(signedJson);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rust_verify_intent(port_, arg0),
+      parseSuccessData: _wire2api_bool,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustVerifyIntentConstMeta,
+      argValues: [signedJson],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustVerifyIntentConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_verify_intent",
+        argNames: ["signedJson"],
+      );
+
+  Future<String> rustGetDidDocument(
+      {required String identityId, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(identityId);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_get_did_document(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustGetDidDocumentConstMeta,
+      argValues: [identityId],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustGetDidDocumentConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_get_did_document",
+        argNames: ["identityId"],
+      );
+
+  Future<bool> rustPublishDidDocument(
+      {required String identityId, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(identityId);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_publish_did_document(port_, arg0),
+      parseSuccessData: _wire2api_bool,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustPublishDidDocumentConstMeta,
+      argValues: [identityId],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustPublishDidDocumentConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_publish_did_document",
+        argNames: ["identityId"],
+      );
+
+  Future<String> rustResolveDid({required String did, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(did);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rust_resolve_did(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustResolveDidConstMeta,
+      argValues: [did],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustResolveDidConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_resolve_did",
+        argNames: ["did"],
+      );
+
+  Future<bool> rustPinDidKey(
+      {required String did, required String publicKeyHex, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(did);
+    var arg1 = _platform.api2wire_String(publicKeyHex);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_pin_did_key(port_, arg0, arg1),
+      parseSuccessData: _wire2api_bool,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustPinDidKeyConstMeta,
+      argValues: [did, publicKeyHex],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustPinDidKeyConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_pin_did_key",
+        argNames: ["did", "publicKeyHex"],
+      );
+
+  Future<bool> rustUnpinDidKey({required String did, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(did);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rust_unpin_did_key(port_, arg0),
+      parseSuccessData: _wire2api_bool,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustUnpinDidKeyConstMeta,
+      argValues: [did],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustUnpinDidKeyConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_unpin_did_key",
+        argNames: ["did"],
+      );
+
+  Future<List<PinnedKey>> rustListPinnedKeys({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rust_list_pinned_keys(port_),
+      parseSuccessData: _wire2api_list_pinned_key,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustListPinnedKeysConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustListPinnedKeysConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_list_pinned_keys",
+        argNames: [],
+      );
+
+  Future<List<SatyaIdentity>> rustGetIdentities({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rust_get_identities(port_),
+      parseSuccessData: _wire2api_list_satya_identity,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustGetIdentitiesConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustGetIdentitiesConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_get_identities",
+        argNames: [],
+      );
+
+  Future<String> rustScanQr({required String rawQrString, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(rawQrString);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rust_scan_qr(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustScanQrConstMeta,
+      argValues: [rawQrString],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustScanQrConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_scan_qr",
+        argNames: ["rawQrString"],
+      );
+
+  Future<ShareScan> rustScanPaperShare(
+      {required String rawQrString, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(rawQrString);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_scan_paper_share(port_, arg0),
+      parseSuccessData: _wire2api_share_scan,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustScanPaperShareConstMeta,
+      argValues: [rawQrString],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustScanPaperShareConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_scan_paper_share",
+        argNames: ["rawQrString"],
+      );
+
+  Future<List<PaperShare>> rustExportPaperShares(
+      {required String pin,
+      required int threshold,
+      required int count,
+      String? passphrase,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(pin);
+    var arg1 = api2wire_u32(threshold);
+    var arg2 = api2wire_u32(count);
+    var arg3 = _platform.api2wire_opt_String(passphrase);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rust_export_paper_shares(
+          port_, arg0, arg1, arg2, arg3),
+      parseSuccessData: _wire2api_list_paper_share,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustExportPaperSharesConstMeta,
+      argValues: [pin, threshold, count, passphrase],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustExportPaperSharesConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_export_paper_shares",
+        argNames: ["pin", "threshold", "count", "passphrase"],
+      );
+
+  Future<bool> rustRestoreFromPaperShares(
+      {required List<String> shares,
+      String? passphrase,
+      required String pin,
+      required String hwId,
+      required String storagePath,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_StringList(shares);
+    var arg1 = _platform.api2wire_opt_String(passphrase);
+    var arg2 = _platform.api2wire_String(pin);
+    var arg3 = _platform.api2wire_String(hwId);
+    var arg4 = _platform.api2wire_String(storagePath);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rust_restore_from_paper_shares(
+          port_, arg0, arg1, arg2, arg3, arg4),
+      parseSuccessData: _wire2api_bool,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustRestoreFromPaperSharesConstMeta,
+      argValues: [shares, passphrase, pin, hwId, storagePath],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustRestoreFromPaperSharesConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_restore_from_paper_shares",
+        argNames: ["shares", "passphrase", "pin", "hwId", "storagePath"],
+      );
+
+  Future<String> rustSignIntent(
+      {required String identityId, required String upiUrl, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(identityId);
+    var arg1 = _platform.api2wire_String(upiUrl);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_sign_intent(port_, arg0, arg1),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustSignIntentConstMeta,
+      argValues: [identityId, upiUrl],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustSignIntentConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_sign_intent",
+        argNames: ["identityId", "upiUrl"],
+      );
+
+  Future<String> rustSignPairwiseIntent(
+      {required String identityId, required String upiUrl, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(identityId);
+    var arg1 = _platform.api2wire_String(upiUrl);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_sign_pairwise_intent(port_, arg0, arg1),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustSignPairwiseIntentConstMeta,
+      argValues: [identityId, upiUrl],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustSignPairwiseIntentConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_sign_pairwise_intent",
+        argNames: ["identityId", "upiUrl"],
+      );
+
+  Future<PairwiseLink> rustGetPairwiseDid(
+      {required String identityId,
+      required String counterparty,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(identityId);
+    var arg1 = _platform.api2wire_String(counterparty);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_get_pairwise_did(port_, arg0, arg1),
+      parseSuccessData: _wire2api_pairwise_link,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustGetPairwiseDidConstMeta,
+      argValues: [identityId, counterparty],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustGetPairwiseDidConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_get_pairwise_did",
+        argNames: ["identityId", "counterparty"],
+      );
+
+  Future<List<PairwiseLink>> rustListPairwiseDids(
+      {required String identityId, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(identityId);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_list_pairwise_dids(port_, arg0),
+      parseSuccessData: _wire2api_list_pairwise_link,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustListPairwiseDidsConstMeta,
+      argValues: [identityId],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustListPairwiseDidsConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_list_pairwise_dids",
+        argNames: ["identityId"],
+      );
+
+  Future<String> rustProveLinkage(
+      {required String pin,
+      required String identityId,
+      required String counterparty,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(pin);
+    var arg1 = _platform.api2wire_String(identityId);
+    var arg2 = _platform.api2wire_String(counterparty);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_prove_linkage(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustProveLinkageConstMeta,
+      argValues: [pin, identityId, counterparty],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustProveLinkageConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_prove_linkage",
+        argNames: ["pin", "identityId", "counterparty"],
+      );
+
+  Future<bool> rustVerifyLinkage({required String proofJson, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(proofJson);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rust_verify_linkage(port_, arg0),
+      parseSuccessData: _wire2api_bool,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustVerifyLinkageConstMeta,
+      argValues: [proofJson],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustVerifyLinkageConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_verify_linkage",
+        argNames: ["proofJson"],
+      );
+
+  Future<bool> rustPublishToNostr({required String signedJson, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(signedJson);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_publish_to_nostr(port_, arg0),
+      parseSuccessData: _wire2api_bool,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustPublishToNostrConstMeta,
+      argValues: [signedJson],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustPublishToNostrConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_publish_to_nostr",
+        argNames: ["signedJson"],
+      );
+
+  Future<PublishResult> rustPublishIntent(
+      {required String signedJson, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(signedJson);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rust_publish_intent(port_, arg0),
+      parseSuccessData: _wire2api_publish_result,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustPublishIntentConstMeta,
+      argValues: [signedJson],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustPublishIntentConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_publish_intent",
+        argNames: ["signedJson"],
+      );
+
+  Future<PublishResult> rustPublishPrivateIntent(
+      {required String signedJson, required String recipient, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(signedJson);
+    var arg1 = _platform.api2wire_String(recipient);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_publish_private_intent(port_, arg0, arg1),
+      parseSuccessData: _wire2api_publish_result,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustPublishPrivateIntentConstMeta,
+      argValues: [signedJson, recipient],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustPublishPrivateIntentConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_publish_private_intent",
+        argNames: ["signedJson", "recipient"],
+      );
+
+  Future<List<PrivateIntent>> rustFetchPrivateIntents(
+      {required String identityId, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(identityId);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_fetch_private_intents(port_, arg0),
+      parseSuccessData: _wire2api_list_private_intent,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustFetchPrivateIntentsConstMeta,
+      argValues: [identityId],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustFetchPrivateIntentsConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_fetch_private_intents",
+        argNames: ["identityId"],
+      );
+
+  Future<PrivateIntent> rustOpenPrivateIntent(
+      {required String identityId, required String wrapJson, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(identityId);
+    var arg1 = _platform.api2wire_String(wrapJson);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_open_private_intent(port_, arg0, arg1),
+      parseSuccessData: _wire2api_private_intent,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustOpenPrivateIntentConstMeta,
+      argValues: [identityId, wrapJson],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustOpenPrivateIntentConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_open_private_intent",
+        argNames: ["identityId", "wrapJson"],
+      );
+
+  Future<PublishResult> rustPublishIntentCommitment(
+      {required String signedJson, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(signedJson);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_publish_intent_commitment(port_, arg0),
+      parseSuccessData: _wire2api_publish_result,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustPublishIntentCommitmentConstMeta,
+      argValues: [signedJson],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustPublishIntentCommitmentConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_publish_intent_commitment",
+        argNames: ["signedJson"],
+      );
+
+  Future<List<LedgerEntry>> rustListCommitments({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rust_list_commitments(port_),
+      parseSuccessData: _wire2api_list_ledger_entry,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustListCommitmentsConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustListCommitmentsConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_list_commitments",
+        argNames: [],
+      );
+
+  Future<String> rustOpenCommitment({required String eventId, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(eventId);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_open_commitment(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustOpenCommitmentConstMeta,
+      argValues: [eventId],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustOpenCommitmentConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_open_commitment",
+        argNames: ["eventId"],
+      );
+
+  Future<VerifiedOpening> rustVerifyCommitmentOpening(
+      {required String openingJson, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(openingJson);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_verify_commitment_opening(port_, arg0),
+      parseSuccessData: _wire2api_verified_opening,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustVerifyCommitmentOpeningConstMeta,
+      argValues: [openingJson],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustVerifyCommitmentOpeningConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_verify_commitment_opening",
+        argNames: ["openingJson"],
+      );
+
+  Future<List<OutboxItem>> rustGetOutbox({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rust_get_outbox(port_),
+      parseSuccessData: _wire2api_list_outbox_item,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustGetOutboxConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustGetOutboxConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_get_outbox",
+        argNames: [],
+      );
+
+  Future<int> rustRetryOutbox({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rust_retry_outbox(port_),
+      parseSuccessData: _wire2api_u32,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustRetryOutboxConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustRetryOutboxConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_retry_outbox",
+        argNames: [],
+      );
+
+  Future<bool> rustDropOutboxItem({required String eventId, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(eventId);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_drop_outbox_item(port_, arg0),
+      parseSuccessData: _wire2api_bool,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustDropOutboxItemConstMeta,
+      argValues: [eventId],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustDropOutboxItemConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_drop_outbox_item",
+        argNames: ["eventId"],
+      );
+
+  Future<bool> rustSetPublishPolicy({required String policy, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(policy);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_set_publish_policy(port_, arg0),
+      parseSuccessData: _wire2api_bool,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustSetPublishPolicyConstMeta,
+      argValues: [policy],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustSetPublishPolicyConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_set_publish_policy",
+        argNames: ["policy"],
+      );
+
+  Future<String> rustGetNostrPublicKey(
+      {required String identityId, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(identityId);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_get_nostr_public_key(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustGetNostrPublicKeyConstMeta,
+      argValues: [identityId],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustGetNostrPublicKeyConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_get_nostr_public_key",
+        argNames: ["identityId"],
+      );
+
+  Future<List<String>> rustFetchInteractionHistory({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_fetch_interaction_history(port_),
+      parseSuccessData: _wire2api_StringList,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustFetchInteractionHistoryConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustFetchInteractionHistoryConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_fetch_interaction_history",
+        argNames: [],
+      );
+
+  Future<HistoryPage> rustQueryInteractionHistory(
+      {required HistoryQuery query, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_history_query(query);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_query_interaction_history(port_, arg0),
+      parseSuccessData: _wire2api_history_page,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustQueryInteractionHistoryConstMeta,
+      argValues: [query],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustQueryInteractionHistoryConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_query_interaction_history",
+        argNames: ["query"],
+      );
+
+  Stream<String> rustSubscribeEvents(
+      {required List<String> watchDids, dynamic hint}) {
+    var arg0 = _platform.api2wire_StringList(watchDids);
+    return _platform.executeStream(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_subscribe_events(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustSubscribeEventsConstMeta,
+      argValues: [watchDids],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustSubscribeEventsConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_subscribe_events",
+        argNames: ["watchDids"],
+      );
+
+  Future<bool> rustUnsubscribeEvents({required String handle, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(handle);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_rust_unsubscribe_events(port_, arg0),
+      parseSuccessData: _wire2api_bool,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustUnsubscribeEventsConstMeta,
+      argValues: [handle],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustUnsubscribeEventsConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_unsubscribe_events",
+        argNames: ["handle"],
+      );
+
+  Future<bool> rustResetVault({required String storagePath, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(storagePath);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rust_reset_vault(port_, arg0),
+      parseSuccessData: _wire2api_bool,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRustResetVaultConstMeta,
+      argValues: [storagePath],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRustResetVaultConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "rust_reset_vault",
+        argNames: ["storagePath"],
+      );
+
+  void dispose() {
+    _platform.dispose();
+  }
+// Section: wire2api
+
+  FrbAnyhowException _wire2api_FrbAnyhowException(dynamic raw) {
+    return FrbAnyhowException(raw as String);
+  }
+
+  String _wire2api_String(dynamic raw) {
+    return raw as String;
+  }
+
+  List<String> _wire2api_StringList(dynamic raw) {
+    return (raw as List<dynamic>).cast<String>();
+  }
+
+  bool _wire2api_bool(dynamic raw) {
+    return raw as bool;
+  }
+
+  int _wire2api_box_autoadd_u32(dynamic raw) {
+    return _wire2api_u32(raw);
+  }
+
+  int _wire2api_box_autoadd_u64(dynamic raw) {
+    return _wire2api_u64(raw);
+  }
+
+  HistoryItem _wire2api_history_item(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return HistoryItem(
+      eventId: _wire2api_String(arr[0]),
+      createdAt: _wire2api_u64(arr[1]),
+      seenOn: _wire2api_StringList(arr[2]),
+      intent: _wire2api_signed_intent(arr[3]),
+    );
+  }
+
+  HistoryPage _wire2api_history_page(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return HistoryPage(
+      items: _wire2api_list_history_item(arr[0]),
+      nextCursor: _wire2api_opt_String(arr[1]),
+    );
+  }
+
+  IdentityStatus _wire2api_identity_status(dynamic raw) {
+    return IdentityStatus.values[raw as int];
+  }
+
+  IdentityTombstone _wire2api_identity_tombstone(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return IdentityTombstone(
+      id: _wire2api_String(arr[0]),
+      did: _wire2api_String(arr[1]),
+      derivationPath: _wire2api_String(arr[2]),
+      deletedAt: _wire2api_u64(arr[3]),
+    );
+  }
+
+  IntentPayload _wire2api_intent_payload(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return IntentPayload(
+      version: _wire2api_String(arr[0]),
+      interactionType: _wire2api_interaction_type(arr[1]),
+      timestamp: _wire2api_u64(arr[2]),
+      upiData: _wire2api_upi_intent(arr[3]),
+    );
+  }
+
+  InteractionType _wire2api_interaction_type(dynamic raw) {
+    return InteractionType.values[raw as int];
+  }
+
+  KeyRecord _wire2api_key_record(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return KeyRecord(
+      publicKeyHex: _wire2api_String(arr[0]),
+      derivationPath: _wire2api_String(arr[1]),
+      validFrom: _wire2api_u64(arr[2]),
+      validUntil: _wire2api_opt_box_autoadd_u64(arr[3]),
+    );
+  }
+
+  LedgerEntry _wire2api_ledger_entry(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return LedgerEntry(
+      eventId: _wire2api_String(arr[0]),
+      commitmentHex: _wire2api_String(arr[1]),
+      saltHex: _wire2api_String(arr[2]),
+      intent: _wire2api_signed_intent(arr[3]),
+      committedAt: _wire2api_u64(arr[4]),
+      authorBindingHex: _wire2api_String(arr[5]),
+    );
+  }
+
+  List<HistoryItem> _wire2api_list_history_item(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_history_item).toList();
+  }
+
+  List<IdentityTombstone> _wire2api_list_identity_tombstone(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_identity_tombstone).toList();
+  }
+
+  List<KeyRecord> _wire2api_list_key_record(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_key_record).toList();
+  }
+
+  List<LedgerEntry> _wire2api_list_ledger_entry(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_ledger_entry).toList();
+  }
+
+  List<OutboxItem> _wire2api_list_outbox_item(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_outbox_item).toList();
+  }
+
+  List<PairwiseLink> _wire2api_list_pairwise_link(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_pairwise_link).toList();
+  }
+
+  List<PaperShare> _wire2api_list_paper_share(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_paper_share).toList();
+  }
+
+  List<PinnedKey> _wire2api_list_pinned_key(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_pinned_key).toList();
+  }
+
+  List<PrivateIntent> _wire2api_list_private_intent(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_private_intent).toList();
+  }
+
+  List<RelayAuthGrant> _wire2api_list_relay_auth_grant(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_relay_auth_grant).toList();
+  }
+
+  List<RelayConfig> _wire2api_list_relay_config(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_relay_config).toList();
+  }
+
+  List<RelayHealth> _wire2api_list_relay_health(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_relay_health).toList();
+  }
+
+  List<RelayOutcome> _wire2api_list_relay_outcome(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_relay_outcome).toList();
+  }
+
+  List<RevokedKey> _wire2api_list_revoked_key(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_revoked_key).toList();
+  }
+
+  List<SatyaIdentity> _wire2api_list_satya_identity(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_satya_identity).toList();
+  }
+
+  String? _wire2api_opt_String(dynamic raw) {
+    return raw == null ? null : _wire2api_String(raw);
+  }
+
+  int? _wire2api_opt_box_autoadd_u32(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_u32(raw);
+  }
+
+  int? _wire2api_opt_box_autoadd_u64(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_u64(raw);
+  }
+
+  OutboxItem _wire2api_outbox_item(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return OutboxItem(
+      eventId: _wire2api_String(arr[0]),
+      kind: _wire2api_u64(arr[1]),
+      eventJson: _wire2api_String(arr[2]),
+      queuedAt: _wire2api_u64(arr[3]),
+      attempts: _wire2api_u32(arr[4]),
+      nextAttemptAt: _wire2api_u64(arr[5]),
+      lastError: _wire2api_opt_String(arr[6]),
+    );
+  }
+
+  PairwiseLink _wire2api_pairwise_link(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return PairwiseLink(
+      identityId: _wire2api_String(arr[0]),
+      counterparty: _wire2api_String(arr[1]),
+      did: _wire2api_String(arr[2]),
+      derivationPath: _wire2api_String(arr[3]),
+      createdAt: _wire2api_u64(arr[4]),
+    );
+  }
+
+  PaperShare _wire2api_paper_share(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PaperShare(
+      index: _wire2api_u32(arr[0]),
+      mnemonic: _wire2api_String(arr[1]),
+      qrSvg: _wire2api_String(arr[2]),
+    );
+  }
+
+  PinnedKey _wire2api_pinned_key(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PinnedKey(
+      did: _wire2api_String(arr[0]),
+      publicKeyHex: _wire2api_String(arr[1]),
+    );
+  }
+
+  PrivateIntent _wire2api_private_intent(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PrivateIntent(
+      eventId: _wire2api_String(arr[0]),
+      senderPublicKey: _wire2api_String(arr[1]),
+      sentAt: _wire2api_u64(arr[2]),
+      intent: _wire2api_signed_intent(arr[3]),
+    );
+  }
+
+  PublishResult _wire2api_publish_result(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return PublishResult(
+      eventId: _wire2api_String(arr[0]),
+      acknowledgments: _wire2api_u32(arr[1]),
+      success: _wire2api_bool(arr[2]),
+      outcomes: _wire2api_list_relay_outcome(arr[3]),
+      queued: _wire2api_bool(arr[4]),
+    );
+  }
+
+  PublishStatus _wire2api_publish_status(dynamic raw) {
+    return PublishStatus.values[raw as int];
+  }
+
+  RelayAuthGrant _wire2api_relay_auth_grant(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return RelayAuthGrant(
+      url: _wire2api_String(arr[0]),
+      identityId: _wire2api_String(arr[1]),
+    );
+  }
+
+  RelayConfig _wire2api_relay_config(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return RelayConfig(
+      url: _wire2api_String(arr[0]),
+      read: _wire2api_bool(arr[1]),
+      write: _wire2api_bool(arr[2]),
+    );
+  }
+
+  RelayHealth _wire2api_relay_health(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return RelayHealth(
+      url: _wire2api_String(arr[0]),
+      read: _wire2api_bool(arr[1]),
+      write: _wire2api_bool(arr[2]),
+      connected: _wire2api_bool(arr[3]),
+      latencyMs: _wire2api_opt_box_autoadd_u64(arr[4]),
+      lastError: _wire2api_opt_String(arr[5]),
+      consecutiveFailures: _wire2api_u32(arr[6]),
+      nextRetryAt: _wire2api_u64(arr[7]),
+      inWriteSet: _wire2api_bool(arr[8]),
+      authRequested: _wire2api_bool(arr[9]),
+      authenticatedAs: _wire2api_opt_String(arr[10]),
+    );
+  }
+
+  RelayOutcome _wire2api_relay_outcome(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return RelayOutcome(
+      url: _wire2api_String(arr[0]),
+      status: _wire2api_publish_status(arr[1]),
+      message: _wire2api_String(arr[2]),
+    );
+  }
+
+  RevocationState _wire2api_revocation_state(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return RevocationState(
+      did: _wire2api_String(arr[0]),
+      recoveryPublicKeyHex: _wire2api_String(arr[1]),
+      revokedKeys: _wire2api_list_revoked_key(arr[2]),
+      identityRevokedAt: _wire2api_opt_box_autoadd_u64(arr[3]),
+    );
+  }
+
+  RevokedKey _wire2api_revoked_key(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return RevokedKey(
+      publicKeyHex: _wire2api_String(arr[0]),
+      revokedAt: _wire2api_u64(arr[1]),
+      reason: _wire2api_String(arr[2]),
+    );
+  }
+
+  SatyaIdentity _wire2api_satya_identity(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return SatyaIdentity(
+      id: _wire2api_String(arr[0]),
+      label: _wire2api_String(arr[1]),
+      did: _wire2api_String(arr[2]),
+      derivationPath: _wire2api_String(arr[3]),
+      status: _wire2api_identity_status(arr[4]),
+    );
+  }
+
+  ShareScan _wire2api_share_scan(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ShareScan(
+      collected: _wire2api_u32(arr[0]),
+      required: _wire2api_u32(arr[1]),
+    );
+  }
+
+  SignedIntent _wire2api_signed_intent(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return SignedIntent(
+      payload: _wire2api_intent_payload(arr[0]),
+      signatureHex: _wire2api_String(arr[1]),
+      signerDid: _wire2api_String(arr[2]),
+      signerPublicKeyHex: _wire2api_String(arr[3]),
+      isVerified: _wire2api_bool(arr[4]),
+    );
+  }
+
+  int _wire2api_u32(dynamic raw) {
+    return raw as int;
+  }
+
+  int _wire2api_u64(dynamic raw) {
+    return castInt(raw);
+  }
+
+  int _wire2api_u8(dynamic raw) {
+    return raw as int;
+  }
+
+  Uint8List _wire2api_uint_8_list(dynamic raw) {
+    return raw as Uint8List;
+  }
+
+  void _wire2api_unit(dynamic raw) {
+    return;
+  }
+
+  UnlockStatus _wire2api_unlock_status(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return UnlockStatus(
+      failedAttempts: _wire2api_u32(arr[0]),
+      retryAfterSecs: _wire2api_u64(arr[1]),
+      attemptsBeforeWipe: _wire2api_opt_box_autoadd_u32(arr[2]),
+    );
+  }
+
+  UpiIntent _wire2api_upi_intent(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return UpiIntent(
+      vpa: _wire2api_String(arr[0]),
+      name: _wire2api_String(arr[1]),
+      amount: _wire2api_String(arr[2]),
+      currency: _wire2api_String(arr[3]),
+    );
+  }
+
+  VerifiedOpening _wire2api_verified_opening(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return VerifiedOpening(
+      eventId: _wire2api_String(arr[0]),
+      committedAt: _wire2api_u64(arr[1]),
+      intent: _wire2api_signed_intent(arr[2]),
+    );
+  }
+}
+
+// Section: api2wire
+
+@protected
+bool api2wire_bool(bool raw) {
+  return raw;
+}
+
+@protected
+int api2wire_i32(int raw) {
+  return raw;
+}
+
+@protected
+int api2wire_interaction_type(InteractionType raw) {
+  return api2wire_i32(raw.index);
+}
+
+@protected
+int api2wire_u32(int raw) {
+  return raw;
+}
+
+@protected
+int api2wire_u64(int raw) {
+  return raw;
+}
+
+@protected
+int api2wire_u8(int raw) {
+  return raw;
+}
+
+// Section: finalizer
+
+class RustCorePlatform extends FlutterRustBridgeBase<RustCoreWire> {
+  RustCorePlatform(ffi.DynamicLibrary dylib) : super(RustCoreWire(dylib));
+
+// Section: api2wire
+
+  @protected
+  ffi.Pointer<wire_uint_8_list> api2wire_String(String raw) {
+    return api2wire_uint_8_list(utf8.encoder.convert(raw));
+  }
+
+  @protected
+  ffi.Pointer<wire_StringList> api2wire_StringList(List<String> raw) {
+    final ans = inner.new_StringList_0(raw.length);
+    for (var i = 0; i < raw.length; i++) {
+      ans.ref.ptr[i] = api2wire_String(raw[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<ffi.Bool> api2wire_box_autoadd_bool(bool raw) {
+    return inner.new_box_autoadd_bool_0(api2wire_bool(raw));
+  }
+
+  @protected
+  ffi.Pointer<wire_HistoryQuery> api2wire_box_autoadd_history_query(
+      HistoryQuery raw) {
+    final ptr = inner.new_box_autoadd_history_query_0();
+    _api_fill_to_wire_history_query(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> api2wire_box_autoadd_interaction_type(
+      InteractionType raw) {
+    return inner.new_box_autoadd_interaction_type_0(
+        api2wire_interaction_type(raw));
+  }
+
+  @protected
+  ffi.Pointer<ffi.Uint32> api2wire_box_autoadd_u32(int raw) {
+    return inner.new_box_autoadd_u32_0(api2wire_u32(raw));
+  }
+
+  @protected
+  ffi.Pointer<ffi.Uint64> api2wire_box_autoadd_u64(int raw) {
+    return inner.new_box_autoadd_u64_0(api2wire_u64(raw));
+  }
+
+  @protected
+  ffi.Pointer<wire_uint_8_list> api2wire_opt_String(String? raw) {
+    return raw == null ? ffi.nullptr : api2wire_String(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Bool> api2wire_opt_box_autoadd_bool(bool? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_bool(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> api2wire_opt_box_autoadd_interaction_type(
+      InteractionType? raw) {
+    return raw == null
+        ? ffi.nullptr
+        : api2wire_box_autoadd_interaction_type(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Uint32> api2wire_opt_box_autoadd_u32(int? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_u32(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Uint64> api2wire_opt_box_autoadd_u64(int? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_u64(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_uint_8_list> api2wire_uint_8_list(Uint8List raw) {
+    final ans = inner.new_uint_8_list_0(raw.length);
+    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
+    return ans;
+  }
+// Section: finalizer
+
+// Section: api_fill_to_wire
+
+  void _api_fill_to_wire_box_autoadd_history_query(
+      HistoryQuery apiObj, ffi.Pointer<wire_HistoryQuery> wireObj) {
+    _api_fill_to_wire_history_query(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_history_query(
+      HistoryQuery apiObj, wire_HistoryQuery wireObj) {
+    wireObj.identity_ids = api2wire_StringList(apiObj.identityIds);
+    wireObj.counterparty = api2wire_opt_String(apiObj.counterparty);
+    wireObj.since = api2wire_opt_box_autoadd_u64(apiObj.since);
+    wireObj.until = api2wire_opt_box_autoadd_u64(apiObj.until);
+    wireObj.interaction_type =
+        api2wire_opt_box_autoadd_interaction_type(apiObj.interactionType);
+    wireObj.verified = api2wire_opt_box_autoadd_bool(apiObj.verified);
+    wireObj.page_size = api2wire_opt_box_autoadd_u32(apiObj.pageSize);
+    wireObj.cursor = api2wire_opt_String(apiObj.cursor);
+    wireObj.timeout_secs = api2wire_opt_box_autoadd_u64(apiObj.timeoutSecs);
+  }
+}
+
+// ignore_for_file: camel_case_types, non_constant_identifier_names, avoid_positional_boolean_parameters, annotate_overrides, constant_identifier_names
+
+// AUTO GENERATED FILE, DO NOT EDIT.
+//
 // Generated by `package:ffigen`.
 // ignore_for_file: type=lint
 
-/// generated by flutter_rust_bridge
-class RustCoreWire implements FlutterRustBridgeWireBase {
-  @internal
-  late final dartApi = DartApiDl(init_frb_dart_api_dl);
+/// generated by flutter_rust_bridge
+class RustCoreWire implements FlutterRustBridgeWireBase {
+  @internal
+  late final dartApi = DartApiDl(init_frb_dart_api_dl);
+
+  /// Holds the symbol lookup function.
+  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
+      _lookup;
+
+  /// The symbols are looked up in [dynamicLibrary].
+  RustCoreWire(ffi.DynamicLibrary dynamicLibrary)
+      : _lookup = dynamicLibrary.lookup;
+
+  /// The symbols are looked up with [lookup].
+  RustCoreWire.fromLookup(
+    ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName) lookup,
+  ) : _lookup = lookup;
+
+  void store_dart_post_cobject(DartPostCObjectFnType ptr) {
+    return _store_dart_post_cobject(ptr);
+  }
+
+  late final _store_dart_post_cobjectPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(DartPostCObjectFnType)>>(
+    'store_dart_post_cobject',
+  );
+  late final _store_dart_post_cobject = _store_dart_post_cobjectPtr
+      .asFunction<void Function(DartPostCObjectFnType)>();
+
+  Object get_dart_object(int ptr) {
+    return _get_dart_object(ptr);
+  }
+
+  late final _get_dart_objectPtr =
+      _lookup<ffi.NativeFunction<ffi.Handle Function(ffi.UintPtr)>>(
+    'get_dart_object',
+  );
+  late final _get_dart_object =
+      _get_dart_objectPtr.asFunction<Object Function(int)>();
+
+  void drop_dart_object(int ptr) {
+    return _drop_dart_object(ptr);
+  }
+
+  late final _drop_dart_objectPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.UintPtr)>>(
+    'drop_dart_object',
+  );
+  late final _drop_dart_object =
+      _drop_dart_objectPtr.asFunction<void Function(int)>();
+
+  int new_dart_opaque(Object handle) {
+    return _new_dart_opaque(handle);
+  }
+
+  late final _new_dart_opaquePtr =
+      _lookup<ffi.NativeFunction<ffi.UintPtr Function(ffi.Handle)>>(
+    'new_dart_opaque',
+  );
+  late final _new_dart_opaque =
+      _new_dart_opaquePtr.asFunction<int Function(Object)>();
+
+  int init_frb_dart_api_dl(ffi.Pointer<ffi.Void> obj) {
+    return _init_frb_dart_api_dl(obj);
+  }
+
+  late final _init_frb_dart_api_dlPtr =
+      _lookup<ffi.NativeFunction<ffi.IntPtr Function(ffi.Pointer<ffi.Void>)>>(
+    'init_frb_dart_api_dl',
+  );
+  late final _init_frb_dart_api_dl = _init_frb_dart_api_dlPtr
+      .asFunction<int Function(ffi.Pointer<ffi.Void>)>();
+
+  void wire_rust_initialize_vault(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> pin,
+    ffi.Pointer<wire_uint_8_list> hw_id,
+    ffi.Pointer<wire_uint_8_list> storage_path,
+  ) {
+    return _wire_rust_initialize_vault(port_, pin, hw_id, storage_path);
+  }
+
+  late final _wire_rust_initialize_vaultPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_initialize_vault');
+  late final _wire_rust_initialize_vault =
+      _wire_rust_initialize_vaultPtr.asFunction<
+          void Function(
+            int,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>();
+
+  void wire_rust_unlock_vault(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> storage_path,
+    ffi.Pointer<wire_uint_8_list> hw_id,
+    ffi.Pointer<wire_uint_8_list> pin,
+    ffi.Pointer<wire_uint_8_list> recovery_code,
+    ffi.Pointer<wire_uint_8_list> key_file_path,
+  ) {
+    return _wire_rust_unlock_vault(
+      port_,
+      storage_path,
+      hw_id,
+      pin,
+      recovery_code,
+      key_file_path,
+    );
+  }
+
+  late final _wire_rust_unlock_vaultPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_unlock_vault');
+  late final _wire_rust_unlock_vault = _wire_rust_unlock_vaultPtr.asFunction<
+      void Function(
+        int,
+        ffi.Pointer<wire_uint_8_list>,
+        ffi.Pointer<wire_uint_8_list>,
+        ffi.Pointer<wire_uint_8_list>,
+        ffi.Pointer<wire_uint_8_list>,
+        ffi.Pointer<wire_uint_8_list>,
+      )>();
+
+  void wire_rust_restore_from_mnemonic(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> mnemonic,
+    ffi.Pointer<wire_uint_8_list> mask_passphrase,
+    ffi.Pointer<wire_uint_8_list> pin,
+    ffi.Pointer<wire_uint_8_list> hw_id,
+    ffi.Pointer<wire_uint_8_list> storage_path,
+  ) {
+    return _wire_rust_restore_from_mnemonic(
+      port_,
+      mnemonic,
+      mask_passphrase,
+      pin,
+      hw_id,
+      storage_path,
+    );
+  }
+
+  late final _wire_rust_restore_from_mnemonicPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_restore_from_mnemonic');
+  late final _wire_rust_restore_from_mnemonic =
+      _wire_rust_restore_from_mnemonicPtr.asFunction<
+          void Function(
+            int,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>();
+
+  void wire_rust_list_relays(int port_) {
+    return _wire_rust_list_relays(port_);
+  }
+
+  late final _wire_rust_list_relaysPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
+    'wire_rust_list_relays',
+  );
+  late final _wire_rust_list_relays =
+      _wire_rust_list_relaysPtr.asFunction<void Function(int)>();
+
+  void wire_rust_add_relay(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> url,
+    bool read,
+    bool write,
+  ) {
+    return _wire_rust_add_relay(port_, url, read, write);
+  }
+
+  late final _wire_rust_add_relayPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Bool,
+            ffi.Bool,
+          )>>('wire_rust_add_relay');
+  late final _wire_rust_add_relay = _wire_rust_add_relayPtr.asFunction<
+      void Function(
+        int,
+        ffi.Pointer<wire_uint_8_list>,
+        bool,
+        bool,
+      )>();
+
+  void wire_rust_remove_relay(int port_, ffi.Pointer<wire_uint_8_list> url) {
+    return _wire_rust_remove_relay(port_, url);
+  }
+
+  late final _wire_rust_remove_relayPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_remove_relay');
+  late final _wire_rust_remove_relay = _wire_rust_remove_relayPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_rust_get_relay_status(int port_) {
+    return _wire_rust_get_relay_status(port_);
+  }
+
+  late final _wire_rust_get_relay_statusPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
+    'wire_rust_get_relay_status',
+  );
+  late final _wire_rust_get_relay_status =
+      _wire_rust_get_relay_statusPtr.asFunction<void Function(int)>();
+
+  void wire_rust_set_relay_auth(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> url,
+    ffi.Pointer<wire_uint_8_list> identity_id,
+  ) {
+    return _wire_rust_set_relay_auth(port_, url, identity_id);
+  }
+
+  late final _wire_rust_set_relay_authPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_set_relay_auth');
+  late final _wire_rust_set_relay_auth =
+      _wire_rust_set_relay_authPtr.asFunction<
+          void Function(
+            int,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>();
+
+  void wire_rust_list_relay_auth(int port_) {
+    return _wire_rust_list_relay_auth(port_);
+  }
+
+  late final _wire_rust_list_relay_authPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
+    'wire_rust_list_relay_auth',
+  );
+  late final _wire_rust_list_relay_auth =
+      _wire_rust_list_relay_authPtr.asFunction<void Function(int)>();
+
+  void wire_rust_start_local_relay(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> bind_address,
+    ffi.Pointer<wire_uint_8_list> storage_path,
+  ) {
+    return _wire_rust_start_local_relay(port_, bind_address, storage_path);
+  }
+
+  late final _wire_rust_start_local_relayPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_start_local_relay');
+  late final _wire_rust_start_local_relay =
+      _wire_rust_start_local_relayPtr.asFunction<
+          void Function(
+            int,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>();
+
+  void wire_rust_stop_local_relay(int port_) {
+    return _wire_rust_stop_local_relay(port_);
+  }
+
+  late final _wire_rust_stop_local_relayPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
+    'wire_rust_stop_local_relay',
+  );
+  late final _wire_rust_stop_local_relay =
+      _wire_rust_stop_local_relayPtr.asFunction<void Function(int)>();
+
+  void wire_rust_generate_recovery_code(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> pin,
+  ) {
+    return _wire_rust_generate_recovery_code(port_, pin);
+  }
+
+  late final _wire_rust_generate_recovery_codePtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_generate_recovery_code');
+  late final _wire_rust_generate_recovery_code =
+      _wire_rust_generate_recovery_codePtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_rust_enroll_unlock_factors(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> pin,
+    ffi.Pointer<wire_StringList> factors,
+    bool require_all,
+    ffi.Pointer<wire_uint_8_list> key_file_path,
+  ) {
+    return _wire_rust_enroll_unlock_factors(
+      port_,
+      pin,
+      factors,
+      require_all,
+      key_file_path,
+    );
+  }
+
+  late final _wire_rust_enroll_unlock_factorsPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_StringList>,
+            ffi.Bool,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_enroll_unlock_factors');
+  late final _wire_rust_enroll_unlock_factors =
+      _wire_rust_enroll_unlock_factorsPtr.asFunction<
+          void Function(
+            int,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_StringList>,
+            bool,
+            ffi.Pointer<wire_uint_8_list>,
+          )>();
+
+  void wire_rust_revoke_unlock_factor(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> pin,
+    ffi.Pointer<wire_uint_8_list> factor,
+    ffi.Pointer<wire_uint_8_list> key_file_path,
+  ) {
+    return _wire_rust_revoke_unlock_factor(port_, pin, factor, key_file_path);
+  }
+
+  late final _wire_rust_revoke_unlock_factorPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_revoke_unlock_factor');
+  late final _wire_rust_revoke_unlock_factor =
+      _wire_rust_revoke_unlock_factorPtr.asFunction<
+          void Function(
+            int,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>();
+
+  void wire_rust_list_unlock_factors(int port_) {
+    return _wire_rust_list_unlock_factors(port_);
+  }
+
+  late final _wire_rust_list_unlock_factorsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
+    'wire_rust_list_unlock_factors',
+  );
+  late final _wire_rust_list_unlock_factors =
+      _wire_rust_list_unlock_factorsPtr.asFunction<void Function(int)>();
+
+  void wire_rust_change_pin(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> old_pin,
+    ffi.Pointer<wire_uint_8_list> new_pin,
+    ffi.Pointer<wire_uint_8_list> recovery_code,
+    ffi.Pointer<wire_uint_8_list> key_file_path,
+  ) {
+    return _wire_rust_change_pin(
+      port_,
+      old_pin,
+      new_pin,
+      recovery_code,
+      key_file_path,
+    );
+  }
+
+  late final _wire_rust_change_pinPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_change_pin');
+  late final _wire_rust_change_pin = _wire_rust_change_pinPtr.asFunction<
+      void Function(
+        int,
+        ffi.Pointer<wire_uint_8_list>,
+        ffi.Pointer<wire_uint_8_list>,
+        ffi.Pointer<wire_uint_8_list>,
+        ffi.Pointer<wire_uint_8_list>,
+      )>();
+
+  void wire_rust_configure_duress_pin(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> pin,
+    ffi.Pointer<wire_uint_8_list> duress_pin,
+  ) {
+    return _wire_rust_configure_duress_pin(port_, pin, duress_pin);
+  }
+
+  late final _wire_rust_configure_duress_pinPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_configure_duress_pin');
+  late final _wire_rust_configure_duress_pin =
+      _wire_rust_configure_duress_pinPtr.asFunction<
+          void Function(
+            int,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>();
+
+  void wire_rust_clear_duress_pin(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> pin,
+  ) {
+    return _wire_rust_clear_duress_pin(port_, pin);
+  }
+
+  late final _wire_rust_clear_duress_pinPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_clear_duress_pin');
+  late final _wire_rust_clear_duress_pin = _wire_rust_clear_duress_pinPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_rust_export_mnemonic(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> pin,
+    ffi.Pointer<wire_uint_8_list> mask_passphrase,
+  ) {
+    return _wire_rust_export_mnemonic(port_, pin, mask_passphrase);
+  }
+
+  late final _wire_rust_export_mnemonicPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_export_mnemonic');
+  late final _wire_rust_export_mnemonic =
+      _wire_rust_export_mnemonicPtr.asFunction<
+          void Function(
+            int,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>();
+
+  void wire_rust_verify_mnemonic(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> mnemonic,
+    ffi.Pointer<wire_uint_8_list> mask_passphrase,
+  ) {
+    return _wire_rust_verify_mnemonic(port_, mnemonic, mask_passphrase);
+  }
+
+  late final _wire_rust_verify_mnemonicPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_verify_mnemonic');
+  late final _wire_rust_verify_mnemonic =
+      _wire_rust_verify_mnemonicPtr.asFunction<
+          void Function(
+            int,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>();
+
+  void wire_rust_get_unlock_status(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> storage_path,
+    ffi.Pointer<wire_uint_8_list> hw_id,
+  ) {
+    return _wire_rust_get_unlock_status(port_, storage_path, hw_id);
+  }
+
+  late final _wire_rust_get_unlock_statusPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_get_unlock_status');
+  late final _wire_rust_get_unlock_status =
+      _wire_rust_get_unlock_statusPtr.asFunction<
+          void Function(
+            int,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>();
+
+  void wire_rust_set_wipe_after(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> pin,
+    ffi.Pointer<ffi.Uint32> limit,
+  ) {
+    return _wire_rust_set_wipe_after(port_, pin, limit);
+  }
+
+  late final _wire_rust_set_wipe_afterPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<ffi.Uint32>,
+          )>>('wire_rust_set_wipe_after');
+  late final _wire_rust_set_wipe_after =
+      _wire_rust_set_wipe_afterPtr.asFunction<
+          void Function(
+            int,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<ffi.Uint32>,
+          )>();
+
+  void wire_rust_set_hardware_binding(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> pin,
+    ffi.Pointer<wire_uint_8_list> strategy,
+    ffi.Pointer<wire_uint_8_list> key_file_path,
+  ) {
+    return _wire_rust_set_hardware_binding(port_, pin, strategy, key_file_path);
+  }
+
+  late final _wire_rust_set_hardware_bindingPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_set_hardware_binding');
+  late final _wire_rust_set_hardware_binding =
+      _wire_rust_set_hardware_bindingPtr.asFunction<
+          void Function(
+            int,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>();
+
+  void wire_rust_rebind_device(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> pin,
+    ffi.Pointer<wire_uint_8_list> recovery_code,
+    ffi.Pointer<wire_uint_8_list> hw_id,
+    ffi.Pointer<wire_uint_8_list> storage_path,
+    ffi.Pointer<wire_uint_8_list> strategy,
+    ffi.Pointer<wire_uint_8_list> key_file_path,
+  ) {
+    return _wire_rust_rebind_device(
+      port_,
+      pin,
+      recovery_code,
+      hw_id,
+      storage_path,
+      strategy,
+      key_file_path,
+    );
+  }
+
+  late final _wire_rust_rebind_devicePtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_rebind_device');
+  late final _wire_rust_rebind_device = _wire_rust_rebind_devicePtr.asFunction<
+      void Function(
+        int,
+        ffi.Pointer<wire_uint_8_list>,
+        ffi.Pointer<wire_uint_8_list>,
+        ffi.Pointer<wire_uint_8_list>,
+        ffi.Pointer<wire_uint_8_list>,
+        ffi.Pointer<wire_uint_8_list>,
+        ffi.Pointer<wire_uint_8_list>,
+      )>();
+
+  void wire_rust_create_identity(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> label,
+  ) {
+    return _wire_rust_create_identity(port_, label);
+  }
+
+  late final _wire_rust_create_identityPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_create_identity');
+  late final _wire_rust_create_identity = _wire_rust_create_identityPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_rust_publish_identity_profile(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> identity_id,
+  ) {
+    return _wire_rust_publish_identity_profile(port_, identity_id);
+  }
+
+  late final _wire_rust_publish_identity_profilePtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_publish_identity_profile');
+  late final _wire_rust_publish_identity_profile =
+      _wire_rust_publish_identity_profilePtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_rust_discover_identities(
+    int port_,
+    ffi.Pointer<ffi.Uint32> gap_limit,
+  ) {
+    return _wire_rust_discover_identities(port_, gap_limit);
+  }
+
+  late final _wire_rust_discover_identitiesPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<ffi.Uint32>,
+          )>>('wire_rust_discover_identities');
+  late final _wire_rust_discover_identities = _wire_rust_discover_identitiesPtr
+      .asFunction<void Function(int, ffi.Pointer<ffi.Uint32>)>();
+
+  void wire_rust_get_guardian_pubkey(int port_) {
+    return _wire_rust_get_guardian_pubkey(port_);
+  }
+
+  late final _wire_rust_get_guardian_pubkeyPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
+    'wire_rust_get_guardian_pubkey',
+  );
+  late final _wire_rust_get_guardian_pubkey =
+      _wire_rust_get_guardian_pubkeyPtr.asFunction<void Function(int)>();
+
+  void wire_rust_create_social_recovery(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> pin,
+    ffi.Pointer<wire_StringList> guardians,
+    int threshold,
+  ) {
+    return _wire_rust_create_social_recovery(port_, pin, guardians, threshold);
+  }
+
+  late final _wire_rust_create_social_recoveryPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_StringList>,
+            ffi.Uint32,
+          )>>('wire_rust_create_social_recovery');
+  late final _wire_rust_create_social_recovery =
+      _wire_rust_create_social_recoveryPtr.asFunction<
+          void Function(
+            int,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_StringList>,
+            int,
+          )>();
+
+  void wire_rust_fetch_guardian_shares(int port_) {
+    return _wire_rust_fetch_guardian_shares(port_);
+  }
+
+  late final _wire_rust_fetch_guardian_sharesPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
+    'wire_rust_fetch_guardian_shares',
+  );
+  late final _wire_rust_fetch_guardian_shares =
+      _wire_rust_fetch_guardian_sharesPtr.asFunction<void Function(int)>();
+
+  void wire_rust_release_guardian_share(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> pin,
+    ffi.Pointer<wire_uint_8_list> envelope,
+    ffi.Pointer<wire_uint_8_list> requester_pubkey,
+  ) {
+    return _wire_rust_release_guardian_share(
+      port_,
+      pin,
+      envelope,
+      requester_pubkey,
+    );
+  }
+
+  late final _wire_rust_release_guardian_sharePtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_release_guardian_share');
+  late final _wire_rust_release_guardian_share =
+      _wire_rust_release_guardian_sharePtr.asFunction<
+          void Function(
+            int,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>();
+
+  void wire_rust_begin_social_recovery(int port_) {
+    return _wire_rust_begin_social_recovery(port_);
+  }
+
+  late final _wire_rust_begin_social_recoveryPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
+    'wire_rust_begin_social_recovery',
+  );
+  late final _wire_rust_begin_social_recovery =
+      _wire_rust_begin_social_recoveryPtr.asFunction<void Function(int)>();
+
+  void wire_rust_collect_recovery_shares(int port_) {
+    return _wire_rust_collect_recovery_shares(port_);
+  }
+
+  late final _wire_rust_collect_recovery_sharesPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
+    'wire_rust_collect_recovery_shares',
+  );
+  late final _wire_rust_collect_recovery_shares =
+      _wire_rust_collect_recovery_sharesPtr.asFunction<void Function(int)>();
+
+  void wire_rust_recover_from_shares(
+    int port_,
+    ffi.Pointer<wire_StringList> envelopes,
+    ffi.Pointer<wire_uint_8_list> pin,
+    ffi.Pointer<wire_uint_8_list> hw_id,
+    ffi.Pointer<wire_uint_8_list> storage_path,
+  ) {
+    return _wire_rust_recover_from_shares(
+      port_,
+      envelopes,
+      pin,
+      hw_id,
+      storage_path,
+    );
+  }
+
+  late final _wire_rust_recover_from_sharesPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_StringList>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_recover_from_shares');
+  late final _wire_rust_recover_from_shares =
+      _wire_rust_recover_from_sharesPtr.asFunction<
+          void Function(
+            int,
+            ffi.Pointer<wire_StringList>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>();
+
+  void wire_rust_rename_identity(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> identity_id,
+    ffi.Pointer<wire_uint_8_list> label,
+  ) {
+    return _wire_rust_rename_identity(port_, identity_id, label);
+  }
+
+  late final _wire_rust_rename_identityPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_rename_identity');
+  late final _wire_rust_rename_identity =
+      _wire_rust_rename_identityPtr.asFunction<
+          void Function(
+            int,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>();
+
+  void wire_rust_archive_identity(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> identity_id,
+  ) {
+    return _wire_rust_archive_identity(port_, identity_id);
+  }
+
+  late final _wire_rust_archive_identityPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_archive_identity');
+  late final _wire_rust_archive_identity = _wire_rust_archive_identityPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_rust_unarchive_identity(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> identity_id,
+  ) {
+    return _wire_rust_unarchive_identity(port_, identity_id);
+  }
+
+  late final _wire_rust_unarchive_identityPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_unarchive_identity');
+  late final _wire_rust_unarchive_identity = _wire_rust_unarchive_identityPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_rust_delete_identity(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> pin,
+    ffi.Pointer<wire_uint_8_list> identity_id,
+    bool publish_retirement,
+  ) {
+    return _wire_rust_delete_identity(
+      port_,
+      pin,
+      identity_id,
+      publish_retirement,
+    );
+  }
+
+  late final _wire_rust_delete_identityPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Bool,
+          )>>('wire_rust_delete_identity');
+  late final _wire_rust_delete_identity =
+      _wire_rust_delete_identityPtr.asFunction<
+          void Function(
+            int,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            bool,
+          )>();
+
+  void wire_rust_get_tombstones(int port_) {
+    return _wire_rust_get_tombstones(port_);
+  }
+
+  late final _wire_rust_get_tombstonesPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
+    'wire_rust_get_tombstones',
+  );
+  late final _wire_rust_get_tombstones =
+      _wire_rust_get_tombstonesPtr.asFunction<void Function(int)>();
+
+  void wire_rust_rotate_identity_key(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> pin,
+    ffi.Pointer<wire_uint_8_list> identity_id,
+  ) {
+    return _wire_rust_rotate_identity_key(port_, pin, identity_id);
+  }
+
+  late final _wire_rust_rotate_identity_keyPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_rotate_identity_key');
+  late final _wire_rust_rotate_identity_key =
+      _wire_rust_rotate_identity_keyPtr.asFunction<
+          void Function(
+            int,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>();
+
+  void wire_rust_get_key_history(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> identity_id,
+  ) {
+    return _wire_rust_get_key_history(port_, identity_id);
+  }
+
+  late final _wire_rust_get_key_historyPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_get_key_history');
+  late final _wire_rust_get_key_history = _wire_rust_get_key_historyPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_rust_publish_revocation_list(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> pin,
+    ffi.Pointer<wire_uint_8_list> identity_id,
+    ffi.Pointer<wire_StringList> public_keys,
+    bool revoke_identity,
+    ffi.Pointer<ffi.Uint64> since,
+    ffi.Pointer<wire_uint_8_list> reason,
+  ) {
+    return _wire_rust_publish_revocation_list(
+      port_,
+      pin,
+      identity_id,
+      public_keys,
+      revoke_identity,
+      since,
+      reason,
+    );
+  }
 
-  /// Holds the symbol lookup function.
-  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
-      _lookup;
+  late final _wire_rust_publish_revocation_listPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_StringList>,
+            ffi.Bool,
+            ffi.Pointer<ffi.Uint64>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_publish_revocation_list');
+  late final _wire_rust_publish_revocation_list =
+      _wire_rust_publish_revocation_listPtr.asFunction<
+          void Function(
+            int,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_StringList>,
+            bool,
+            ffi.Pointer<ffi.Uint64>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>();
 
-  /// The symbols are looked up in [dynamicLibrary].
-  RustCoreWire(ffi.DynamicLibrary dynamicLibrary)
-      : _lookup = dynamicLibrary.lookup;
+  void wire_rust_get_revocation_status(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> did,
+  ) {
+    return _wire_rust_get_revocation_status(port_, did);
+  }
+
+  late final _wire_rust_get_revocation_statusPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_get_revocation_status');
+  late final _wire_rust_get_revocation_status =
+      _wire_rust_get_revocation_statusPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_rust_verify_intent(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> signed_json,
+  ) {
+    return _wire_rust_verify_intent(port_, signed_json);
+  }
+
+  late final _wire_rust_verify_intentPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_verify_intent');
+  late final _wire_rust_verify_intent = _wire_rust_verify_intentPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_rust_get_did_document(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> identity_id,
+  ) {
+    return _wire_rust_get_did_document(port_, identity_id);
+  }
+
+  late final _wire_rust_get_did_documentPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_get_did_document');
+  late final _wire_rust_get_did_document = _wire_rust_get_did_documentPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_rust_publish_did_document(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> identity_id,
+  ) {
+    return _wire_rust_publish_did_document(port_, identity_id);
+  }
+
+  late final _wire_rust_publish_did_documentPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_publish_did_document');
+  late final _wire_rust_publish_did_document =
+      _wire_rust_publish_did_documentPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_rust_resolve_did(int port_, ffi.Pointer<wire_uint_8_list> did) {
+    return _wire_rust_resolve_did(port_, did);
+  }
+
+  late final _wire_rust_resolve_didPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_resolve_did');
+  late final _wire_rust_resolve_did = _wire_rust_resolve_didPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_rust_pin_did_key(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> did,
+    ffi.Pointer<wire_uint_8_list> public_key_hex,
+  ) {
+    return _wire_rust_pin_did_key(port_, did, public_key_hex);
+  }
+
+  late final _wire_rust_pin_did_keyPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_pin_did_key');
+  late final _wire_rust_pin_did_key = _wire_rust_pin_did_keyPtr.asFunction<
+      void Function(
+        int,
+        ffi.Pointer<wire_uint_8_list>,
+        ffi.Pointer<wire_uint_8_list>,
+      )>();
+
+  void wire_rust_unpin_did_key(int port_, ffi.Pointer<wire_uint_8_list> did) {
+    return _wire_rust_unpin_did_key(port_, did);
+  }
+
+  late final _wire_rust_unpin_did_keyPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_unpin_did_key');
+  late final _wire_rust_unpin_did_key = _wire_rust_unpin_did_keyPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_rust_list_pinned_keys(int port_) {
+    return _wire_rust_list_pinned_keys(port_);
+  }
+
+  late final _wire_rust_list_pinned_keysPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
+    'wire_rust_list_pinned_keys',
+  );
+  late final _wire_rust_list_pinned_keys =
+      _wire_rust_list_pinned_keysPtr.asFunction<void Function(int)>();
+
+  void wire_rust_get_identities(int port_) {
+    return _wire_rust_get_identities(port_);
+  }
+
+  late final _wire_rust_get_identitiesPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
+    'wire_rust_get_identities',
+  );
+  late final _wire_rust_get_identities =
+      _wire_rust_get_identitiesPtr.asFunction<void Function(int)>();
+
+  void wire_rust_scan_qr(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> raw_qr_string,
+  ) {
+    return _wire_rust_scan_qr(port_, raw_qr_string);
+  }
+
+  late final _wire_rust_scan_qrPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_scan_qr');
+  late final _wire_rust_scan_qr = _wire_rust_scan_qrPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_rust_scan_paper_share(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> raw_qr_string,
+  ) {
+    return _wire_rust_scan_paper_share(port_, raw_qr_string);
+  }
+
+  late final _wire_rust_scan_paper_sharePtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_scan_paper_share');
+  late final _wire_rust_scan_paper_share = _wire_rust_scan_paper_sharePtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_rust_export_paper_shares(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> pin,
+    int threshold,
+    int count,
+    ffi.Pointer<wire_uint_8_list> passphrase,
+  ) {
+    return _wire_rust_export_paper_shares(
+      port_,
+      pin,
+      threshold,
+      count,
+      passphrase,
+    );
+  }
+
+  late final _wire_rust_export_paper_sharesPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Uint32,
+            ffi.Uint32,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_export_paper_shares');
+  late final _wire_rust_export_paper_shares =
+      _wire_rust_export_paper_sharesPtr.asFunction<
+          void Function(
+            int,
+            ffi.Pointer<wire_uint_8_list>,
+            int,
+            int,
+            ffi.Pointer<wire_uint_8_list>,
+          )>();
+
+  void wire_rust_restore_from_paper_shares(
+    int port_,
+    ffi.Pointer<wire_StringList> shares,
+    ffi.Pointer<wire_uint_8_list> passphrase,
+    ffi.Pointer<wire_uint_8_list> pin,
+    ffi.Pointer<wire_uint_8_list> hw_id,
+    ffi.Pointer<wire_uint_8_list> storage_path,
+  ) {
+    return _wire_rust_restore_from_paper_shares(
+      port_,
+      shares,
+      passphrase,
+      pin,
+      hw_id,
+      storage_path,
+    );
+  }
+
+  late final _wire_rust_restore_from_paper_sharesPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_StringList>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_restore_from_paper_shares');
+  late final _wire_rust_restore_from_paper_shares =
+      _wire_rust_restore_from_paper_sharesPtr.asFunction<
+          void Function(
+            int,
+            ffi.Pointer<wire_StringList>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>();
+
+  void wire_rust_sign_intent(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> identity_id,
+    ffi.Pointer<wire_uint_8_list> upi_url,
+  ) {
+    return _wire_rust_sign_intent(port_, identity_id, upi_url);
+  }
+
+  late final _wire_rust_sign_intentPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_sign_intent');
+  late final _wire_rust_sign_intent = _wire_rust_sign_intentPtr.asFunction<
+      void Function(
+        int,
+        ffi.Pointer<wire_uint_8_list>,
+        ffi.Pointer<wire_uint_8_list>,
+      )>();
+
+  void wire_rust_sign_pairwise_intent(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> identity_id,
+    ffi.Pointer<wire_uint_8_list> upi_url,
+  ) {
+    return _wire_rust_sign_pairwise_intent(port_, identity_id, upi_url);
+  }
+
+  late final _wire_rust_sign_pairwise_intentPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_sign_pairwise_intent');
+  late final _wire_rust_sign_pairwise_intent =
+      _wire_rust_sign_pairwise_intentPtr.asFunction<
+          void Function(
+            int,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>();
+
+  void wire_rust_get_pairwise_did(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> identity_id,
+    ffi.Pointer<wire_uint_8_list> counterparty,
+  ) {
+    return _wire_rust_get_pairwise_did(port_, identity_id, counterparty);
+  }
+
+  late final _wire_rust_get_pairwise_didPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_get_pairwise_did');
+  late final _wire_rust_get_pairwise_did =
+      _wire_rust_get_pairwise_didPtr.asFunction<
+          void Function(
+            int,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>();
+
+  void wire_rust_list_pairwise_dids(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> identity_id,
+  ) {
+    return _wire_rust_list_pairwise_dids(port_, identity_id);
+  }
+
+  late final _wire_rust_list_pairwise_didsPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_list_pairwise_dids');
+  late final _wire_rust_list_pairwise_dids = _wire_rust_list_pairwise_didsPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_rust_prove_linkage(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> pin,
+    ffi.Pointer<wire_uint_8_list> identity_id,
+    ffi.Pointer<wire_uint_8_list> counterparty,
+  ) {
+    return _wire_rust_prove_linkage(port_, pin, identity_id, counterparty);
+  }
 
-  /// The symbols are looked up with [lookup].
-  RustCoreWire.fromLookup(
-    ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName) lookup,
-  ) : _lookup = lookup;
+  late final _wire_rust_prove_linkagePtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_prove_linkage');
+  late final _wire_rust_prove_linkage = _wire_rust_prove_linkagePtr.asFunction<
+      void Function(
+        int,
+        ffi.Pointer<wire_uint_8_list>,
+        ffi.Pointer<wire_uint_8_list>,
+        ffi.Pointer<wire_uint_8_list>,
+      )>();
 
-  void store_dart_post_cobject(DartPostCObjectFnType ptr) {
-    return _store_dart_post_cobject(ptr);
+  void wire_rust_verify_linkage(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> proof_json,
+  ) {
+    return _wire_rust_verify_linkage(port_, proof_json);
   }
 
-  late final _store_dart_post_cobjectPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(DartPostCObjectFnType)>>(
-    'store_dart_post_cobject',
-  );
-  late final _store_dart_post_cobject = _store_dart_post_cobjectPtr
-      .asFunction<void Function(DartPostCObjectFnType)>();
+  late final _wire_rust_verify_linkagePtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_verify_linkage');
+  late final _wire_rust_verify_linkage = _wire_rust_verify_linkagePtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  Object get_dart_object(int ptr) {
-    return _get_dart_object(ptr);
+  void wire_rust_publish_to_nostr(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> signed_json,
+  ) {
+    return _wire_rust_publish_to_nostr(port_, signed_json);
   }
 
-  late final _get_dart_objectPtr =
-      _lookup<ffi.NativeFunction<ffi.Handle Function(ffi.UintPtr)>>(
-    'get_dart_object',
-  );
-  late final _get_dart_object =
-      _get_dart_objectPtr.asFunction<Object Function(int)>();
+  late final _wire_rust_publish_to_nostrPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_publish_to_nostr');
+  late final _wire_rust_publish_to_nostr = _wire_rust_publish_to_nostrPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void drop_dart_object(int ptr) {
-    return _drop_dart_object(ptr);
+  void wire_rust_publish_intent(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> signed_json,
+  ) {
+    return _wire_rust_publish_intent(port_, signed_json);
   }
 
-  late final _drop_dart_objectPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.UintPtr)>>(
-    'drop_dart_object',
-  );
-  late final _drop_dart_object =
-      _drop_dart_objectPtr.asFunction<void Function(int)>();
+  late final _wire_rust_publish_intentPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_publish_intent');
+  late final _wire_rust_publish_intent = _wire_rust_publish_intentPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  int new_dart_opaque(Object handle) {
-    return _new_dart_opaque(handle);
+  void wire_rust_publish_private_intent(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> signed_json,
+    ffi.Pointer<wire_uint_8_list> recipient,
+  ) {
+    return _wire_rust_publish_private_intent(port_, signed_json, recipient);
   }
 
-  late final _new_dart_opaquePtr =
-      _lookup<ffi.NativeFunction<ffi.UintPtr Function(ffi.Handle)>>(
-    'new_dart_opaque',
-  );
-  late final _new_dart_opaque =
-      _new_dart_opaquePtr.asFunction<int Function(Object)>();
+  late final _wire_rust_publish_private_intentPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_publish_private_intent');
+  late final _wire_rust_publish_private_intent =
+      _wire_rust_publish_private_intentPtr.asFunction<
+          void Function(
+            int,
+            ffi.Pointer<wire_uint_8_list>,
+            ffi.Pointer<wire_uint_8_list>,
+          )>();
 
-  int init_frb_dart_api_dl(ffi.Pointer<ffi.Void> obj) {
-    return _init_frb_dart_api_dl(obj);
+  void wire_rust_fetch_private_intents(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> identity_id,
+  ) {
+    return _wire_rust_fetch_private_intents(port_, identity_id);
   }
 
-  late final _init_frb_dart_api_dlPtr =
-      _lookup<ffi.NativeFunction<ffi.IntPtr Function(ffi.Pointer<ffi.Void>)>>(
-    'init_frb_dart_api_dl',
-  );
-  late final _init_frb_dart_api_dl = _init_frb_dart_api_dlPtr
-      .asFunction<int Function(ffi.Pointer<ffi.Void>)>();
+  late final _wire_rust_fetch_private_intentsPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_fetch_private_intents');
+  late final _wire_rust_fetch_private_intents =
+      _wire_rust_fetch_private_intentsPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_rust_initialize_vault(
+  void wire_rust_open_private_intent(
     int port_,
-    ffi.Pointer<wire_uint_8_list> pin,
-    ffi.Pointer<wire_uint_8_list> hw_id,
-    ffi.Pointer<wire_uint_8_list> storage_path,
+    ffi.Pointer<wire_uint_8_list> identity_id,
+    ffi.Pointer<wire_uint_8_list> wrap_json,
   ) {
-    return _wire_rust_initialize_vault(port_, pin, hw_id, storage_path);
+    return _wire_rust_open_private_intent(port_, identity_id, wrap_json);
   }
 
-  late final _wire_rust_initialize_vaultPtr = _lookup<
+  late final _wire_rust_open_private_intentPtr = _lookup<
       ffi.NativeFunction<
           ffi.Void Function(
             ffi.Int64,
             ffi.Pointer<wire_uint_8_list>,
             ffi.Pointer<wire_uint_8_list>,
-            ffi.Pointer<wire_uint_8_list>,
-          )>>('wire_rust_initialize_vault');
-  late final _wire_rust_initialize_vault =
-      _wire_rust_initialize_vaultPtr.asFunction<
+          )>>('wire_rust_open_private_intent');
+  late final _wire_rust_open_private_intent =
+      _wire_rust_open_private_intentPtr.asFunction<
           void Function(
             int,
             ffi.Pointer<wire_uint_8_list>,
             ffi.Pointer<wire_uint_8_list>,
-            ffi.Pointer<wire_uint_8_list>,
           )>();
 
-  void wire_rust_create_identity(
+  void wire_rust_publish_intent_commitment(
     int port_,
-    ffi.Pointer<wire_uint_8_list> label,
+    ffi.Pointer<wire_uint_8_list> signed_json,
   ) {
-    return _wire_rust_create_identity(port_, label);
+    return _wire_rust_publish_intent_commitment(port_, signed_json);
   }
 
-  late final _wire_rust_create_identityPtr = _lookup<
+  late final _wire_rust_publish_intent_commitmentPtr = _lookup<
       ffi.NativeFunction<
-          ffi.Void Function(ffi.Int64,
-              ffi.Pointer<wire_uint_8_list>)>>('wire_rust_create_identity');
-  late final _wire_rust_create_identity = _wire_rust_create_identityPtr
-      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_publish_intent_commitment');
+  late final _wire_rust_publish_intent_commitment =
+      _wire_rust_publish_intent_commitmentPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_rust_get_identities(int port_) {
-    return _wire_rust_get_identities(port_);
+  void wire_rust_list_commitments(int port_) {
+    return _wire_rust_list_commitments(port_);
   }
 
-  late final _wire_rust_get_identitiesPtr =
+  late final _wire_rust_list_commitmentsPtr =
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
-    'wire_rust_get_identities',
+    'wire_rust_list_commitments',
   );
-  late final _wire_rust_get_identities =
-      _wire_rust_get_identitiesPtr.asFunction<void Function(int)>();
+  late final _wire_rust_list_commitments =
+      _wire_rust_list_commitmentsPtr.asFunction<void Function(int)>();
 
-  void wire_rust_scan_qr(
+  void wire_rust_open_commitment(
     int port_,
-    ffi.Pointer<wire_uint_8_list> raw_qr_string,
+    ffi.Pointer<wire_uint_8_list> event_id,
   ) {
-    return _wire_rust_scan_qr(port_, raw_qr_string);
+    return _wire_rust_open_commitment(port_, event_id);
   }
 
-  late final _wire_rust_scan_qrPtr = _lookup<
+  late final _wire_rust_open_commitmentPtr = _lookup<
       ffi.NativeFunction<
           ffi.Void Function(
-              ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_rust_scan_qr');
-  late final _wire_rust_scan_qr = _wire_rust_scan_qrPtr
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_open_commitment');
+  late final _wire_rust_open_commitment = _wire_rust_open_commitmentPtr
       .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_rust_sign_intent(
+  void wire_rust_verify_commitment_opening(
     int port_,
-    ffi.Pointer<wire_uint_8_list> identity_id,
-    ffi.Pointer<wire_uint_8_list> upi_url,
+    ffi.Pointer<wire_uint_8_list> opening_json,
   ) {
-    return _wire_rust_sign_intent(port_, identity_id, upi_url);
+    return _wire_rust_verify_commitment_opening(port_, opening_json);
   }
 
-  late final _wire_rust_sign_intentPtr = _lookup<
+  late final _wire_rust_verify_commitment_openingPtr = _lookup<
       ffi.NativeFunction<
           ffi.Void Function(
             ffi.Int64,
             ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_verify_commitment_opening');
+  late final _wire_rust_verify_commitment_opening =
+      _wire_rust_verify_commitment_openingPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_rust_get_outbox(int port_) {
+    return _wire_rust_get_outbox(port_);
+  }
+
+  late final _wire_rust_get_outboxPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
+    'wire_rust_get_outbox',
+  );
+  late final _wire_rust_get_outbox =
+      _wire_rust_get_outboxPtr.asFunction<void Function(int)>();
+
+  void wire_rust_retry_outbox(int port_) {
+    return _wire_rust_retry_outbox(port_);
+  }
+
+  late final _wire_rust_retry_outboxPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
+    'wire_rust_retry_outbox',
+  );
+  late final _wire_rust_retry_outbox =
+      _wire_rust_retry_outboxPtr.asFunction<void Function(int)>();
+
+  void wire_rust_drop_outbox_item(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> event_id,
+  ) {
+    return _wire_rust_drop_outbox_item(port_, event_id);
+  }
+
+  late final _wire_rust_drop_outbox_itemPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
             ffi.Pointer<wire_uint_8_list>,
-          )>>('wire_rust_sign_intent');
-  late final _wire_rust_sign_intent = _wire_rust_sign_intentPtr.asFunction<
-      void Function(
-        int,
-        ffi.Pointer<wire_uint_8_list>,
-        ffi.Pointer<wire_uint_8_list>,
-      )>();
+          )>>('wire_rust_drop_outbox_item');
+  late final _wire_rust_drop_outbox_item = _wire_rust_drop_outbox_itemPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_rust_publish_to_nostr(
+  void wire_rust_set_publish_policy(
     int port_,
-    ffi.Pointer<wire_uint_8_list> signed_json,
+    ffi.Pointer<wire_uint_8_list> policy,
   ) {
-    return _wire_rust_publish_to_nostr(port_, signed_json);
+    return _wire_rust_set_publish_policy(port_, policy);
   }
 
-  late final _wire_rust_publish_to_nostrPtr = _lookup<
+  late final _wire_rust_set_publish_policyPtr = _lookup<
       ffi.NativeFunction<
-          ffi.Void Function(ffi.Int64,
-              ffi.Pointer<wire_uint_8_list>)>>('wire_rust_publish_to_nostr');
-  late final _wire_rust_publish_to_nostr = _wire_rust_publish_to_nostrPtr
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_set_publish_policy');
+  late final _wire_rust_set_publish_policy = _wire_rust_set_publish_policyPtr
       .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
+  void wire_rust_get_nostr_public_key(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> identity_id,
+  ) {
+    return _wire_rust_get_nostr_public_key(port_, identity_id);
+  }
+
+  late final _wire_rust_get_nostr_public_keyPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_get_nostr_public_key');
+  late final _wire_rust_get_nostr_public_key =
+      _wire_rust_get_nostr_public_keyPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
   void wire_rust_fetch_interaction_history(int port_) {
     return _wire_rust_fetch_interaction_history(port_);
   }
@@ -494,6 +4889,55 @@ class RustCoreWire implements FlutterRustBridgeWireBase {
   late final _wire_rust_fetch_interaction_history =
       _wire_rust_fetch_interaction_historyPtr.asFunction<void Function(int)>();
 
+  void wire_rust_query_interaction_history(
+    int port_,
+    ffi.Pointer<wire_HistoryQuery> query,
+  ) {
+    return _wire_rust_query_interaction_history(port_, query);
+  }
+
+  late final _wire_rust_query_interaction_historyPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_HistoryQuery>,
+          )>>('wire_rust_query_interaction_history');
+  late final _wire_rust_query_interaction_history =
+      _wire_rust_query_interaction_historyPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_HistoryQuery>)>();
+
+  void wire_rust_subscribe_events(
+    int port_,
+    ffi.Pointer<wire_StringList> watch_dids,
+  ) {
+    return _wire_rust_subscribe_events(port_, watch_dids);
+  }
+
+  late final _wire_rust_subscribe_eventsPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_StringList>,
+          )>>('wire_rust_subscribe_events');
+  late final _wire_rust_subscribe_events = _wire_rust_subscribe_eventsPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_StringList>)>();
+
+  void wire_rust_unsubscribe_events(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> handle,
+  ) {
+    return _wire_rust_unsubscribe_events(port_, handle);
+  }
+
+  late final _wire_rust_unsubscribe_eventsPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_unsubscribe_events');
+  late final _wire_rust_unsubscribe_events = _wire_rust_unsubscribe_eventsPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
   void wire_rust_reset_vault(
     int port_,
     ffi.Pointer<wire_uint_8_list> storage_path,
@@ -503,19 +4947,91 @@ class RustCoreWire implements FlutterRustBridgeWireBase {
 
   late final _wire_rust_reset_vaultPtr = _lookup<
       ffi.NativeFunction<
-          ffi.Void Function(ffi.Int64,
-              ffi.Pointer<wire_uint_8_list>)>>('wire_rust_reset_vault');
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_uint_8_list>,
+          )>>('wire_rust_reset_vault');
   late final _wire_rust_reset_vault = _wire_rust_reset_vaultPtr
       .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
+  ffi.Pointer<wire_StringList> new_StringList_0(int len) {
+    return _new_StringList_0(len);
+  }
+
+  late final _new_StringList_0Ptr = _lookup<
+      ffi.NativeFunction<
+          ffi.Pointer<wire_StringList> Function(
+            ffi.Int32,
+          )>>('new_StringList_0');
+  late final _new_StringList_0 = _new_StringList_0Ptr
+      .asFunction<ffi.Pointer<wire_StringList> Function(int)>();
+
+  ffi.Pointer<ffi.Bool> new_box_autoadd_bool_0(bool value) {
+    return _new_box_autoadd_bool_0(value);
+  }
+
+  late final _new_box_autoadd_bool_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Bool> Function(ffi.Bool)>>(
+    'new_box_autoadd_bool_0',
+  );
+  late final _new_box_autoadd_bool_0 = _new_box_autoadd_bool_0Ptr
+      .asFunction<ffi.Pointer<ffi.Bool> Function(bool)>();
+
+  ffi.Pointer<wire_HistoryQuery> new_box_autoadd_history_query_0() {
+    return _new_box_autoadd_history_query_0();
+  }
+
+  late final _new_box_autoadd_history_query_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_HistoryQuery> Function()>>(
+    'new_box_autoadd_history_query_0',
+  );
+  late final _new_box_autoadd_history_query_0 =
+      _new_box_autoadd_history_query_0Ptr
+          .asFunction<ffi.Pointer<wire_HistoryQuery> Function()>();
+
+  ffi.Pointer<ffi.Int32> new_box_autoadd_interaction_type_0(int value) {
+    return _new_box_autoadd_interaction_type_0(value);
+  }
+
+  late final _new_box_autoadd_interaction_type_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>(
+    'new_box_autoadd_interaction_type_0',
+  );
+  late final _new_box_autoadd_interaction_type_0 =
+      _new_box_autoadd_interaction_type_0Ptr
+          .asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+
+  ffi.Pointer<ffi.Uint32> new_box_autoadd_u32_0(int value) {
+    return _new_box_autoadd_u32_0(value);
+  }
+
+  late final _new_box_autoadd_u32_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint32> Function(ffi.Uint32)>>(
+    'new_box_autoadd_u32_0',
+  );
+  late final _new_box_autoadd_u32_0 = _new_box_autoadd_u32_0Ptr
+      .asFunction<ffi.Pointer<ffi.Uint32> Function(int)>();
+
+  ffi.Pointer<ffi.Uint64> new_box_autoadd_u64_0(int value) {
+    return _new_box_autoadd_u64_0(value);
+  }
+
+  late final _new_box_autoadd_u64_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint64> Function(ffi.Uint64)>>(
+    'new_box_autoadd_u64_0',
+  );
+  late final _new_box_autoadd_u64_0 = _new_box_autoadd_u64_0Ptr
+      .asFunction<ffi.Pointer<ffi.Uint64> Function(int)>();
+
   ffi.Pointer<wire_uint_8_list> new_uint_8_list_0(int len) {
     return _new_uint_8_list_0(len);
   }
 
   late final _new_uint_8_list_0Ptr = _lookup<
-          ffi
-          .NativeFunction<ffi.Pointer<wire_uint_8_list> Function(ffi.Int32)>>(
-      'new_uint_8_list_0');
+      ffi.NativeFunction<
+          ffi.Pointer<wire_uint_8_list> Function(
+            ffi.Int32,
+          )>>('new_uint_8_list_0');
   late final _new_uint_8_list_0 = _new_uint_8_list_0Ptr
       .asFunction<ffi.Pointer<wire_uint_8_list> Function(int)>();
 
@@ -533,6 +5049,33 @@ class RustCoreWire implements FlutterRustBridgeWireBase {
 
 final class _Dart_Handle extends ffi.Opaque {}
 
+final class wire_StringList extends ffi.Struct {
+  external ffi.Pointer<ffi.Pointer<wire_uint_8_list>> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_HistoryQuery extends ffi.Struct {
+  external ffi.Pointer<wire_StringList> identity_ids;
+
+  external ffi.Pointer<wire_uint_8_list> counterparty;
+
+  external ffi.Pointer<ffi.Uint64> since;
+
+  external ffi.Pointer<ffi.Uint64> until;
+
+  external ffi.Pointer<ffi.Int32> interaction_type;
+
+  external ffi.Pointer<ffi.Bool> verified;
+
+  external ffi.Pointer<ffi.Uint32> page_size;
+
+  external ffi.Pointer<wire_uint_8_list> cursor;
+
+  external ffi.Pointer<ffi.Uint64> timeout_secs;
+}
+
 final class wire_uint_8_list extends ffi.Struct {
   external ffi.Pointer<ffi.Uint8> ptr;
 
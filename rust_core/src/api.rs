@@ -5,24 +5,41 @@
  * GOAL: Maintain persistent relay connections and prevent 'No Reactor' panics.
  * FIX: Implemented a global STATIC_RUNTIME to keep the Tokio reactor alive.
  */
-
-use crate::persistence::{VaultManager, SatyaVault};
-use crate::crypto::{VaultKey, sign_with_key, verify_with_key};
-use crate::domain::{SatyaIdentity, SignedIntent, IntentPayload, InteractionType, PROTOCOL_VERSION};
+use crate::persistence::{VaultManager, SatyaVault, UnlockSecrets, FactorKind, UnlockPolicy, Compartment};
+use crate::crypto::{VaultKey, sign_with_key, public_key_from_secret, generate_recovery_code};
+use crate::binding::{self, BindingKind};
+use crate::lockout::{AttemptGuard, UnlockStatus};
+use crate::backup;
+use crate::derivation;
+use crate::discovery::{self, ProfileState};
+use crate::rotation;
+use crate::did::{self, DidDocument, DidResolver, KeyResolver, MultiResolver, PeerResolver, SatyaResolver, WebResolver};
+use crate::pairwise;
+use crate::relays::{self, RelayHealth, RelayMonitor};
+use crate::relay_server::{EventStore, LocalRelay};
+use crate::publishing::{self, AckPolicy, PublishResult, PublishStatus, RelayOutcome};
+use crate::outbox::{Outbox, OutboxItem};
+use crate::commitment::{self, CommitmentLedger, LedgerEntry, VerifiedOpening};
+use crate::history::{self, Cursor, HistoryItem, HistoryPage, HistoryQuery};
+use crate::subscriptions::{self, LiveEvent};
+use crate::private::{self, PrivateIntent, KIND_GIFT_WRAP};
+use crate::revocation::{self, RevocationCache, RevocationState};
+use crate::recovery;
+use crate::paper::{self, PaperShare, ShareScan};
+use crate::domain::{SatyaIdentity, IdentityStatus, IdentityTombstone, IdentityRetirement, KeyRecord, PairwiseLink, LinkageProof, RelayConfig, RelayAuthGrant, PinnedKey, KeyRotation, RevocationList, RevokedKey, SignedIntent, IntentPayload, IntentCommitment, IntentOpening, InteractionType, PROTOCOL_VERSION, KIND_IDENTITY_RETIREMENT, KIND_KEY_ROTATION, KIND_REVOCATION_LIST, KIND_DID_DOCUMENT, KIND_INTENT_COMMITMENT};
 use crate::parser::parse_upi_url;
 use anyhow::{Result, anyhow};
+use std::collections::HashMap;
 use std::sync::Mutex;
 use once_cell::sync::Lazy;
-use nostr_sdk::prelude::{Keys, Client, EventBuilder, Kind, Tag, Filter, Options}; 
+use nostr_sdk::prelude::{Keys, Client, ClientMessage, Event, EventBuilder, EventId, JsonUtil, Kind, Filter, InternalSubscriptionId, Options, Relay, RelayMessage, RelayOptions, RelayPoolNotification, RelayStatus, Tag, Timestamp, ToBech32, Url, XOnlyPublicKey, nip04};
 use std::time::{SystemTime, UNIX_EPOCH, Duration};
 use std::path::PathBuf;
 use std::fs;
 use uuid::Uuid;
 use tokio::runtime::Runtime;
-
-// Cryptographic trait disambiguation
-use hmac::Mac; 
-use sha2::Sha512;
+use tokio::sync::{broadcast, oneshot};
+use flutter_rust_bridge::StreamSink;
 
 // Persistent Global Runtime and Client
 static STATIC_RUNTIME: Lazy<Runtime> = Lazy::new(|| {
@@ -34,71 +51,1216 @@ static STATIC_RUNTIME: Lazy<Runtime> = Lazy::new(|| {
 
 static NOSTR_CLIENT: Lazy<Mutex<Option<Client>>> = Lazy::new(|| Mutex::new(None));
 
-static VAULT_STATE: Lazy<Mutex<Option<(VaultManager, SatyaVault, String, String)>>> = 
-    Lazy::new(|| Mutex::new(None));
+/// Unlocked vault held in memory for the lifetime of the session.
+struct VaultSession {
+    manager: VaultManager,
+    vault: SatyaVault,
+    data_key: VaultKey,
+    hw_id: String,
+    /// `Decoy` when opened with the duress PIN. Key-slot changes are then
+    /// simulated so the UI behaves exactly as it would on the real vault.
+    compartment: Compartment,
+}
+
+impl VaultSession {
+    fn save(&self) -> Result<()> {
+        self.manager.save(self.compartment, &self.data_key, &self.vault)
+    }
+
+    fn is_decoy(&self) -> bool {
+        self.compartment == Compartment::Decoy
+    }
+}
+
+static VAULT_STATE: Lazy<Mutex<Option<VaultSession>>> = Lazy::new(|| Mutex::new(None));
+
+/// Ephemeral keys guardians seal released shares to during a social recovery.
+static RECOVERY_KEYS: Lazy<Mutex<Option<Keys>>> = Lazy::new(|| Mutex::new(None));
+
+/// Configured relays and their observed health. Never held across an await.
+static RELAY_MONITOR: Lazy<Mutex<RelayMonitor>> = Lazy::new(|| Mutex::new(RelayMonitor::default()));
+
+/// Acknowledgments required for `rust_publish_to_nostr` to report success.
+static PUBLISH_POLICY: Lazy<Mutex<AckPolicy>> = Lazy::new(|| Mutex::new(AckPolicy::default()));
+
+/// Signed events waiting for a relay to accept them. Never held across an await.
+static OUTBOX: Lazy<Mutex<Outbox>> = Lazy::new(|| Mutex::new(Outbox::ephemeral()));
+
+/// Openings of published intent commitments.
+static LEDGER: Lazy<Mutex<CommitmentLedger>> = Lazy::new(|| Mutex::new(CommitmentLedger::ephemeral()));
+
+/// Live subscriptions by handle: their filters (re-sent to relays added
+/// later) and the signal that stops their dispatcher.
+static SUBSCRIPTIONS: Lazy<Mutex<HashMap<String, LiveSubscription>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+struct LiveSubscription {
+    filters: Vec<Filter>,
+    cancel: oneshot::Sender<()>,
+}
+
+/// The embedded relay, while one is running.
+static LOCAL_RELAY: Lazy<Mutex<Option<LocalRelay>>> = Lazy::new(|| Mutex::new(None));
+
+/// SLIP-39 shares collected through `rust_scan_paper_share` for a paper restore.
+static SCANNED_SHARES: Lazy<Mutex<Vec<String>>> = Lazy::new(|| Mutex::new(Vec::new()));
 
 pub fn rust_initialize_vault(pin: String, hw_id: String, storage_path: String) -> Result<bool> {
     let manager = VaultManager::new(&storage_path);
-    let key = VaultKey::from_pin(&pin, b"satya_salt_v1")?;
-    
-    match manager.load(&key, hw_id.as_bytes()) {
-        Ok(mut vault) => {
-            if vault.master_seed.is_empty() {
-                vault.master_seed = (0..32).map(|_| rand::random::<u8>()).collect();
-                manager.atomic_save(&key, hw_id.as_bytes(), &vault)?;
+
+    let (vault, data_key, compartment) = if manager.exists() {
+        guarded(&manager, &hw_id, || manager.unlock_compartment(&UnlockSecrets::pin(&pin, &hw_id)))?
+    } else {
+        let vault = SatyaVault::default();
+        let binding = binding::default_for(&hw_id, manager.base_path());
+        let data_key = manager.create(&pin, binding.as_ref(), &vault)?;
+        (vault, data_key, Compartment::Primary)
+    };
+    open_session(VaultSession { manager, vault, data_key, hw_id, compartment })
+}
+
+/// Unlocks an existing vault with any enrolled combination of factors
+/// (PIN, recovery code, key file; the hardware binding is implicit).
+pub fn rust_unlock_vault(storage_path: String, hw_id: String, pin: Option<String>, recovery_code: Option<String>, key_file_path: Option<String>) -> Result<bool> {
+    let manager = VaultManager::new(&storage_path);
+    let secrets = UnlockSecrets {
+        pin: pin.as_deref(),
+        recovery_code: recovery_code.as_deref(),
+        key_file: key_file_path.as_deref(),
+        hw_id: &hw_id,
+    };
+    let (vault, data_key, compartment) = guarded(&manager, &hw_id, || manager.unlock_compartment(&secrets))?;
+    open_session(VaultSession { manager, vault, data_key, hw_id, compartment })
+}
+
+/// Creates a vault on a new device from a 24-word mnemonic backup. The seed is
+/// identical, so identities re-created in the same order get the same SLIP-0010
+/// keys. New identities never use the pre-11.1 `legacy/i` scheme: those come
+/// back through `rust_discover_identities`, which also scans legacy paths.
+/// `mask_passphrase` is the one given to `rust_export_mnemonic`.
+pub fn rust_restore_from_mnemonic(mnemonic: String, mask_passphrase: Option<String>, pin: String, hw_id: String, storage_path: String) -> Result<bool> {
+    let manager = VaultManager::new(&storage_path);
+    if manager.exists() { return Err(anyhow!("Vault already exists; reset it before restoring")); }
+    let master_seed = backup::mnemonic_to_seed(&mnemonic, mask_passphrase.as_deref())?;
+    restore_vault(manager, master_seed, pin, hw_id)
+}
+
+/// Creates a fresh vault around a recovered master seed.
+fn restore_vault(manager: VaultManager, master_seed: Vec<u8>, pin: String, hw_id: String) -> Result<bool> {
+    let vault = SatyaVault { master_seed, ..Default::default() };
+    let binding = binding::default_for(&hw_id, manager.base_path());
+    let data_key = manager.create(&pin, binding.as_ref(), &vault)?;
+    open_session(VaultSession { manager, vault, data_key, hw_id, compartment: Compartment::Primary })
+}
+
+/// Installs an unlocked vault as the active session and brings up the swarm client.
+fn open_session(mut session: VaultSession) -> Result<bool> {
+    if session.vault.master_seed.is_empty() {
+        session.vault.master_seed = (0..32).map(|_| rand::random::<u8>()).collect();
+        session.save()?;
+    }
+
+    RELAY_MONITOR.lock().unwrap().configure(session.vault.relays.clone());
+    // A decoy session must never touch the real outbox or ledger files.
+    *OUTBOX.lock().unwrap() = match session.compartment {
+        Compartment::Primary => Outbox::new(session.manager.base_path(), &session.data_key)?,
+        Compartment::Decoy => Outbox::ephemeral(),
+    };
+    *LEDGER.lock().unwrap() = match session.compartment {
+        Compartment::Primary => CommitmentLedger::new(session.manager.base_path(), &session.data_key)?,
+        Compartment::Decoy => CommitmentLedger::ephemeral(),
+    };
+    let mut state = VAULT_STATE.lock().unwrap();
+    *state = Some(session);
+    ensure_client();
+    Ok(true)
+}
+
+const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Initializes the Swarm Client within the Persistent Runtime, or brings an
+/// existing one in line with the configured relay set.
+fn ensure_client() {
+    let mut client_lock = NOSTR_CLIENT.lock().unwrap();
+    let _guard = STATIC_RUNTIME.enter();
+    if let Some(client) = &*client_lock {
+        STATIC_RUNTIME.block_on(sync_relays(client));
+    } else {
+        let my_keys = Keys::generate();
+        let opts = Options::new().wait_for_send(false);
+        let client = Client::with_opts(&my_keys, opts);
+
+        STATIC_RUNTIME.block_on(sync_relays(&client));
+        STATIC_RUNTIME.spawn(monitor_relays(client.clone()));
+        STATIC_RUNTIME.spawn(answer_auth_challenges(client.clone()));
+        *client_lock = Some(client);
+    }
+}
+
+/// Adds, removes and re-flags pool relays to match RELAY_MONITOR. The pool's
+/// own reconnect is off; `monitor_relays` retries with backoff instead.
+async fn sync_relays(client: &Client) {
+    let configs = RELAY_MONITOR.lock().unwrap().configs();
+    let current = client.relays().await;
+    for url in current.keys() {
+        if !configs.iter().any(|c| relays::same_relay(&c.url, url.as_str())) {
+            let _ = client.remove_relay(url.as_str()).await;
+        }
+    }
+    for config in &configs {
+        let writable = RELAY_MONITOR.lock().unwrap().is_writable(&config.url);
+        match current.iter().find(|(url, _)| relays::same_relay(&config.url, url.as_str())) {
+            Some((_, relay)) => {
+                relay.opts().update_read(config.read);
+                relay.opts().update_write(writable);
+            },
+            None => {
+                let opts = RelayOptions::new().read(config.read).write(writable).reconnect(false);
+                if client.add_relay_with_opts(config.url.as_str(), opts).await.is_ok() {
+                    let _ = client.connect_relay(config.url.as_str()).await;
+                    if let Ok(relay) = client.relay(config.url.as_str()).await {
+                        let live: Vec<(String, Vec<Filter>)> = SUBSCRIPTIONS.lock().unwrap().iter()
+                            .map(|(handle, s)| (handle.clone(), s.filters.clone()))
+                            .collect();
+                        for (handle, filters) in live {
+                            let _ = relay.subscribe_with_internal_id(InternalSubscriptionId::Custom(handle), filters, None).await;
+                        }
+                    }
+                }
+            },
+        }
+    }
+}
+
+/// Background health check: records latency of connected relays, reconnects
+/// dropped ones once their backoff elapses and keeps the write set current.
+/// While any relay is up, due outbox items are retried.
+async fn monitor_relays(client: Client) {
+    loop {
+        tokio::time::sleep(HEALTH_CHECK_INTERVAL).await;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default();
+        for (url, relay) in client.relays().await {
+            let status = relay.status().await;
+            match status {
+                RelayStatus::Connected => {
+                    let latency = relay.stats().latency().await.map(|d| d.as_millis() as u64);
+                    RELAY_MONITOR.lock().unwrap().record_connected(url.as_str(), latency);
+                },
+                RelayStatus::Pending | RelayStatus::Connecting => {},
+                _ => {
+                    let retry = {
+                        let mut monitor = RELAY_MONITOR.lock().unwrap();
+                        let due = monitor.should_retry(url.as_str(), now);
+                        if due { monitor.record_failure(url.as_str(), &format!("Relay {}", status), now); }
+                        due
+                    };
+                    if retry { relay.connect(false).await; }
+                },
             }
-            
-            let mut state = VAULT_STATE.lock().unwrap();
-            *state = Some((manager, vault, pin, hw_id));
-            
-            // Initialize Swarm Client within the Persistent Runtime
-            let mut client_lock = NOSTR_CLIENT.lock().unwrap();
-            if client_lock.is_none() {
-                let _guard = STATIC_RUNTIME.enter();
-                let my_keys = Keys::generate();
-                let opts = Options::new().wait_for_send(false);
-                let client = Client::with_opts(&my_keys, opts);
-                
-                STATIC_RUNTIME.block_on(async {
-                    let _ = client.add_relay("wss://relay.damus.io").await;
-                    let _ = client.add_relay("wss://relay.nostr.band").await;
-                    client.connect().await;
-                });
-                *client_lock = Some(client);
+            let writable = RELAY_MONITOR.lock().unwrap().is_writable(url.as_str());
+            relay.opts().update_write(writable);
+        }
+        if RELAY_MONITOR.lock().unwrap().report().iter().any(|r| r.connected) {
+            flush_outbox(&client).await;
+        }
+    }
+}
+
+/// The relay set stored in the vault (the defaults until one is configured).
+pub fn rust_list_relays() -> Result<Vec<RelayConfig>> {
+    let state = VAULT_STATE.lock().unwrap();
+    if state.is_some() { Ok(RELAY_MONITOR.lock().unwrap().configs()) }
+    else { Err(anyhow!("Vault Locked")) }
+}
+
+/// Adds a relay, or updates its read/write flags if it is already present.
+pub fn rust_add_relay(url: String, read: bool, write: bool) -> Result<bool> {
+    let url = relays::normalize_url(&url)?;
+    update_relays(|configs| {
+        match configs.iter_mut().find(|c| relays::same_relay(&c.url, &url)) {
+            Some(config) => { config.read = read; config.write = write; },
+            None => configs.push(RelayConfig { url, read, write }),
+        }
+        Ok(())
+    })
+}
+
+pub fn rust_remove_relay(url: String) -> Result<bool> {
+    update_relays(|configs| {
+        let before = configs.len();
+        configs.retain(|c| !relays::same_relay(&c.url, url.trim()));
+        if configs.len() == before { return Err(anyhow!("Relay not found")); }
+        if configs.is_empty() { return Err(anyhow!("Cannot remove the last relay")); }
+        Ok(())
+    })
+}
+
+/// Connection state, latency, last error and write-set membership per relay.
+pub fn rust_get_relay_status() -> Result<Vec<RelayHealth>> {
+    Ok(RELAY_MONITOR.lock().unwrap().report())
+}
+
+/// Applies `change` to the vault's relay set, saves it and re-syncs the pool.
+fn update_relays(change: impl FnOnce(&mut Vec<RelayConfig>) -> Result<()>) -> Result<bool> {
+    let mut state = VAULT_STATE.lock().unwrap();
+    if let Some(session) = &mut *state {
+        let mut configs = RELAY_MONITOR.lock().unwrap().configs();
+        change(&mut configs)?;
+        session.vault.relay_auth.retain(|g| configs.iter().any(|c| relays::same_relay(&c.url, &g.url)));
+        session.vault.relays = configs.clone();
+        session.save()?;
+        RELAY_MONITOR.lock().unwrap().configure(configs);
+        ensure_client();
+        Ok(true)
+    } else { Err(anyhow!("Vault Locked")) }
+}
+
+/// Approves `identity_id` to answer `url`'s NIP-42 AUTH challenges, or
+/// withdraws the approval with `None`. A pending challenge is answered right
+/// away; on withdrawal an authenticated connection is dropped so the relay
+/// forgets the identity.
+pub fn rust_set_relay_auth(url: String, identity_id: Option<String>) -> Result<bool> {
+    let url = relays::normalize_url(&url)?;
+    {
+        let mut state = VAULT_STATE.lock().unwrap();
+        let Some(session) = &mut *state else { return Err(anyhow!("Vault Locked")) };
+        if !RELAY_MONITOR.lock().unwrap().configs().iter().any(|c| relays::same_relay(&c.url, &url)) {
+            return Err(anyhow!("Relay not found"));
+        }
+        let vault = &mut session.vault;
+        if let Some(identity_id) = &identity_id {
+            let identity = vault.identities.iter().find(|i| &i.id == identity_id).ok_or_else(|| anyhow!("Identity not found"))?;
+            if identity.status == IdentityStatus::Archived { return Err(anyhow!("Identity archived")); }
+        }
+        vault.relay_auth.retain(|g| !relays::same_relay(&g.url, &url));
+        if let Some(identity_id) = &identity_id {
+            vault.relay_auth.push(RelayAuthGrant { url: url.clone(), identity_id: identity_id.clone() });
+        }
+        session.save()?;
+    }
+    let authenticated_as = RELAY_MONITOR.lock().unwrap().report().into_iter()
+        .find(|r| relays::same_relay(&r.url, &url))
+        .and_then(|r| r.authenticated_as);
+    let client = NOSTR_CLIENT.lock().unwrap().clone();
+    if let Some(client) = client {
+        let _guard = STATIC_RUNTIME.enter();
+        STATIC_RUNTIME.block_on(async {
+            if authenticated_as.is_some() && authenticated_as != identity_id {
+                // A fresh connection gets a fresh challenge, answered by the new approval if any.
+                // The relay is replaced rather than reconnected: the old connection's
+                // teardown would otherwise close the new one.
+                let _ = client.remove_relay(url.as_str()).await;
+                RELAY_MONITOR.lock().unwrap().record_auth_dropped(&url);
+                sync_relays(&client).await;
+            } else {
+                authenticate_relay(&client, &url).await;
             }
-            
-            Ok(true)
+        });
+    }
+    Ok(true)
+}
+
+/// Identities approved for relay authentication, one per relay at most.
+pub fn rust_list_relay_auth() -> Result<Vec<RelayAuthGrant>> {
+    let state = VAULT_STATE.lock().unwrap();
+    if let Some(session) = &*state { Ok(session.vault.relay_auth.clone()) }
+    else { Err(anyhow!("Vault Locked")) }
+}
+
+/// Records NIP-42 challenges as relays send them and answers those of
+/// relays with an approved identity.
+async fn answer_auth_challenges(client: Client) {
+    let mut notifications = client.notifications();
+    loop {
+        match notifications.recv().await {
+            Ok(RelayPoolNotification::Message { relay_url, message: RelayMessage::Auth { challenge } }) => {
+                RELAY_MONITOR.lock().unwrap().record_auth_challenge(relay_url.as_str(), &challenge);
+                let client = client.clone();
+                tokio::spawn(async move { authenticate_relay(&client, relay_url.as_str()).await });
+            },
+            Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+            Err(broadcast::error::RecvError::Closed) => break,
+        }
+    }
+}
+
+/// Answers the relay's pending challenge with the Nostr key of the identity
+/// approved for it. Without an approval nothing is sent.
+async fn authenticate_relay(client: &Client, url: &str) {
+    let Some(challenge) = RELAY_MONITOR.lock().unwrap().auth_challenge(url) else { return };
+    let Ok(Some((identity_id, keys))) = tokio::task::block_in_place(|| auth_identity(url)) else { return };
+    let Ok(relay) = client.relay(url).await else { return };
+    let Ok(event) = EventBuilder::auth(challenge.clone(), relay.url()).to_event(&keys) else { return };
+    let id = event.id;
+    let notifications = client.notifications();
+    let result = match relay.send_msg(ClientMessage::new_auth(event), None).await {
+        Err(e) => Err(e.to_string()),
+        Ok(()) => match await_ok(&relay.url(), id, notifications).await {
+            Ok(Some((true, _))) => Ok(()),
+            Ok(Some((false, message))) => Err(format!("AUTH rejected: {}", message)),
+            Ok(None) => Err("Notification channel closed".into()),
+            Err(_) => Err("No reply to AUTH".into()),
         },
-        Err(e) => Err(anyhow!("{}", e))
+    };
+    RELAY_MONITOR.lock().unwrap().record_auth(url, &challenge, &identity_id, result);
+}
+
+/// The identity approved to authenticate to `url` and its Nostr keys.
+fn auth_identity(url: &str) -> Result<Option<(String, Keys)>> {
+    let (identity_id, did) = {
+        let state = VAULT_STATE.lock().unwrap();
+        let Some(session) = &*state else { return Ok(None) };
+        let vault = &session.vault;
+        let Some(grant) = vault.relay_auth.iter().find(|g| relays::same_relay(&g.url, url)) else { return Ok(None) };
+        let Some(identity) = vault.identities.iter().find(|i| i.id == grant.identity_id && i.status != IdentityStatus::Archived) else { return Ok(None) };
+        (identity.id.clone(), identity.did.clone())
+    };
+    Ok(nostr_keys_for(&did)?.map(|keys| (identity_id, keys)))
+}
+
+/// Write relays of the configured set, as advertised in DID Documents.
+fn write_relays() -> Vec<String> {
+    RELAY_MONITOR.lock().unwrap().configs().into_iter().filter(|c| c.write).map(|c| c.url).collect()
+}
+
+/// Starts the embedded relay on `bind_address` (`127.0.0.1:0` picks a free
+/// port, `0.0.0.0:<port>` serves the LAN). With `storage_path` its events
+/// are kept in `satya_relay/events.jsonl` there, otherwise only in memory.
+/// Returns the relay URL; `rust_add_relay` puts it in the relay set.
+pub fn rust_start_local_relay(bind_address: String, storage_path: Option<String>) -> Result<String> {
+    let mut running = LOCAL_RELAY.lock().unwrap();
+    if let Some(relay) = &*running { return Ok(relay.url()); }
+    let store = match storage_path {
+        Some(storage_path) => {
+            let mut path = PathBuf::from(storage_path);
+            path.push("satya_relay/events.jsonl");
+            EventStore::open(&path)?
+        },
+        None => EventStore::in_memory(),
+    };
+    let relay = STATIC_RUNTIME.block_on(LocalRelay::start(&bind_address, store))?;
+    let url = relay.url();
+    *running = Some(relay);
+    Ok(url)
+}
+
+/// Stops the embedded relay. Returns whether one was running.
+pub fn rust_stop_local_relay() -> Result<bool> {
+    Ok(LOCAL_RELAY.lock().unwrap().take().is_some())
+}
+
+/// Issues a new printable recovery code and enrolls it (with the PIN) as a
+/// re-binding slot. Any previously issued code stops working.
+pub fn rust_generate_recovery_code(pin: String) -> Result<String> {
+    let state = VAULT_STATE.lock().unwrap();
+    if let Some(session) = &*state {
+        authorize(session, &pin, None)?;
+        let code = generate_recovery_code();
+        if !session.is_decoy() {
+            session.manager.enroll_recovery(&session.data_key, &pin, &code)?;
+        }
+        Ok(code)
+    } else { Err(anyhow!("Vault Locked")) }
+}
+
+/// Enrolls new unlock factors (`pin`, `recovery_code`, `key_file`, `hardware`).
+/// With `require_all` the factors form one combined slot, otherwise each
+/// factor opens the vault on its own. Returns the recovery code if one was issued.
+pub fn rust_enroll_unlock_factors(pin: String, factors: Vec<String>, require_all: bool, key_file_path: Option<String>) -> Result<Option<String>> {
+    let state = VAULT_STATE.lock().unwrap();
+    if let Some(session) = &*state {
+        authorize(session, &pin, key_file_path.as_deref())?;
+        let kinds = factors.iter().map(|f| f.parse()).collect::<Result<Vec<FactorKind>>>()?;
+        let code = kinds.contains(&FactorKind::RecoveryCode).then(generate_recovery_code);
+        let secrets = UnlockSecrets {
+            pin: Some(&pin),
+            recovery_code: code.as_deref(),
+            key_file: key_file_path.as_deref(),
+            hw_id: &session.hw_id,
+        };
+        let binding = binding::default_for(&session.hw_id, session.manager.base_path());
+        let policy = if require_all { UnlockPolicy::AllOf } else { UnlockPolicy::AnyOf };
+        if session.is_decoy() { return Ok(code); }
+        session.manager.enroll(&session.data_key, &kinds, policy, &secrets, Some(binding.as_ref()))?;
+        Ok(code)
+    } else { Err(anyhow!("Vault Locked")) }
+}
+
+/// Removes every key slot that depends on `factor`.
+pub fn rust_revoke_unlock_factor(pin: String, factor: String, key_file_path: Option<String>) -> Result<bool> {
+    let state = VAULT_STATE.lock().unwrap();
+    if let Some(session) = &*state {
+        authorize(session, &pin, key_file_path.as_deref())?;
+        let kind: FactorKind = factor.parse()?;
+        if !session.is_decoy() {
+            session.manager.revoke(kind, &session.hw_id)?;
+        }
+        Ok(true)
+    } else { Err(anyhow!("Vault Locked")) }
+}
+
+/// Lists the factor combinations able to open the vault, e.g. `pin+hardware`.
+pub fn rust_list_unlock_factors() -> Result<Vec<String>> {
+    let state = VAULT_STATE.lock().unwrap();
+    if let Some(session) = &*state { session.manager.slot_descriptions() }
+    else { Err(anyhow!("Vault Locked")) }
+}
+
+/// Changes the vault PIN. The old PIN is verified, the new one is derived with
+/// fresh salt and KDF parameters, and the current session is closed: the
+/// caller must unlock again with the new PIN. Every slot that includes the
+/// PIN is re-wrapped, so slots that also need a recovery code or key file
+/// require those too.
+pub fn rust_change_pin(old_pin: String, new_pin: String, recovery_code: Option<String>, key_file_path: Option<String>) -> Result<bool> {
+    let mut state = VAULT_STATE.lock().unwrap();
+    if let Some(session) = &*state {
+        if new_pin.is_empty() { return Err(anyhow!("PIN must not be empty")); }
+        if session.is_decoy() {
+            guarded(&session.manager, &session.hw_id, || session.manager.change_decoy_pin(&old_pin, &new_pin))?;
+        } else {
+            let secrets = UnlockSecrets {
+                recovery_code: recovery_code.as_deref(),
+                key_file: key_file_path.as_deref(),
+                ..UnlockSecrets::pin(&old_pin, &session.hw_id)
+            };
+            guarded(&session.manager, &session.hw_id, || session.manager.change_pin(&session.data_key, &secrets, &new_pin))?;
+        }
+        *state = None;
+        Ok(true)
+    } else { Err(anyhow!("Vault Locked")) }
+}
+
+/// Re-proves possession of the vault before changing its key slots.
+fn authorize(session: &VaultSession, pin: &str, key_file_path: Option<&str>) -> Result<()> {
+    if session.is_decoy() {
+        return guarded(&session.manager, &session.hw_id, || session.manager.verify_decoy_pin(pin));
     }
+    let secrets = UnlockSecrets { key_file: key_file_path, ..UnlockSecrets::pin(pin, &session.hw_id) };
+    guarded(&session.manager, &session.hw_id, || session.manager.unlock_with(&secrets))?;
+    Ok(())
+}
+
+/// Runs a vault unlock under the persisted brute-force guard. Hitting a
+/// configured failure limit crypto-erases the vault.
+fn guarded<T>(manager: &VaultManager, hw_id: &str, unlock: impl FnOnce() -> Result<T>) -> Result<T> {
+    let guard = AttemptGuard::new(manager, hw_id);
+    guard.attempt(unlock, || manager.crypto_erase())
+}
+
+/// Configures a duress PIN that opens a separate decoy vault through the
+/// normal unlock path. Replaces any existing decoy.
+pub fn rust_configure_duress_pin(pin: String, duress_pin: String) -> Result<bool> {
+    let state = VAULT_STATE.lock().unwrap();
+    if let Some(session) = &*state {
+        authorize(session, &pin, None)?;
+        if duress_pin.is_empty() || duress_pin == pin { return Err(anyhow!("Duress PIN must differ from the vault PIN")); }
+        if !session.is_decoy() {
+            session.manager.set_duress_pin(&duress_pin)?;
+        }
+        Ok(true)
+    } else { Err(anyhow!("Vault Locked")) }
+}
+
+/// Removes the decoy vault; the shadow region is refilled with noise.
+pub fn rust_clear_duress_pin(pin: String) -> Result<bool> {
+    let state = VAULT_STATE.lock().unwrap();
+    if let Some(session) = &*state {
+        authorize(session, &pin, None)?;
+        if !session.is_decoy() {
+            session.manager.clear_duress_pin()?;
+        }
+        Ok(true)
+    } else { Err(anyhow!("Vault Locked")) }
+}
+
+/// Exports the master seed as a 24-word BIP39 mnemonic, optionally masked
+/// with a passphrase that must also be supplied on restore. The mask is
+/// Satya's own scheme, not a BIP39 passphrase: other wallets cannot restore
+/// masked words.
+pub fn rust_export_mnemonic(pin: String, mask_passphrase: Option<String>) -> Result<String> {
+    let state = VAULT_STATE.lock().unwrap();
+    if let Some(session) = &*state {
+        authorize(session, &pin, None)?;
+        backup::seed_to_mnemonic(&session.vault.master_seed, mask_passphrase.as_deref())
+    } else { Err(anyhow!("Vault Locked")) }
+}
+
+/// Confirms a user-typed mnemonic (and mask passphrase) matches the vault seed.
+pub fn rust_verify_mnemonic(mnemonic: String, mask_passphrase: Option<String>) -> Result<bool> {
+    let state = VAULT_STATE.lock().unwrap();
+    if let Some(session) = &*state {
+        backup::verify_mnemonic(&session.vault.master_seed, &mnemonic, mask_passphrase.as_deref())
+    } else { Err(anyhow!("Vault Locked")) }
+}
+
+/// Failed attempts and remaining backoff, for the lock screen.
+pub fn rust_get_unlock_status(storage_path: String, hw_id: String) -> Result<UnlockStatus> {
+    let manager = VaultManager::new(&storage_path);
+    Ok(AttemptGuard::new(&manager, &hw_id).status())
+}
+
+/// Crypto-erases the vault after `limit` consecutive failures; `None` disables.
+pub fn rust_set_wipe_after(pin: String, limit: Option<u32>) -> Result<bool> {
+    let state = VAULT_STATE.lock().unwrap();
+    if let Some(session) = &*state {
+        authorize(session, &pin, None)?;
+        if session.is_decoy() { return Ok(true); }
+        AttemptGuard::new(&session.manager, &session.hw_id).set_wipe_after(limit)?;
+        Ok(true)
+    } else { Err(anyhow!("Vault Locked")) }
+}
+
+/// Switches the open vault to another hardware binding strategy:
+/// `none`, `device_id`, `key_file` (needs `key_file_path`) or `keystore`.
+pub fn rust_set_hardware_binding(pin: String, strategy: String, key_file_path: Option<String>) -> Result<bool> {
+    let state = VAULT_STATE.lock().unwrap();
+    if let Some(session) = &*state {
+        authorize(session, &pin, None)?;
+        let kind: BindingKind = strategy.parse()?;
+        let binding = binding::create(kind, &session.hw_id, key_file_path.as_deref(), session.manager.base_path())?;
+        if session.is_decoy() { return Ok(true); }
+        session.manager.set_binding(&session.data_key, &pin, &session.hw_id, binding.as_ref())?;
+        Ok(true)
+    } else { Err(anyhow!("Vault Locked")) }
+}
+
+/// Re-binds a vault whose hardware binding no longer matches (new device id,
+/// lost key file) using the PIN plus recovery code, then unlocks it.
+pub fn rust_rebind_device(pin: String, recovery_code: String, hw_id: String, storage_path: String, strategy: String, key_file_path: Option<String>) -> Result<bool> {
+    let manager = VaultManager::new(&storage_path);
+    let kind: BindingKind = strategy.parse()?;
+    let binding = binding::create(kind, &hw_id, key_file_path.as_deref(), manager.base_path())?;
+    let guard = AttemptGuard::new(&manager, &hw_id);
+    let (vault, data_key) = guard.attempt_recovery(|| manager.rebind(&pin, &recovery_code, &hw_id, binding.as_ref()), || manager.crypto_erase())?;
+    open_session(VaultSession { manager, vault, data_key, hw_id, compartment: Compartment::Primary })
 }
 
 pub fn rust_create_identity(label: String) -> Result<SatyaIdentity> {
     let mut state = VAULT_STATE.lock().unwrap();
-    if let Some((manager, vault, pin, hw_id)) = &mut *state {
-        let index = vault.identities.len();
-        // Explicit trait usage for derivation
-        let mut mac = <hmac::SimpleHmac<Sha512> as hmac::Mac>::new_from_slice(&vault.master_seed)
-            .map_err(|_| anyhow!("Derivation Error"))?;
-        mac.update(format!("satya_identity_{}", index).as_bytes());
-        let result = mac.finalize().into_bytes();
-        let priv_key = result[..32].to_vec();
+    if let Some(session) = &mut *state {
+        let vault = &mut session.vault;
+        let derivation_path = derivation::identity_path(vault.next_index);
+        let priv_key = derivation::derive_identity_key(&vault.master_seed, &derivation_path)?;
+        vault.next_index += 1;
         
         let id_uuid = Uuid::new_v4().to_string();
-        let new_id = SatyaIdentity { id: id_uuid.clone(), label, did: format!("did:satya:{}", id_uuid) };
+        let new_id = SatyaIdentity { id: id_uuid.clone(), label, did: format!("did:satya:{}", id_uuid), derivation_path, status: IdentityStatus::Active };
         
+        let profile = discovery::seal_profile(&vault.master_seed, &new_id, &priv_key, ProfileState::Live)?;
         vault.identities.push(new_id.clone());
         vault.private_keys.insert(id_uuid, priv_key);
         
-        let key = VaultKey::from_pin(pin, b"satya_salt_v1")?;
-        manager.atomic_save(&key, hw_id.as_bytes(), vault)?;
+        session.save()?;
+        // A decoy identity must leave no trace on the relays or in the real revocation cache.
+        if session.is_decoy() { return Ok(new_id); }
+        let keys = identity_nostr_keys(&session.vault, &new_id.did)?;
+        // Best effort: a restore can only find identities whose profile reached a relay.
+        let _ = publish_profile(&keys, profile);
+        // An empty list announces the recovery key before it is ever needed.
+        let _ = issue_revocation_list(session, &new_id.id, |_| {});
         Ok(new_id)
     } else { Err(anyhow!("Vault Locked")) }
 }
 
+/// (Re-)publishes the encrypted profile of an identity so that a seed
+/// restore on another device can rediscover it and its label.
+pub fn rust_publish_identity_profile(identity_id: String) -> Result<bool> {
+    let state = VAULT_STATE.lock().unwrap();
+    if let Some(VaultSession { vault, .. }) = &*state {
+        let identity = vault.identities.iter().find(|i| i.id == identity_id).ok_or_else(|| anyhow!("Identity not found"))?;
+        let priv_key = vault.private_keys.get(&identity_id).ok_or_else(|| anyhow!("Identity not found"))?;
+        let keys = identity_nostr_keys(vault, &identity.did)?;
+        publish_profile(&keys, discovery::seal_profile(&vault.master_seed, identity, priv_key, ProfileState::Live)?)
+    } else { Err(anyhow!("Vault Locked")) }
+}
+
+fn publish_profile(keys: &Keys, (locator, content): (String, String)) -> Result<bool> {
+    let client_lock = NOSTR_CLIENT.lock().unwrap();
+    if let Some(client) = &*client_lock {
+        let _guard = STATIC_RUNTIME.enter();
+        STATIC_RUNTIME.block_on(async {
+            let event = EventBuilder::new(Kind::from(discovery::PROFILE_KIND), content, vec![Tag::Identifier(locator)]).to_event(keys)?;
+            client.send_event(event).await?;
+            Ok(true)
+        })
+    } else { Err(anyhow!("Network Client Not Initialized")) }
+}
+
+/// Scans derivation indices (up to `gap_limit` empty ones in a row, default
+/// 20) against the relays and adds every identity whose profile is found.
+/// Intended right after `rust_restore_from_mnemonic`. Returns the new identities.
+pub fn rust_discover_identities(gap_limit: Option<u32>) -> Result<Vec<SatyaIdentity>> {
+    // The relay round-trips run without holding the vault lock.
+    let master_seed = match &*VAULT_STATE.lock().unwrap() {
+        Some(session) => session.vault.master_seed.clone(),
+        None => return Err(anyhow!("Vault Locked")),
+    };
+
+    let found = {
+        let client_lock = NOSTR_CLIENT.lock().unwrap();
+        let client = client_lock.as_ref().ok_or_else(|| anyhow!("Network Client Not Initialized"))?;
+        let _guard = STATIC_RUNTIME.enter();
+        discovery::scan(&master_seed, gap_limit.unwrap_or(discovery::DEFAULT_GAP_LIMIT), |locators| {
+            STATIC_RUNTIME.block_on(async {
+                let filter = Filter::new().kind(Kind::from(discovery::PROFILE_KIND)).identifiers(locators.to_vec());
+                let events = client.get_events_of(vec![filter], Some(Duration::from_secs(10))).await?;
+                Ok(events.iter()
+                    .filter_map(|e| e.identifier().map(|d| (d.to_string(), e.content.clone())))
+                    .collect())
+            })
+        })?
+    };
+
+    let mut state = VAULT_STATE.lock().unwrap();
+    let session = state.as_mut().ok_or_else(|| anyhow!("Vault Locked"))?;
+    if session.vault.master_seed != master_seed { return Err(anyhow!("Vault changed during discovery")); }
+    let vault = &mut session.vault;
+    vault.next_index = vault.next_index.max(discovery::next_index_after(&found));
+
+    let mut added = Vec::new();
+    for discovered in found {
+        let identity = discovered.identity;
+        let known = vault.is_path_used(&identity.derivation_path) || vault.identities.iter().any(|i| i.id == identity.id);
+        if known { continue; }
+        if discovered.retired {
+            vault.tombstones.push(IdentityTombstone {
+                id: identity.id,
+                did: identity.did,
+                derivation_path: identity.derivation_path,
+                deleted_at: 0,
+            });
+            continue;
+        }
+        vault.private_keys.insert(identity.id.clone(), discovered.private_key);
+        vault.identities.push(identity.clone());
+        added.push(identity);
+    }
+    session.save()?;
+    Ok(added)
+}
+
+/// This vault's guardian public key (`npub`), given to friends who want the
+/// owner to hold a share of their seed.
+pub fn rust_get_guardian_pubkey() -> Result<String> {
+    let state = VAULT_STATE.lock().unwrap();
+    if let Some(session) = &*state {
+        let keys = recovery::guardian_keys(&session.vault.master_seed)?;
+        Ok(keys.public_key().to_bech32()?)
+    } else { Err(anyhow!("Vault Locked")) }
+}
+
+/// Splits the master seed into `threshold`-of-n shares, one per guardian
+/// public key. Each share is sealed to its guardian, sent as a DM (best
+/// effort) and returned for export in guardian order.
+pub fn rust_create_social_recovery(pin: String, guardians: Vec<String>, threshold: u32) -> Result<Vec<String>> {
+    let (owner, sealed) = {
+        let state = VAULT_STATE.lock().unwrap();
+        let session = state.as_ref().ok_or_else(|| anyhow!("Vault Locked"))?;
+        authorize(session, &pin, None)?;
+        let recipients = guardians.iter().map(|g| recovery::parse_public_key(g)).collect::<Result<Vec<_>>>()?;
+        let total = u8::try_from(recipients.len()).map_err(|_| anyhow!("Too many guardians"))?;
+        let threshold = u8::try_from(threshold).map_err(|_| anyhow!("Invalid threshold"))?;
+        let owner = recovery::guardian_keys(&session.vault.master_seed)?;
+        let shares = recovery::split_seed(&session.vault.master_seed, threshold, total)?;
+        let sealed = shares.iter().zip(recipients)
+            .map(|(share, recipient)| Ok((recipient, recovery::seal_share(share, &owner, &recipient)?)))
+            .collect::<Result<Vec<_>>>()?;
+        (owner, sealed)
+    };
+    for (recipient, envelope) in &sealed {
+        let _ = send_share(&owner, recipient, envelope);
+    }
+    Ok(sealed.into_iter().map(|(_, envelope)| envelope).collect())
+}
+
+/// Share envelopes other users have entrusted to this vault, read from relays.
+pub fn rust_fetch_guardian_shares() -> Result<Vec<String>> {
+    let keys = match &*VAULT_STATE.lock().unwrap() {
+        Some(session) => recovery::guardian_keys(&session.vault.master_seed)?,
+        None => return Err(anyhow!("Vault Locked")),
+    };
+    fetch_shares(&keys)
+}
+
+/// Guardian side: opens a held share and re-seals it to the recovering
+/// device's `requester_pubkey`. The result is also sent as a DM.
+pub fn rust_release_guardian_share(pin: String, envelope: String, requester_pubkey: String) -> Result<String> {
+    let (keys, requester, released) = {
+        let state = VAULT_STATE.lock().unwrap();
+        let session = state.as_ref().ok_or_else(|| anyhow!("Vault Locked"))?;
+        authorize(session, &pin, None)?;
+        let keys = recovery::guardian_keys(&session.vault.master_seed)?;
+        let share = recovery::open_share(&envelope, &keys)?;
+        let requester = recovery::parse_public_key(&requester_pubkey)?;
+        let released = recovery::seal_share(&share, &keys, &requester)?;
+        (keys, requester, released)
+    };
+    let _ = send_share(&keys, &requester, &released);
+    Ok(released)
+}
+
+/// Recovering device: starts a recovery and returns the public key to give
+/// to guardians. Valid until the app restarts.
+pub fn rust_begin_social_recovery() -> Result<String> {
+    let keys = Keys::generate();
+    let npub = keys.public_key().to_bech32()?;
+    *RECOVERY_KEYS.lock().unwrap() = Some(keys);
+    ensure_client();
+    Ok(npub)
+}
+
+/// Released share envelopes that guardians have sent to this device so far.
+pub fn rust_collect_recovery_shares() -> Result<Vec<String>> {
+    let keys = RECOVERY_KEYS.lock().unwrap().clone().ok_or_else(|| anyhow!("No recovery in progress"))?;
+    fetch_shares(&keys)
+}
+
+/// Rebuilds the vault from released shares (DM'd, pasted or scanned).
+pub fn rust_recover_from_shares(envelopes: Vec<String>, pin: String, hw_id: String, storage_path: String) -> Result<bool> {
+    let manager = VaultManager::new(&storage_path);
+    if manager.exists() { return Err(anyhow!("Vault already exists; reset it before restoring")); }
+    let keys = RECOVERY_KEYS.lock().unwrap().clone().ok_or_else(|| anyhow!("No recovery in progress"))?;
+    let shares = envelopes.iter()
+        .filter_map(|e| recovery::open_share(e, &keys).ok())
+        .collect::<Vec<_>>();
+    let master_seed = recovery::recover_seed(&shares)?;
+    restore_vault(manager, master_seed, pin, hw_id)?;
+    *RECOVERY_KEYS.lock().unwrap() = None;
+    Ok(true)
+}
+
+fn send_share(sender: &Keys, recipient: &XOnlyPublicKey, envelope: &str) -> Result<bool> {
+    let client_lock = NOSTR_CLIENT.lock().unwrap();
+    if let Some(client) = &*client_lock {
+        let _guard = STATIC_RUNTIME.enter();
+        STATIC_RUNTIME.block_on(async {
+            let event = EventBuilder::new_encrypted_direct_msg(sender, *recipient, envelope, None)?.to_event(sender)?;
+            client.send_event(event).await?;
+            Ok(true)
+        })
+    } else { Err(anyhow!("Network Client Not Initialized")) }
+}
+
+fn fetch_shares(keys: &Keys) -> Result<Vec<String>> {
+    let secret = keys.secret_key()?;
+    let client_lock = NOSTR_CLIENT.lock().unwrap();
+    if let Some(client) = &*client_lock {
+        let _guard = STATIC_RUNTIME.enter();
+        STATIC_RUNTIME.block_on(async {
+            let filter = Filter::new().kind(Kind::EncryptedDirectMessage).pubkey(keys.public_key());
+            let events = client.get_events_of(vec![filter], Some(Duration::from_secs(10))).await?;
+            let mut envelopes: Vec<String> = events.iter()
+                .filter_map(|e| nip04::decrypt(&secret, &e.pubkey, &e.content).ok())
+                .filter(|content| content.starts_with(recovery::SHARE_PREFIX))
+                .collect();
+            envelopes.dedup();
+            Ok(envelopes)
+        })
+    } else { Err(anyhow!("Network Client Not Initialized")) }
+}
+
+/// Renames an identity and refreshes its discovery profile.
+pub fn rust_rename_identity(identity_id: String, label: String) -> Result<SatyaIdentity> {
+    update_identity(&identity_id, |identity| identity.label = label)
+}
+
+/// Hides an identity from everyday use. Its key is kept; it cannot sign
+/// intents until unarchived.
+pub fn rust_archive_identity(identity_id: String) -> Result<SatyaIdentity> {
+    update_identity(&identity_id, |identity| identity.status = IdentityStatus::Archived)
+}
+
+pub fn rust_unarchive_identity(identity_id: String) -> Result<SatyaIdentity> {
+    update_identity(&identity_id, |identity| identity.status = IdentityStatus::Active)
+}
+
+fn update_identity(identity_id: &str, change: impl FnOnce(&mut SatyaIdentity)) -> Result<SatyaIdentity> {
+    let mut state = VAULT_STATE.lock().unwrap();
+    if let Some(session) = &mut *state {
+        let vault = &mut session.vault;
+        let identity = vault.identities.iter_mut().find(|i| i.id == identity_id).ok_or_else(|| anyhow!("Identity not found"))?;
+        change(identity);
+        let updated = identity.clone();
+        let priv_key = vault.private_keys.get(identity_id).ok_or_else(|| anyhow!("Identity not found"))?;
+        let profile = discovery::seal_profile(&vault.master_seed, &updated, priv_key, ProfileState::Live)?;
+        let keys = identity_nostr_keys(vault, &updated.did)?;
+        session.save()?;
+        if !session.is_decoy() { let _ = publish_profile(&keys, profile); }
+        Ok(updated)
+    } else { Err(anyhow!("Vault Locked")) }
+}
+
+/// Deletes an identity: its key is wiped and a tombstone keeps its
+/// derivation path and DID reserved. The discovery profile is replaced by a
+/// retired one, so a seed restore does not bring it back. With
+/// `publish_retirement`, a retirement statement signed by the identity's
+/// key is published first. Returns the statement JSON, if any.
+pub fn rust_delete_identity(pin: String, identity_id: String, publish_retirement: bool) -> Result<Option<String>> {
+    let mut state = VAULT_STATE.lock().unwrap();
+    if let Some(session) = &mut *state {
+        authorize(session, &pin, None)?;
+        let vault = &mut session.vault;
+        let position = vault.identities.iter().position(|i| i.id == identity_id).ok_or_else(|| anyhow!("Identity not found"))?;
+        let priv_key = vault.private_keys.get(&identity_id).ok_or_else(|| anyhow!("Identity not found"))?.clone();
+        let identity = vault.identities[position].clone();
+        let did = identity.did.clone();
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+
+        let retirement = if publish_retirement { Some(sign_retirement(&identity, &priv_key, now)?) } else { None };
+        let profile = discovery::seal_profile(&vault.master_seed, &identity, &priv_key, ProfileState::Retired)?;
+        let keys = identity_nostr_keys(vault, &did)?;
+
+        vault.identities.remove(position);
+        vault.private_keys.remove(&identity_id);
+        vault.pairwise.retain(|l| l.identity_id != identity_id);
+        vault.relay_auth.retain(|g| g.identity_id != identity_id);
+        vault.tombstones.push(IdentityTombstone {
+            id: identity.id,
+            did: identity.did,
+            derivation_path: identity.derivation_path,
+            deleted_at: now,
+        });
+        session.save()?;
+        if session.is_decoy() { return Ok(retirement); }
+
+        let _ = publish_profile(&keys, profile);
+        if let Some(statement) = &retirement {
+            let _ = publish_event(&keys, KIND_IDENTITY_RETIREMENT, statement.clone(), vec![Tag::Identifier(did)]);
+        }
+        Ok(retirement)
+    } else { Err(anyhow!("Vault Locked")) }
+}
+
+/// Deleted identities kept as tombstones.
+pub fn rust_get_tombstones() -> Result<Vec<IdentityTombstone>> {
+    let state = VAULT_STATE.lock().unwrap();
+    if let Some(session) = &*state { Ok(session.vault.tombstones.clone()) }
+    else { Err(anyhow!("Vault Locked")) }
+}
+
+fn sign_retirement(identity: &SatyaIdentity, priv_key: &[u8], retired_at: u64) -> Result<String> {
+    let mut statement = IdentityRetirement {
+        version: PROTOCOL_VERSION.to_string(),
+        did: identity.did.clone(),
+        public_key_hex: hex::encode(public_key_from_secret(priv_key)?),
+        retired_at,
+        signature_hex: String::new(),
+    };
+    // The signature covers the statement with an empty signature field.
+    let message = serde_json::to_string(&statement)?;
+    statement.signature_hex = hex::encode(sign_with_key(priv_key, message.as_bytes())?);
+    Ok(serde_json::to_string(&statement)?)
+}
+
+/// Publishes an identity's statement, authored by the identity's Nostr key.
+fn publish_event(keys: &Keys, kind: u64, content: String, tags: Vec<Tag>) -> Result<bool> {
+    let client_lock = NOSTR_CLIENT.lock().unwrap();
+    if let Some(client) = &*client_lock {
+        let _guard = STATIC_RUNTIME.enter();
+        STATIC_RUNTIME.block_on(async {
+            let event = EventBuilder::new(Kind::from(kind), content, tags).to_event(keys)?;
+            client.send_event(event).await?;
+            Ok(true)
+        })
+    } else { Err(anyhow!("Network Client Not Initialized")) }
+}
+
+/// Replaces a (possibly compromised) identity key with a freshly derived
+/// one. The DID stays the same; a rotation statement signed by both keys is
+/// published and the key history recorded. Returns the statement JSON.
+pub fn rust_rotate_identity_key(pin: String, identity_id: String) -> Result<String> {
+    let mut state = VAULT_STATE.lock().unwrap();
+    if let Some(session) = &mut *state {
+        authorize(session, &pin, None)?;
+        let vault = &mut session.vault;
+        let mut history = vault.key_history_of(&identity_id)?;
+        let position = vault.identities.iter().position(|i| i.id == identity_id).ok_or_else(|| anyhow!("Identity not found"))?;
+        let old_key = vault.private_keys.get(&identity_id).ok_or_else(|| anyhow!("Identity not found"))?.clone();
+        let old_identity = vault.identities[position].clone();
+
+        let derivation_path = derivation::identity_path(vault.next_index);
+        let new_key = derivation::derive_identity_key(&vault.master_seed, &derivation_path)?;
+        vault.next_index += 1;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        let statement = rotation::sign_rotation(&old_identity.did, &old_key, &new_key, now)?;
+
+        if let Some(current) = history.last_mut() { current.valid_until = Some(now); }
+        history.push(KeyRecord {
+            public_key_hex: statement.new_public_key_hex.clone(),
+            derivation_path: derivation_path.clone(),
+            valid_from: now,
+            valid_until: None,
+        });
+        let identity = &mut vault.identities[position];
+        identity.derivation_path = derivation_path;
+        let new_identity = identity.clone();
+        let superseded = discovery::seal_profile(&vault.master_seed, &old_identity, &old_key, ProfileState::Superseded)?;
+        let live = discovery::seal_profile(&vault.master_seed, &new_identity, &new_key, ProfileState::Live)?;
+        vault.private_keys.insert(identity_id.clone(), new_key);
+        vault.key_history.insert(identity_id, history);
+        let keys = identity_nostr_keys(vault, &new_identity.did)?;
+        session.save()?;
+
+        let json = serde_json::to_string(&statement)?;
+        let _ = publish_profile(&keys, superseded);
+        let _ = publish_profile(&keys, live);
+        let _ = publish_event(&keys, KIND_KEY_ROTATION, json.clone(), vec![Tag::Identifier(new_identity.did)]);
+        Ok(json)
+    } else { Err(anyhow!("Vault Locked")) }
+}
+
+/// Every key the identity has signed with, oldest first.
+pub fn rust_get_key_history(identity_id: String) -> Result<Vec<KeyRecord>> {
+    let state = VAULT_STATE.lock().unwrap();
+    if let Some(session) = &*state { session.vault.key_history_of(&identity_id) }
+    else { Err(anyhow!("Vault Locked")) }
+}
+
+/// Revokes keys of an identity (`public_keys` as hex; empty for none) and/or
+/// the whole identity, effective from `since` (default: now). The list is
+/// signed with the identity key and its seed-derived recovery key, cached
+/// and published. Returns the list JSON.
+pub fn rust_publish_revocation_list(pin: String, identity_id: String, public_keys: Vec<String>, revoke_identity: bool, since: Option<u64>, reason: String) -> Result<String> {
+    let state = VAULT_STATE.lock().unwrap();
+    if let Some(session) = &*state {
+        authorize(session, &pin, None)?;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        let revoked_at = since.unwrap_or(now);
+        let list = issue_revocation_list(session, &identity_id, |revocations| {
+            for public_key_hex in public_keys {
+                if !revocations.revoked_keys.iter().any(|k| k.public_key_hex == public_key_hex) {
+                    revocations.revoked_keys.push(RevokedKey { public_key_hex, revoked_at, reason: reason.clone() });
+                }
+            }
+            if revoke_identity && revocations.identity_revoked_at.is_none() {
+                revocations.identity_revoked_at = Some(revoked_at);
+            }
+        })?;
+        Ok(serde_json::to_string(&list)?)
+    } else { Err(anyhow!("Vault Locked")) }
+}
+
+/// Cached revocation state of any DID.
+pub fn rust_get_revocation_status(did: String) -> Result<RevocationState> {
+    Ok(verification_context().cache.state(&did))
+}
+
+/// Verifies a signed intent JSON (e.g. scanned from a customer) against the
+/// signer's published rotations and revocations. A foreign did:satya only
+/// verifies once one of its keys is pinned (`rust_pin_did_key`).
+pub fn rust_verify_intent(signed_json: String) -> Result<bool> {
+    let mut intents = vec![serde_json::from_str::<SignedIntent>(&signed_json)?];
+    let mut context = verification_context();
+    let client_lock = NOSTR_CLIENT.lock().unwrap();
+    if let Some(client) = &*client_lock {
+        let _guard = STATIC_RUNTIME.enter();
+        STATIC_RUNTIME.block_on(verify_intents(client, &mut intents, &mut context));
+        Ok(intents[0].is_verified)
+    } else { Err(anyhow!("Network Client Not Initialized")) }
+}
+
+/// The identity's W3C DID Document as JSON.
+pub fn rust_get_did_document(identity_id: String) -> Result<String> {
+    let state = VAULT_STATE.lock().unwrap();
+    if let Some(session) = &*state {
+        Ok(serde_json::to_string(&own_document(&session.vault, &identity_id)?)?)
+    } else { Err(anyhow!("Vault Locked")) }
+}
+
+/// Publishes the identity's DID Document to the relays (replaceable, `d`-tagged with the DID).
+pub fn rust_publish_did_document(identity_id: String) -> Result<bool> {
+    let (document, keys) = {
+        let state = VAULT_STATE.lock().unwrap();
+        let Some(session) = &*state else { return Err(anyhow!("Vault Locked")) };
+        let document = own_document(&session.vault, &identity_id)?;
+        let keys = identity_nostr_keys(&session.vault, &document.id)?;
+        (document, keys)
+    };
+    publish_event(&keys, KIND_DID_DOCUMENT, serde_json::to_string(&document)?, vec![Tag::Identifier(document.id.clone())])
+}
+
+/// Resolves any supported DID (did:satya, did:key, did:web) to its document JSON.
+/// Without a network client only local sources are consulted.
+pub fn rust_resolve_did(did: String) -> Result<String> {
+    let (resolver, _) = resolver_for(std::slice::from_ref(&did));
+    Ok(serde_json::to_string(&resolver.resolve(&did)?)?)
+}
+
+/// Pins `public_key_hex` as a key of the foreign did:satya `did`, e.g. read
+/// from its DID Document in person. Its published rotations are trusted
+/// from that key on; without a pin the DID's intents do not verify.
+pub fn rust_pin_did_key(did: String, public_key_hex: String) -> Result<bool> {
+    if !did.starts_with("did:satya:") { return Err(anyhow!("Only did:satya keys are pinned")); }
+    if hex::decode(&public_key_hex).map_or(true, |key| key.len() != 32) { return Err(anyhow!("Invalid public key")); }
+    let mut state = VAULT_STATE.lock().unwrap();
+    let Some(session) = &mut *state else { return Err(anyhow!("Vault Locked")) };
+    let vault = &mut session.vault;
+    if vault.identities.iter().any(|i| i.did == did) { return Err(anyhow!("Own identities need no pin")); }
+    vault.pinned_keys.retain(|p| p.did != did);
+    vault.pinned_keys.push(PinnedKey { did, public_key_hex: public_key_hex.to_lowercase() });
+    session.save()?;
+    Ok(true)
+}
+
+pub fn rust_unpin_did_key(did: String) -> Result<bool> {
+    let mut state = VAULT_STATE.lock().unwrap();
+    let Some(session) = &mut *state else { return Err(anyhow!("Vault Locked")) };
+    let before = session.vault.pinned_keys.len();
+    session.vault.pinned_keys.retain(|p| p.did != did);
+    if session.vault.pinned_keys.len() == before { return Ok(false); }
+    session.save()?;
+    Ok(true)
+}
+
+pub fn rust_list_pinned_keys() -> Result<Vec<PinnedKey>> {
+    let state = VAULT_STATE.lock().unwrap();
+    if let Some(session) = &*state { Ok(session.vault.pinned_keys.clone()) }
+    else { Err(anyhow!("Vault Locked")) }
+}
+
+/// A resolver for `dids` backed by the relays, or by local sources only
+/// when there is no network client.
+fn resolver_for(dids: &[String]) -> (MultiResolver, VerificationContext) {
+    let mut context = verification_context();
+    let client_lock = NOSTR_CLIENT.lock().unwrap();
+    let resolver = match &*client_lock {
+        Some(client) => {
+            let _guard = STATIC_RUNTIME.enter();
+            STATIC_RUNTIME.block_on(build_resolver(client, dids, &mut context))
+        },
+        None => {
+            let satya = SatyaResolver { own: context.own.clone(), documents: context.documents.clone(), anchors: context.anchors.clone(), ..Default::default() };
+            resolver(satya, &context.web_root)
+        },
+    };
+    (resolver, context)
+}
+
+/// Signs the identity's updated revocation state, caches and publishes it.
+fn issue_revocation_list(session: &VaultSession, identity_id: &str, change: impl FnOnce(&mut RevocationState)) -> Result<RevocationList> {
+    let vault = &session.vault;
+    let identity = vault.identities.iter().find(|i| i.id == identity_id).ok_or_else(|| anyhow!("Identity not found"))?;
+    let priv_key = vault.private_keys.get(identity_id).ok_or_else(|| anyhow!("Identity not found"))?;
+    let recovery_key = revocation::recovery_key(&vault.master_seed, &identity.did)?;
+    let mut cache = RevocationCache::new(session.manager.base_path());
+    let mut revocations = cache.state(&identity.did);
+    change(&mut revocations);
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let list = revocation::sign_list(&revocations, &recovery_key, &[priv_key, &recovery_key], now)?;
+    // revocations.json and the relays belong to the real vault.
+    if session.is_decoy() { return Ok(list); }
+    cache.merge(&list, &vault.key_history_of(identity_id)?)?;
+    let _ = publish_event(&identity_nostr_keys(vault, &identity.did)?, KIND_REVOCATION_LIST, serde_json::to_string(&list)?, vec![Tag::Identifier(identity.did.clone())]);
+    Ok(list)
+}
+
+/// What verification needs from the vault, copied out so that VAULT_STATE
+/// is never taken while NOSTR_CLIENT is held.
+struct VerificationContext {
+    own: HashMap<String, Vec<KeyRecord>>,
+    documents: HashMap<String, DidDocument>,
+    anchors: HashMap<String, String>,
+    cache: RevocationCache,
+    /// Local did:web mirror (`satya_vault/did_web/<domain>/...`).
+    web_root: Option<PathBuf>,
+}
+
+fn verification_context() -> VerificationContext {
+    let state = VAULT_STATE.lock().unwrap();
+    let Some(session) = &*state else {
+        return VerificationContext { own: HashMap::new(), documents: HashMap::new(), anchors: HashMap::new(), cache: RevocationCache::ephemeral(), web_root: None };
+    };
+    let vault = &session.vault;
+    let own = vault.identities.iter()
+        .filter_map(|i| vault.key_history_of(&i.id).ok().map(|h| (i.did.clone(), h)))
+        .collect();
+    let documents = vault.identities.iter()
+        .filter_map(|i| own_document(vault, &i.id).ok().map(|d| (i.did.clone(), d)))
+        .collect();
+    let anchors = vault.pinned_keys.iter().map(|p| (p.did.clone(), p.public_key_hex.clone())).collect();
+    let mut web_root = PathBuf::from(session.manager.base_path());
+    web_root.push("satya_vault");
+    web_root.push("did_web");
+    VerificationContext { own, documents, anchors, cache: RevocationCache::new(session.manager.base_path()), web_root: Some(web_root) }
+}
+
+/// DID Document of one of the vault's identities.
+fn own_document(vault: &SatyaVault, identity_id: &str) -> Result<DidDocument> {
+    let identity = vault.identities.iter().find(|i| i.id == identity_id).ok_or_else(|| anyhow!("Identity not found"))?;
+    let priv_key = vault.private_keys.get(identity_id).ok_or_else(|| anyhow!("Identity not found"))?;
+    did::document_for_identity(identity, priv_key, &write_relays())
+}
+
+/// Builds a resolver for the given DIDs from published rotations and
+/// documents, merging their revocation lists into the cache on the way.
+async fn build_resolver(client: &Client, dids: &[String], context: &mut VerificationContext) -> MultiResolver {
+    let satya_dids: Vec<String> = dids.iter().filter(|d| d.starts_with("did:satya:") && !context.own.contains_key(*d)).cloned().collect();
+    let rotations: Vec<KeyRotation> = fetch_statements(client, KIND_KEY_ROTATION, satya_dids.clone()).await.unwrap_or_default();
+    let published: Vec<DidDocument> = fetch_statements(client, KIND_DID_DOCUMENT, satya_dids).await.unwrap_or_default();
+    let lists: Vec<RevocationList> = fetch_statements(client, KIND_REVOCATION_LIST, dids.to_vec()).await.unwrap_or_default();
+
+    let mut documents = context.documents.clone();
+    for document in published {
+        documents.entry(document.id.clone()).or_insert(document);
+    }
+    let mut satya = SatyaResolver {
+        own: context.own.clone(),
+        documents,
+        rotations,
+        anchors: context.anchors.clone(),
+        revocations: HashMap::new(),
+        relays: write_relays(),
+    };
+    for did in dids {
+        satya.revocations.insert(did.clone(), context.cache.state(did));
+    }
+    let before = resolver(satya.clone(), &context.web_root);
+    for did in dids {
+        // Empty unless anchored; `merge` then only accepts the pinned recovery key.
+        let history = before.key_history(did).unwrap_or_default();
+        for list in lists.iter().filter(|l| &l.did == did) {
+            let _ = context.cache.merge(list, &history);
+        }
+        satya.revocations.insert(did.clone(), context.cache.state(did));
+    }
+    resolver(satya, &context.web_root)
+}
+
+fn resolver(satya: SatyaResolver, web_root: &Option<PathBuf>) -> MultiResolver {
+    let resolver = MultiResolver::new().with("satya", satya).with("key", KeyResolver).with("peer", PeerResolver);
+    match web_root {
+        Some(root) => resolver.with("web", WebResolver::from_directory(root.clone())),
+        None => resolver,
+    }
+}
+
+/// Sets `is_verified` on each intent: the signature must come from the key
+/// its signer's DID held at the intent's timestamp, and that key must not be revoked.
+async fn verify_intents(client: &Client, intents: &mut [SignedIntent], context: &mut VerificationContext) {
+    let mut dids: Vec<String> = intents.iter().map(|i| i.signer_did.clone()).collect();
+    dids.sort();
+    dids.dedup();
+    let resolver = build_resolver(client, &dids, context).await;
+
+    for intent in intents.iter_mut() {
+        let revocations = context.cache.state(&intent.signer_did);
+        intent.is_verified = resolver.key_history(&intent.signer_did)
+            .and_then(|keys| rotation::verify_intent(intent, &keys, &revocations))
+            .unwrap_or(false);
+    }
+}
+
+/// Statements of one kind published for the given DIDs (`d`-tagged).
+async fn fetch_statements<T: serde::de::DeserializeOwned>(client: &Client, kind: u64, dids: Vec<String>) -> Result<Vec<T>> {
+    if dids.is_empty() { return Ok(Vec::new()); }
+    let filter = Filter::new().kind(Kind::from(kind)).identifiers(dids);
+    let events = client.get_events_of(vec![filter], Some(Duration::from_secs(10))).await?;
+    Ok(events.iter().filter_map(|e| serde_json::from_str(&e.content).ok()).collect())
+}
+
 pub fn rust_get_identities() -> Result<Vec<SatyaIdentity>> {
     let state = VAULT_STATE.lock().unwrap();
-    if let Some((_, vault, _, _)) = &*state { Ok(vault.identities.clone()) }
+    if let Some(session) = &*state { Ok(session.vault.identities.clone()) }
     else { Err(anyhow!("Vault Locked")) }
 }
 
@@ -107,64 +1269,718 @@ pub fn rust_scan_qr(raw_qr_string: String) -> Result<String> {
     Ok(serde_json::to_string(&intent)?)
 }
 
+/// Collects a scanned (or typed) SLIP-39 paper share for
+/// `rust_restore_from_paper_shares` and reports the restore progress.
+pub fn rust_scan_paper_share(raw_qr_string: String) -> Result<ShareScan> {
+    let (identifier, threshold) = paper::inspect(&raw_qr_string)?;
+    let mut scanned = SCANNED_SHARES.lock().unwrap();
+    // A share from another backup restarts the collection.
+    if scanned.first().is_some_and(|s| paper::inspect(s).ok().map(|(id, _)| id) != Some(identifier)) {
+        scanned.clear();
+    }
+    let share = paper::normalize(&raw_qr_string);
+    if !scanned.contains(&share) { scanned.push(share); }
+    Ok(ShareScan { collected: scanned.len() as u32, required: threshold as u32 })
+}
+
+/// Exports the master seed as `threshold`-of-`count` SLIP-39 shares, each
+/// with a printable QR code. The optional passphrase is needed on restore.
+pub fn rust_export_paper_shares(pin: String, threshold: u32, count: u32, passphrase: Option<String>) -> Result<Vec<PaperShare>> {
+    let state = VAULT_STATE.lock().unwrap();
+    if let Some(session) = &*state {
+        authorize(session, &pin, None)?;
+        let threshold = u8::try_from(threshold).map_err(|_| anyhow!("Invalid threshold"))?;
+        let count = u8::try_from(count).map_err(|_| anyhow!("Invalid share count"))?;
+        paper::split(&session.vault.master_seed, threshold, count, passphrase.as_deref())
+    } else { Err(anyhow!("Vault Locked")) }
+}
+
+/// Restores a vault from SLIP-39 shares: the typed `shares` plus any
+/// collected through `rust_scan_paper_share`.
+pub fn rust_restore_from_paper_shares(shares: Vec<String>, passphrase: Option<String>, pin: String, hw_id: String, storage_path: String) -> Result<bool> {
+    let manager = VaultManager::new(&storage_path);
+    if manager.exists() { return Err(anyhow!("Vault already exists; reset it before restoring")); }
+    let mut all = SCANNED_SHARES.lock().unwrap().clone();
+    all.extend(shares);
+    let master_seed = paper::combine(&all, passphrase.as_deref())?;
+    restore_vault(manager, master_seed, pin, hw_id)?;
+    SCANNED_SHARES.lock().unwrap().clear();
+    Ok(true)
+}
+
 pub fn rust_sign_intent(identity_id: String, upi_url: String) -> Result<String> {
     let state = VAULT_STATE.lock().unwrap();
-    if let Some((_, vault, _, _)) = &*state {
+    if let Some(VaultSession { vault, .. }) = &*state {
         let priv_key = vault.private_keys.get(&identity_id).ok_or_else(|| anyhow!("Identity not found"))?;
-        let intent_data = parse_upi_url(&upi_url)?;
-        let payload = IntentPayload {
-            version: PROTOCOL_VERSION.to_string(),
-            interaction_type: InteractionType::PaymentIntent,
-            timestamp: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
-            upi_data: intent_data,
-        };
-        let message = serde_json::to_string(&payload)?;
-        let signature = sign_with_key(priv_key, message.as_bytes())?;
-        let signed = SignedIntent { 
-            payload, 
-            signature_hex: hex::encode(signature), 
-            signer_did: format!("did:satya:{}", identity_id),
-            is_verified: true 
-        };
-        Ok(serde_json::to_string(&signed)?)
+        if vault.identities.iter().any(|i| i.id == identity_id && i.status == IdentityStatus::Archived) {
+            return Err(anyhow!("Identity archived"));
+        }
+        sign_payment_intent(priv_key, format!("did:satya:{}", identity_id), &upi_url)
+    } else { Err(anyhow!("Vault Locked")) }
+}
+
+/// Like `rust_sign_intent`, but signs as the identity's pairwise DID for the
+/// payee VPA, so payments to different merchants cannot be correlated.
+pub fn rust_sign_pairwise_intent(identity_id: String, upi_url: String) -> Result<String> {
+    let mut state = VAULT_STATE.lock().unwrap();
+    if let Some(session) = &mut *state {
+        if session.vault.identities.iter().any(|i| i.id == identity_id && i.status == IdentityStatus::Archived) {
+            return Err(anyhow!("Identity archived"));
+        }
+        let payee = parse_upi_url(&upi_url)?.vpa;
+        let (link, key) = pairwise_link(session, &identity_id, &payee)?;
+        sign_payment_intent(&key, link.did, &upi_url)
+    } else { Err(anyhow!("Vault Locked")) }
+}
+
+fn sign_payment_intent(priv_key: &[u8], signer_did: String, upi_url: &str) -> Result<String> {
+    let intent_data = parse_upi_url(upi_url)?;
+    let payload = IntentPayload {
+        version: PROTOCOL_VERSION.to_string(),
+        interaction_type: InteractionType::PaymentIntent,
+        timestamp: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+        upi_data: intent_data,
+    };
+    let message = serde_json::to_string(&payload)?;
+    let signature = sign_with_key(priv_key, message.as_bytes())?;
+    let signed = SignedIntent { 
+        payload, 
+        signature_hex: hex::encode(signature), 
+        signer_did,
+        signer_public_key_hex: hex::encode(public_key_from_secret(priv_key)?),
+        is_verified: true 
+    };
+    Ok(serde_json::to_string(&signed)?)
+}
+
+/// The pairwise DID an identity uses with `counterparty` (VPA or DID),
+/// derived and remembered on first use.
+pub fn rust_get_pairwise_did(identity_id: String, counterparty: String) -> Result<PairwiseLink> {
+    let mut state = VAULT_STATE.lock().unwrap();
+    if let Some(session) = &mut *state { Ok(pairwise_link(session, &identity_id, &counterparty)?.0) }
+    else { Err(anyhow!("Vault Locked")) }
+}
+
+/// Every pairwise DID handed out by an identity.
+pub fn rust_list_pairwise_dids(identity_id: String) -> Result<Vec<PairwiseLink>> {
+    let state = VAULT_STATE.lock().unwrap();
+    if let Some(session) = &*state {
+        Ok(session.vault.pairwise.iter().filter(|l| l.identity_id == identity_id).cloned().collect())
     } else { Err(anyhow!("Vault Locked")) }
 }
 
+/// Proves to `counterparty` that its pairwise DID belongs to the parent
+/// identity. Only issued on request; returns the proof JSON.
+pub fn rust_prove_linkage(pin: String, identity_id: String, counterparty: String) -> Result<String> {
+    let mut state = VAULT_STATE.lock().unwrap();
+    if let Some(session) = &mut *state {
+        authorize(session, &pin, None)?;
+        let (link, pairwise_key) = pairwise_link(session, &identity_id, &counterparty)?;
+        let vault = &session.vault;
+        let identity = vault.identities.iter().find(|i| i.id == identity_id).ok_or_else(|| anyhow!("Identity not found"))?;
+        let parent_key = vault.private_keys.get(&identity_id).ok_or_else(|| anyhow!("Identity not found"))?;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        let proof = pairwise::prove_linkage(&link, &identity.did, parent_key, &pairwise_key, now)?;
+        Ok(serde_json::to_string(&proof)?)
+    } else { Err(anyhow!("Vault Locked")) }
+}
+
+/// Verifies a linkage proof against the parent DID's key history and revocations.
+pub fn rust_verify_linkage(proof_json: String) -> Result<bool> {
+    let proof: LinkageProof = serde_json::from_str(&proof_json)?;
+    let (resolver, context) = resolver_for(std::slice::from_ref(&proof.parent_did));
+    let history = resolver.key_history(&proof.parent_did)?;
+    pairwise::verify_linkage(&proof, &history, &context.cache.state(&proof.parent_did))
+}
+
+/// Existing link and key for a counterparty, or a newly derived and saved one.
+fn pairwise_link(session: &mut VaultSession, identity_id: &str, counterparty: &str) -> Result<(PairwiseLink, Vec<u8>)> {
+    let vault = &mut session.vault;
+    let counterparty = pairwise::normalize_counterparty(counterparty);
+    if let Some(link) = vault.pairwise.iter().find(|l| l.identity_id == identity_id && l.counterparty == counterparty) {
+        let key = derivation::derive_identity_key(&vault.master_seed, &link.derivation_path)?;
+        return Ok((link.clone(), key));
+    }
+    let history = vault.key_history_of(identity_id)?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let (link, key) = pairwise::derive_link(&vault.master_seed, identity_id, &history, &counterparty, now)?;
+    if vault.pairwise.iter().any(|l| l.did == link.did) { return Err(anyhow!("Pairwise DID collision")); }
+    vault.pairwise.push(link.clone());
+    session.save()?;
+    Ok((link, key))
+}
+
+/// Publishes a signed intent; true only if enough relays acknowledged it
+/// under the publish policy. `rust_publish_intent` has the per-relay detail.
 pub fn rust_publish_to_nostr(signed_json: String) -> Result<bool> {
+    Ok(rust_publish_intent(signed_json)?.success)
+}
+
+/// Publishes a signed intent to the write relays and reports each relay's
+/// answer. Intents of own identities (and their pairwise DIDs) are authored
+/// by that identity's Nostr key; foreign ones by the session key.
+pub fn rust_publish_intent(signed_json: String) -> Result<PublishResult> {
+    let signed: SignedIntent = serde_json::from_str(&signed_json)?;
+    let author = nostr_keys_for(&signed.signer_did)?;
+    let policy = *PUBLISH_POLICY.lock().unwrap();
     let client_lock = NOSTR_CLIENT.lock().unwrap();
     if let Some(client) = &*client_lock {
         let _guard = STATIC_RUNTIME.enter();
         STATIC_RUNTIME.block_on(async {
-            let keys = client.keys().await;
-            let event = EventBuilder::new(Kind::from(29001), signed_json, Vec::new()).to_event(&keys).unwrap();
-            client.send_event(event).await?;
-            Ok(true)
+            let keys = match author {
+                Some(keys) => keys,
+                None => client.keys().await,
+            };
+            let event = EventBuilder::new(Kind::from(29001), signed_json, Vec::new()).to_event(&keys)?;
+            publish_or_queue(client, event, policy).await
         })
     } else { Err(anyhow!("Network Client Not Initialized")) }
 }
 
-pub fn rust_fetch_interaction_history() -> Result<Vec<String>> {
+/// Publishes an event under the policy; if it falls short, the event goes
+/// to the outbox for retry.
+async fn publish_or_queue(client: &Client, event: Event, policy: AckPolicy) -> Result<PublishResult> {
+    let mut result = publish_to_relays(client, event.clone(), policy).await;
+    if !result.success {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        let reason = publishing::failure_reason(&result);
+        OUTBOX.lock().unwrap().enqueue(&result.event_id, event.kind.as_u64(), event.as_json(), &reason, now)?;
+        result.queued = true;
+    }
+    Ok(result)
+}
+
+/// Sends a signed intent of an own identity (or pairwise DID) only to
+/// `recipient` (npub or hex Nostr key): sealed with NIP-44 and gift wrapped
+/// per NIP-59, so relays see neither the content nor the sender.
+pub fn rust_publish_private_intent(signed_json: String, recipient: String) -> Result<PublishResult> {
+    let signed: SignedIntent = serde_json::from_str(&signed_json)?;
+    let recipient = recovery::parse_public_key(&recipient)?;
+    let (sender, signing_key) = signer_keys(&signed)?;
+    let binding_hex = did::bind_nostr_key(&signing_key, &signed.signer_did, &sender.public_key().to_string())?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let wrap = private::gift_wrap(&sender, &recipient, &signed_json, &binding_hex, now)?;
+    let policy = *PUBLISH_POLICY.lock().unwrap();
+    let client_lock = NOSTR_CLIENT.lock().unwrap();
+    if let Some(client) = &*client_lock {
+        let _guard = STATIC_RUNTIME.enter();
+        STATIC_RUNTIME.block_on(publish_or_queue(client, wrap, policy))
+    } else { Err(anyhow!("Network Client Not Initialized")) }
+}
+
+/// Gift-wrapped intents addressed to the identity or its pairwise DIDs,
+/// newest first. Wraps that do not open are skipped; `intent.is_verified`
+/// reports the signer check done after decryption.
+pub fn rust_fetch_private_intents(identity_id: String) -> Result<Vec<PrivateIntent>> {
+    let keys = receiving_keys(&identity_id)?;
+    let mut context = verification_context();
+    let client_lock = NOSTR_CLIENT.lock().unwrap();
+    if let Some(client) = &*client_lock {
+        let _guard = STATIC_RUNTIME.enter();
+        STATIC_RUNTIME.block_on(async {
+            let filter = Filter::new().kind(Kind::from(KIND_GIFT_WRAP)).pubkeys(keys.iter().map(|k| k.public_key()).collect::<Vec<_>>());
+            let wraps = client.get_events_of(vec![filter], Some(Duration::from_secs(10))).await?;
+            let mut messages = open_private_intents(&keys, &wraps);
+            verify_private_intents(client, &mut messages, &mut context).await;
+            messages.sort_by_key(|m| std::cmp::Reverse(m.sent_at));
+            Ok(messages)
+        })
+    } else { Err(anyhow!("Network Client Not Initialized")) }
+}
+
+/// Opens and verifies a gift wrap event JSON received out of band.
+pub fn rust_open_private_intent(identity_id: String, wrap_json: String) -> Result<PrivateIntent> {
+    let wrap = Event::from_json(&wrap_json)?;
+    let keys = receiving_keys(&identity_id)?;
+    let mut messages = open_private_intents(&keys, std::slice::from_ref(&wrap));
+    if messages.is_empty() { return Err(anyhow!("Gift wrap not addressed to this identity")); }
+    let mut context = verification_context();
+    let client_lock = NOSTR_CLIENT.lock().unwrap();
+    if let Some(client) = &*client_lock {
+        let _guard = STATIC_RUNTIME.enter();
+        STATIC_RUNTIME.block_on(verify_private_intents(client, &mut messages, &mut context));
+        Ok(messages.remove(0))
+    } else { Err(anyhow!("Network Client Not Initialized")) }
+}
+
+/// Nostr keys an identity receives private intents on: its own and those of its pairwise DIDs.
+fn receiving_keys(identity_id: &str) -> Result<Vec<Keys>> {
+    let dids: Vec<String> = {
+        let state = VAULT_STATE.lock().unwrap();
+        let Some(VaultSession { vault, .. }) = &*state else { return Err(anyhow!("Vault Locked")) };
+        let identity = vault.identities.iter().find(|i| i.id == identity_id).ok_or_else(|| anyhow!("Identity not found"))?;
+        std::iter::once(identity.did.clone())
+            .chain(vault.pairwise.iter().filter(|l| l.identity_id == identity_id).map(|l| l.did.clone()))
+            .collect()
+    };
+    let mut keys = Vec::new();
+    for did in &dids {
+        if let Some(k) = nostr_keys_for(did)? { keys.push(k); }
+    }
+    Ok(keys)
+}
+
+/// Opens each wrap with the own key it is addressed to, deduplicated by wrap id.
+fn open_private_intents(keys: &[Keys], wraps: &[Event]) -> Vec<PrivateIntent> {
+    let mut messages: Vec<PrivateIntent> = Vec::new();
+    for wrap in wraps {
+        let recipients: Vec<XOnlyPublicKey> = wrap.public_keys().copied().collect();
+        let Some(receiver) = keys.iter().find(|k| recipients.contains(&k.public_key())) else { continue };
+        if let Ok(message) = private::unwrap(receiver, wrap) {
+            if !messages.iter().any(|m| m.event_id == message.event_id) { messages.push(message); }
+        }
+    }
+    messages
+}
+
+async fn verify_private_intents(client: &Client, messages: &mut [PrivateIntent], context: &mut VerificationContext) {
+    let mut intents: Vec<SignedIntent> = messages.iter().map(|m| m.intent.clone()).collect();
+    verify_intents(client, &mut intents, context).await;
+    for (message, intent) in messages.iter_mut().zip(intents) {
+        message.intent = intent;
+    }
+}
+
+/// Publishes only a salted hash commitment to the intent's payload, as
+/// timestamped proof it existed. The salt and intent stay in the local
+/// ledger for `rust_open_commitment`.
+pub fn rust_publish_intent_commitment(signed_json: String) -> Result<PublishResult> {
+    let signed: SignedIntent = serde_json::from_str(&signed_json)?;
+    let (keys, signing_key) = signer_keys(&signed)?;
+    let author_binding_hex = did::bind_nostr_key(&signing_key, &signed.signer_did, &keys.public_key().to_string())?;
+    let salt: [u8; 32] = rand::random();
+    let published = commitment::commit(&signed.payload, &salt)?;
+    let event = EventBuilder::new(Kind::from(KIND_INTENT_COMMITMENT), serde_json::to_string(&published)?, Vec::new()).to_event(&keys)?;
+    let policy = *PUBLISH_POLICY.lock().unwrap();
+    let client_lock = NOSTR_CLIENT.lock().unwrap();
+    if let Some(client) = &*client_lock {
+        // Recorded before publishing: a queued commitment must stay openable.
+        LEDGER.lock().unwrap().record(LedgerEntry {
+            event_id: event.id.to_hex(),
+            commitment_hex: published.commitment_hex,
+            salt_hex: hex::encode(salt),
+            intent: signed,
+            committed_at: event.created_at.as_u64(),
+            author_binding_hex,
+        })?;
+        let _guard = STATIC_RUNTIME.enter();
+        STATIC_RUNTIME.block_on(publish_or_queue(client, event, policy))
+    } else { Err(anyhow!("Network Client Not Initialized")) }
+}
+
+/// Commitments published from this vault, oldest first.
+pub fn rust_list_commitments() -> Result<Vec<LedgerEntry>> {
+    Ok(LEDGER.lock().unwrap().entries().to_vec())
+}
+
+/// The opening JSON for a published commitment, to hand to a verifier.
+pub fn rust_open_commitment(event_id: String) -> Result<String> {
+    let ledger = LEDGER.lock().unwrap();
+    let entry = ledger.find(&event_id).ok_or_else(|| anyhow!("Commitment not in the ledger"))?;
+    Ok(serde_json::to_string(&entry.opening())?)
+}
+
+/// Verifier side: fetches the commitment event, checks the opening against
+/// it and that the event was published by the intent's signer, then verifies
+/// that signer against its DID.
+pub fn rust_verify_commitment_opening(opening_json: String) -> Result<VerifiedOpening> {
+    let opening: IntentOpening = serde_json::from_str(&opening_json)?;
+    let id = EventId::from_hex(&opening.event_id).map_err(|_| anyhow!("Invalid event id"))?;
+    let mut context = verification_context();
     let client_lock = NOSTR_CLIENT.lock().unwrap();
     if let Some(client) = &*client_lock {
         let _guard = STATIC_RUNTIME.enter();
         STATIC_RUNTIME.block_on(async {
-            let filter = Filter::new().kind(Kind::from(29001)).limit(20);
+            let filter = Filter::new().id(id).kind(Kind::from(KIND_INTENT_COMMITMENT));
             let events = client.get_events_of(vec![filter], Some(Duration::from_secs(10))).await?;
-            let mut history = Vec::new();
-            for event in events {
-                if let Ok(mut signed) = serde_json::from_str::<SignedIntent>(&event.content) {
-                    signed.is_verified = true; 
-                    history.push(serde_json::to_string(&signed)?);
-                }
+            let event = events.into_iter().find(|e| e.id == id && e.verify().is_ok())
+                .ok_or_else(|| anyhow!("Commitment not found on the relays"))?;
+            let published: IntentCommitment = serde_json::from_str(&event.content)?;
+            if !commitment::opens(&published, &opening)? {
+                return Err(anyhow!("Opening does not match the commitment"));
             }
-            Ok(history)
+            let intent = &opening.intent;
+            if !did::verify_nostr_binding(&intent.signer_public_key_hex, &intent.signer_did, &event.pubkey.to_string(), &opening.author_binding_hex) {
+                return Err(anyhow!("Commitment not published by the intent's signer"));
+            }
+            let mut intents = vec![opening.intent];
+            verify_intents(client, &mut intents, &mut context).await;
+            Ok(VerifiedOpening { event_id: opening.event_id, committed_at: event.created_at.as_u64(), intent: intents.remove(0) })
         })
     } else { Err(anyhow!("Network Client Not Initialized")) }
 }
 
+/// Events waiting in the outbox, oldest first.
+pub fn rust_get_outbox() -> Result<Vec<OutboxItem>> {
+    Ok(OUTBOX.lock().unwrap().items().to_vec())
+}
+
+/// Retries every queued event now, ignoring backoff. Returns how many were published.
+pub fn rust_retry_outbox() -> Result<u32> {
+    OUTBOX.lock().unwrap().expedite();
+    let client_lock = NOSTR_CLIENT.lock().unwrap();
+    if let Some(client) = &*client_lock {
+        let _guard = STATIC_RUNTIME.enter();
+        Ok(STATIC_RUNTIME.block_on(flush_outbox(client)))
+    } else { Err(anyhow!("Network Client Not Initialized")) }
+}
+
+/// Gives up on a queued event.
+pub fn rust_drop_outbox_item(event_id: String) -> Result<bool> {
+    OUTBOX.lock().unwrap().remove(&event_id)
+}
+
+/// Republishes due outbox items; published ones leave the queue, the rest
+/// back off further. Returns how many were published.
+async fn flush_outbox(client: &Client) -> u32 {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default();
+    let due = OUTBOX.lock().unwrap().due(now);
+    let policy = *PUBLISH_POLICY.lock().unwrap();
+    let mut published = 0;
+    for item in due {
+        let Ok(event) = Event::from_json(&item.event_json) else {
+            let _ = OUTBOX.lock().unwrap().remove(&item.event_id);
+            continue;
+        };
+        let result = publish_to_relays(client, event, policy).await;
+        let mut outbox = OUTBOX.lock().unwrap();
+        if result.success {
+            let _ = outbox.remove(&item.event_id);
+            published += 1;
+        } else {
+            let _ = outbox.record_failure(&item.event_id, &publishing::failure_reason(&result), now);
+        }
+    }
+    published
+}
+
+/// Sets how many relay acknowledgments a publish needs to count as a success
+/// (default: at least one). `policy` is `majority`, `all` or `at_least:<n>`.
+/// Applies for the rest of the app session.
+pub fn rust_set_publish_policy(policy: String) -> Result<bool> {
+    *PUBLISH_POLICY.lock().unwrap() = policy.parse()?;
+    Ok(true)
+}
+
+const PUBLISH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Sends `event` to every write relay concurrently and collects their `OK` replies.
+async fn publish_to_relays(client: &Client, event: Event, policy: AckPolicy) -> PublishResult {
+    let event_id = event.id.to_hex();
+    let mut sends = tokio::task::JoinSet::new();
+    for (url, relay) in client.relays().await {
+        if !RELAY_MONITOR.lock().unwrap().is_writable(url.as_str()) { continue; }
+        let notifications = client.notifications();
+        sends.spawn(send_to_relay(relay, event.clone(), notifications));
+    }
+    let mut outcomes = Vec::new();
+    while let Some(joined) = sends.join_next().await {
+        if let Ok(outcome) = joined { outcomes.push(outcome); }
+    }
+    publishing::summarize(event_id, outcomes, policy)
+}
+
+async fn send_to_relay(relay: Relay, event: Event, notifications: broadcast::Receiver<RelayPoolNotification>) -> RelayOutcome {
+    let url = relay.url();
+    let outcome = |status, message: String| RelayOutcome { url: url.to_string(), status, message };
+    let id = event.id;
+    if let Err(e) = relay.send_msg(ClientMessage::new_event(event), None).await {
+        return outcome(PublishStatus::Failed, e.to_string());
+    }
+    match await_ok(&url, id, notifications).await {
+        Ok(Some((accepted, message))) => outcome(publishing::classify(accepted, &message), message),
+        Ok(None) => outcome(PublishStatus::Failed, "Notification channel closed".into()),
+        Err(_) => outcome(PublishStatus::Timeout, "No reply from relay".into()),
+    }
+}
+
+/// Waits for the relay's `OK` to event `id`: `(accepted, message)`, or an
+/// error after the publish timeout.
+async fn await_ok(url: &Url, id: EventId, mut notifications: broadcast::Receiver<RelayPoolNotification>) -> Result<Option<(bool, String)>, tokio::time::error::Elapsed> {
+    tokio::time::timeout(PUBLISH_TIMEOUT, async {
+        while let Ok(notification) = notifications.recv().await {
+            if let RelayPoolNotification::Message { relay_url, message: RelayMessage::Ok { event_id, status, message } } = notification {
+                if &relay_url == url && event_id == id { return Some((status, message)); }
+            }
+        }
+        None
+    }).await
+}
+
+/// The npub an identity publishes its intents under. Derived from the
+/// master seed, so it is the same on every device and after a restore.
+pub fn rust_get_nostr_public_key(identity_id: String) -> Result<String> {
+    let did = {
+        let state = VAULT_STATE.lock().unwrap();
+        let Some(session) = &*state else { return Err(anyhow!("Vault Locked")) };
+        session.vault.identities.iter().find(|i| i.id == identity_id).ok_or_else(|| anyhow!("Identity not found"))?.did.clone()
+    };
+    let keys = nostr_keys_for(&did)?.ok_or_else(|| anyhow!("Identity not found"))?;
+    Ok(keys.public_key().to_bech32()?)
+}
+
+/// Nostr keys of an own identity or pairwise DID; `None` for foreign DIDs.
+/// Identity keys hang off the original derivation path so rotations keep them.
+fn nostr_keys_for(did: &str) -> Result<Option<Keys>> {
+    let state = VAULT_STATE.lock().unwrap();
+    let Some(session) = &*state else { return Ok(None) };
+    vault_nostr_keys(&session.vault, did)
+}
+
+/// `nostr_keys_for` for callers that already hold VAULT_STATE.
+fn vault_nostr_keys(vault: &SatyaVault, did: &str) -> Result<Option<Keys>> {
+    let path = if let Some(identity) = vault.identities.iter().find(|i| i.did == did) {
+        vault.key_history_of(&identity.id)?.first().map_or(identity.derivation_path.clone(), |k| k.derivation_path.clone())
+    } else if let Some(link) = vault.pairwise.iter().find(|l| l.did == did) {
+        link.derivation_path.clone()
+    } else {
+        return Ok(None);
+    };
+    Ok(Some(derivation::derive_nostr_keys(&vault.master_seed, &derivation::nostr_path(&path)?)?))
+}
+
+/// Nostr keys an own identity signs its profiles and statements with.
+fn identity_nostr_keys(vault: &SatyaVault, did: &str) -> Result<Keys> {
+    vault_nostr_keys(vault, did)?.ok_or_else(|| anyhow!("Identity not found"))
+}
+
+/// Nostr keys of an intent's signer and the Ed25519 secret behind its
+/// `signer_public_key_hex`, for intents signed by this vault.
+fn signer_keys(intent: &SignedIntent) -> Result<(Keys, Vec<u8>)> {
+    let keys = nostr_keys_for(&intent.signer_did)?.ok_or_else(|| anyhow!("Intent not signed by an identity of this vault"))?;
+    let state = VAULT_STATE.lock().unwrap();
+    let Some(session) = &*state else { return Err(anyhow!("Vault Locked")) };
+    let vault = &session.vault;
+    let paths: Vec<String> = match vault.identities.iter().find(|i| i.did == intent.signer_did) {
+        Some(identity) => {
+            let current = vault.private_keys.get(&identity.id).ok_or_else(|| anyhow!("Identity not found"))?;
+            if hex::encode(public_key_from_secret(current)?) == intent.signer_public_key_hex {
+                return Ok((keys, current.clone()));
+            }
+            vault.key_history_of(&identity.id)?.into_iter().map(|k| k.derivation_path).collect()
+        },
+        None => vault.pairwise.iter().filter(|l| l.did == intent.signer_did).map(|l| l.derivation_path.clone()).collect(),
+    };
+    for path in paths {
+        let key = derivation::derive_identity_key(&vault.master_seed, &path)?;
+        if hex::encode(public_key_from_secret(&key)?) == intent.signer_public_key_hex { return Ok((keys, key)); }
+    }
+    Err(anyhow!("Intent not signed by a key of this vault"))
+}
+
+/// The latest page of everyone's intents, verified; see
+/// `rust_query_interaction_history` for filters and paging.
+pub fn rust_fetch_interaction_history() -> Result<Vec<String>> {
+    let page = rust_query_interaction_history(HistoryQuery::default())?;
+    page.items.iter().map(|item| Ok(serde_json::to_string(&item.intent)?)).collect()
+}
+
+/// Fetches one page of intents, newest first, merged and deduplicated across
+/// relays. Each is verified against the key its signer held at the intent's
+/// timestamp and the signer's revocations before the filters apply.
+pub fn rust_query_interaction_history(query: HistoryQuery) -> Result<HistoryPage> {
+    let own_dids = {
+        let state = VAULT_STATE.lock().unwrap();
+        let mut dids: Vec<String> = Vec::new();
+        for identity_id in &query.identity_ids {
+            let Some(VaultSession { vault, .. }) = &*state else { return Err(anyhow!("Vault Locked")) };
+            let identity = vault.identities.iter().find(|i| &i.id == identity_id).ok_or_else(|| anyhow!("Identity not found"))?;
+            dids.push(identity.did.clone());
+            dids.extend(vault.pairwise.iter().filter(|l| &l.identity_id == identity_id).map(|l| l.did.clone()));
+        }
+        dids
+    };
+    // Intents of own identities are authored by their derived Nostr keys,
+    // so relays can filter on them.
+    let mut authors = Vec::new();
+    for did in &own_dids {
+        if let Some(keys) = nostr_keys_for(did)? { authors.push(keys.public_key()); }
+    }
+    let mut context = verification_context();
+    let client_lock = NOSTR_CLIENT.lock().unwrap();
+    if let Some(client) = &*client_lock {
+        let _guard = STATIC_RUNTIME.enter();
+        STATIC_RUNTIME.block_on(fetch_history(client, &query, &own_dids, authors, &mut context))
+    } else { Err(anyhow!("Network Client Not Initialized")) }
+}
+
+/// Fetch rounds per page before handing back a partial page with a cursor.
+const MAX_HISTORY_ROUNDS: usize = 5;
+
+async fn fetch_history(client: &Client, query: &HistoryQuery, own_dids: &[String], authors: Vec<XOnlyPublicKey>, context: &mut VerificationContext) -> Result<HistoryPage> {
+    let page_size = query.page_size.unwrap_or(history::DEFAULT_PAGE_SIZE).clamp(1, history::MAX_PAGE_SIZE) as usize;
+    let timeout = Duration::from_secs(query.timeout_secs.unwrap_or(history::DEFAULT_TIMEOUT_SECS));
+    let mut cursor = query.cursor.as_deref().map(Cursor::parse).transpose()?;
+    let mut items: Vec<HistoryItem> = Vec::new();
+
+    for _ in 0..MAX_HISTORY_ROUNDS {
+        let batch = page_size * 2;
+        let mut filter = Filter::new().kind(Kind::from(29001)).limit(batch);
+        if !authors.is_empty() { filter = filter.authors(authors.clone()); }
+        if let Some(since) = query.since { filter = filter.since(Timestamp::from(since)); }
+        let until = [cursor.as_ref().map(Cursor::until), query.until].into_iter().flatten().min();
+        if let Some(until) = until { filter = filter.until(Timestamp::from(until)); }
+
+        let mut events = client.get_events_of(vec![filter], Some(timeout)).await?;
+        // `until` cannot page within one second: when a full batch shares a
+        // timestamp the relay would return it again, so later rounds move
+        // below it. Events of that second past the batch are skipped.
+        let shared = events.first().map(|e| e.created_at.as_u64())
+            .filter(|t| events.len() >= batch && events.iter().all(|e| e.created_at.as_u64() == *t));
+        let exhausted = events.len() < batch || shared == Some(0);
+        events.retain(|e| cursor.as_ref().is_none_or(|c| c.precedes(e.created_at.as_u64(), &e.id.to_hex())));
+        events.sort_by_key(|e| std::cmp::Reverse((e.created_at, e.id)));
+        events.dedup_by_key(|e| e.id);
+
+        let parsed: Vec<(usize, SignedIntent)> = events.iter().enumerate()
+            .filter_map(|(n, e)| serde_json::from_str(&e.content).ok().map(|intent| (n, intent)))
+            .collect();
+        let mut intents: Vec<SignedIntent> = parsed.iter().map(|(_, intent)| intent.clone()).collect();
+        verify_intents(client, &mut intents, context).await;
+        let mut verified = parsed.iter().map(|(n, _)| *n).zip(intents).peekable();
+
+        for (n, event) in events.iter().enumerate() {
+            cursor = Some(Cursor { created_at: event.created_at.as_u64(), event_id: event.id.to_hex() });
+            let Some((_, intent)) = verified.next_if(|(m, _)| *m == n) else { continue };
+            if !history::matches(query, &intent, own_dids) { continue; }
+            let seen_on = client.database().event_seen_on_relays(event.id).await.ok().flatten()
+                .map(|urls| urls.iter().map(|u| u.to_string()).collect())
+                .unwrap_or_default();
+            items.push(HistoryItem { event_id: event.id.to_hex(), created_at: event.created_at.as_u64(), seen_on, intent });
+            if items.len() == page_size {
+                let more = !exhausted || n + 1 < events.len();
+                return Ok(HistoryPage { items, next_cursor: cursor.filter(|_| more).map(|c| c.encode()) });
+            }
+        }
+        if exhausted { return Ok(HistoryPage { items, next_cursor: None }); }
+        if let Some(created_at) = shared { cursor = Some(Cursor::below(created_at)); }
+    }
+    Ok(HistoryPage { items, next_cursor: cursor.map(|c| c.encode()) })
+}
+
+/// Streams verified Satya events as they arrive: own intents as relays store
+/// them, intents addressed to own identities, and rotations and revocations
+/// of own DIDs plus `watch_dids`. The first message carries the handle.
+/// Relays resubscribe on reconnect; the stream ends on unsubscribe.
+pub fn rust_subscribe_events(watch_dids: Vec<String>, sink: StreamSink<String>) -> Result<()> {
+    let (own_dids, mut watched) = {
+        let state = VAULT_STATE.lock().unwrap();
+        let Some(VaultSession { vault, .. }) = &*state else { return Err(anyhow!("Vault Locked")) };
+        let identity_dids: Vec<String> = vault.identities.iter().map(|i| i.did.clone()).collect();
+        let own = identity_dids.iter().cloned().chain(vault.pairwise.iter().map(|l| l.did.clone())).collect::<Vec<_>>();
+        (own, identity_dids)
+    };
+    watched.extend(watch_dids);
+    watched.sort();
+    watched.dedup();
+    let mut own_keys = Vec::new();
+    for did in &own_dids {
+        if let Some(keys) = nostr_keys_for(did)? { own_keys.push(keys); }
+    }
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let public_keys: Vec<XOnlyPublicKey> = own_keys.iter().map(|k| k.public_key()).collect();
+    let filters = subscriptions::filters(&public_keys, &watched, now);
+    if filters.is_empty() { return Err(anyhow!("Nothing to subscribe to")); }
+
+    let client = NOSTR_CLIENT.lock().unwrap().clone().ok_or_else(|| anyhow!("Network Client Not Initialized"))?;
+    let handle = Uuid::new_v4().to_string();
+    sink.add(serde_json::to_string(&LiveEvent::Subscribed { handle: handle.clone() })?);
+    let (cancel, cancelled) = oneshot::channel();
+    // Listen before subscribing so nothing is missed.
+    let notifications = client.notifications();
+    SUBSCRIPTIONS.lock().unwrap().insert(handle.clone(), LiveSubscription { filters: filters.clone(), cancel });
+    STATIC_RUNTIME.spawn(dispatch_events(client.clone(), handle.clone(), filters.clone(), own_keys, notifications, sink, cancelled));
+
+    let _guard = STATIC_RUNTIME.enter();
+    STATIC_RUNTIME.block_on(async {
+        for relay in client.relays().await.into_values() {
+            let _ = relay.subscribe_with_internal_id(InternalSubscriptionId::Custom(handle.clone()), filters.clone(), None).await;
+        }
+    });
+    Ok(())
+}
+
+/// Ends a live subscription and closes its stream.
+pub fn rust_unsubscribe_events(handle: String) -> Result<bool> {
+    let Some(subscription) = SUBSCRIPTIONS.lock().unwrap().remove(&handle) else { return Ok(false) };
+    let _ = subscription.cancel.send(());
+    let client = NOSTR_CLIENT.lock().unwrap().clone();
+    if let Some(client) = client {
+        let _guard = STATIC_RUNTIME.enter();
+        STATIC_RUNTIME.block_on(async {
+            for relay in client.relays().await.into_values() {
+                let _ = relay.unsubscribe_with_internal_id(InternalSubscriptionId::Custom(handle.clone()), None).await;
+            }
+        });
+    }
+    Ok(true)
+}
+
+/// Forwards matching pool events to the sink once verified. Stops when
+/// cancelled or when the app side of the stream is gone.
+async fn dispatch_events(client: Client, handle: String, filters: Vec<Filter>, own_keys: Vec<Keys>, mut notifications: broadcast::Receiver<RelayPoolNotification>, sink: StreamSink<String>, mut cancelled: oneshot::Receiver<()>) {
+    loop {
+        let notification = tokio::select! {
+            _ = &mut cancelled => break,
+            notification = notifications.recv() => notification,
+        };
+        let (relay_url, event) = match notification {
+            Ok(RelayPoolNotification::Event { relay_url, event }) => (relay_url, event),
+            Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+            Err(broadcast::error::RecvError::Closed) => break,
+        };
+        if !filters.iter().any(|f| f.match_event(&event)) { continue; }
+        let Some(live) = verify_live_event(&client, &event, relay_url.to_string(), &own_keys).await else { continue };
+        let Ok(json) = serde_json::to_string(&live) else { continue };
+        if !sink.add(json) { break; }
+    }
+    SUBSCRIPTIONS.lock().unwrap().remove(&handle);
+    sink.close();
+}
+
+/// Verifies an incoming event the way history and `rust_verify_intent` do;
+/// `None` for anything that does not check out.
+async fn verify_live_event(client: &Client, event: &Event, relay: String, own_keys: &[Keys]) -> Option<LiveEvent> {
+    let event_id = event.id.to_hex();
+    let mut context = tokio::task::block_in_place(verification_context);
+    match event.kind.as_u64() {
+        subscriptions::KIND_INTENT => {
+            let mut intents = vec![serde_json::from_str::<SignedIntent>(&event.content).ok()?];
+            verify_intents(client, &mut intents, &mut context).await;
+            let intent = intents.pop().filter(|i| i.is_verified)?;
+            let receipt = own_keys.iter().any(|k| k.public_key() == event.pubkey);
+            Some(LiveEvent::Intent { event_id, relay, receipt, intent })
+        },
+        KIND_GIFT_WRAP => {
+            let mut messages = open_private_intents(own_keys, std::slice::from_ref(event));
+            verify_private_intents(client, &mut messages, &mut context).await;
+            let message = messages.pop().filter(|m| m.intent.is_verified)?;
+            Some(LiveEvent::PrivateIntent { relay, message })
+        },
+        KIND_KEY_ROTATION => {
+            let statement: KeyRotation = serde_json::from_str(&event.content).ok()?;
+            // Only rotations on the DID's anchored chain are worth reporting.
+            let resolver = build_resolver(client, std::slice::from_ref(&statement.did), &mut context).await;
+            let history = resolver.key_history(&statement.did).unwrap_or_default();
+            history.iter().find(|k| k.public_key_hex == statement.new_public_key_hex && k.valid_from == statement.rotated_at)?;
+            Some(LiveEvent::Rotation { event_id, relay, statement })
+        },
+        KIND_REVOCATION_LIST => {
+            let list: RevocationList = serde_json::from_str(&event.content).ok()?;
+            let resolver = build_resolver(client, std::slice::from_ref(&list.did), &mut context).await;
+            let history = resolver.key_history(&list.did).unwrap_or_default();
+            context.cache.merge(&list, &history).ok()?;
+            Some(LiveEvent::Revocation { event_id, relay, list })
+        },
+        _ => None,
+    }
+}
+
 pub fn rust_reset_vault(storage_path: String) -> Result<bool> {
     let mut state = VAULT_STATE.lock().unwrap();
     *state = None;
+    *OUTBOX.lock().unwrap() = Outbox::ephemeral();
+    *LEDGER.lock().unwrap() = CommitmentLedger::ephemeral();
     let mut path = PathBuf::from(storage_path);
     path.push("satya_vault");
     if path.exists() {
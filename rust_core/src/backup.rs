@@ -0,0 +1,54 @@
+/**
+ * FILE: rust_core/src/backup.rs
+ * VERSION: 1.0.0
+ * PHASE: Phase 11.0 (Seed Backup)
+ * PURPOSE: Human-readable backups of the vault master seed. The words are
+ * the BIP39 encoding of the 32-byte seed itself. The optional mask passphrase
+ * is a Satya-only scheme, not the BIP39 passphrase: other wallets cannot
+ * apply it and read masked words as a different seed.
+ */
+use crate::crypto::{VaultKey, KdfParams, constant_time_eq};
+use anyhow::{Result, anyhow};
+use bip39::Mnemonic;
+
+pub const MNEMONIC_WORDS: usize = 24;
+/// Fixed forever: a mnemonic written today must restore on every future build.
+const PASSPHRASE_KDF: KdfParams = KdfParams { m_cost_kib: 19 * 1024, t_cost: 2, p_cost: 1 };
+const PASSPHRASE_SALT: &[u8] = b"satya_mnemonic_passphrase_v1";
+
+/// Encodes the 32-byte master seed as a 24-word BIP39 mnemonic. With a
+/// mask passphrase the entropy is masked, so the words alone reveal nothing.
+pub fn seed_to_mnemonic(master_seed: &[u8], mask_passphrase: Option<&str>) -> Result<String> {
+    if master_seed.len() != 32 { return Err(anyhow!("Master seed must be 32 bytes")); }
+    let entropy = mask(master_seed, mask_passphrase)?;
+    let mnemonic = Mnemonic::from_entropy(&entropy).map_err(|e| anyhow!("Mnemonic error: {}", e))?;
+    Ok(mnemonic.to_string())
+}
+
+/// Decodes a typed mnemonic (case and spacing are forgiven) back to the seed.
+pub fn mnemonic_to_seed(phrase: &str, mask_passphrase: Option<&str>) -> Result<Vec<u8>> {
+    let normalized = phrase.split_whitespace().map(str::to_lowercase).collect::<Vec<_>>().join(" ");
+    let mnemonic = Mnemonic::parse_normalized(&normalized).map_err(|e| anyhow!("Invalid mnemonic: {}", e))?;
+    if mnemonic.word_count() != MNEMONIC_WORDS {
+        return Err(anyhow!("Expected {} words, got {}", MNEMONIC_WORDS, mnemonic.word_count()));
+    }
+    mask(&mnemonic.to_entropy(), mask_passphrase)
+}
+
+/// Checks a user-typed backup against the live seed in constant time.
+pub fn verify_mnemonic(master_seed: &[u8], phrase: &str, mask_passphrase: Option<&str>) -> Result<bool> {
+    let restored = mnemonic_to_seed(phrase, mask_passphrase)?;
+    Ok(constant_time_eq(&restored, master_seed))
+}
+
+/// XORs the entropy with an Argon2id stretch of the passphrase (involution).
+/// Unlike a BIP39 passphrase it changes the words, not the seed derivation.
+fn mask(entropy: &[u8], mask_passphrase: Option<&str>) -> Result<Vec<u8>> {
+    match mask_passphrase.filter(|p| !p.is_empty()) {
+        None => Ok(entropy.to_vec()),
+        Some(passphrase) => {
+            let pad = VaultKey::from_pin_with(passphrase, PASSPHRASE_SALT, &PASSPHRASE_KDF)?;
+            Ok(entropy.iter().zip(pad.as_bytes()).map(|(a, b)| a ^ b).collect())
+        },
+    }
+}
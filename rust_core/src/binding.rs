@@ -0,0 +1,203 @@
+/**
+ * FILE: rust_core/src/binding.rs
+ * VERSION: 1.0.0
+ * PHASE: Phase 10.1 (Resilient Binding)
+ * PURPOSE: Pluggable hardware binding strategies for vault key slots.
+ */
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Device identifiers emitted by `HardwareIdService` when the platform gives
+/// us nothing stable. Binding to them silently breaks on the next launch.
+const UNSTABLE_DEVICE_IDS: [&str; 3] = ["satya_unbound_identity", "macos_stable_static_id", "ios_stable_id"];
+const UNSTABLE_DEVICE_PREFIXES: [&str; 1] = ["android_emu_"];
+
+const KEYSTORE_DIR: &str = "satya_keystore";
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BindingKind {
+    None,
+    DeviceId,
+    KeyFile,
+    Keystore,
+}
+
+impl FromStr for BindingKind {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "none" => Ok(BindingKind::None),
+            "device_id" => Ok(BindingKind::DeviceId),
+            "key_file" => Ok(BindingKind::KeyFile),
+            "keystore" => Ok(BindingKind::Keystore),
+            other => Err(anyhow!("Unknown binding strategy: {}", other)),
+        }
+    }
+}
+
+/// A source of device-held entropy that is mixed into a vault key slot.
+pub trait HardwareBinding {
+    fn kind(&self) -> BindingKind;
+    /// Strategy specific locator persisted next to the slot (path, alias).
+    fn reference(&self) -> String { String::new() }
+    /// Stable 32-byte secret. Must return the same bytes on every launch.
+    fn secret(&self) -> Result<[u8; 32]>;
+}
+
+/// Slot is not bound to any device; the other factors carry all the weight.
+pub struct NoBinding;
+
+impl HardwareBinding for NoBinding {
+    fn kind(&self) -> BindingKind { BindingKind::None }
+
+    fn secret(&self) -> Result<[u8; 32]> {
+        Ok(domain_hash("none", b""))
+    }
+}
+
+/// Binds to the platform device identifier reported by Flutter.
+pub struct DeviceIdBinding {
+    device_id: String,
+}
+
+impl DeviceIdBinding {
+    pub fn new(device_id: &str) -> Self {
+        Self { device_id: device_id.trim().to_string() }
+    }
+}
+
+impl HardwareBinding for DeviceIdBinding {
+    fn kind(&self) -> BindingKind { BindingKind::DeviceId }
+
+    fn secret(&self) -> Result<[u8; 32]> {
+        if is_unstable_device_id(&self.device_id) {
+            return Err(anyhow!("Unstable device id: {}", self.device_id));
+        }
+        Ok(domain_hash("device_id", self.device_id.as_bytes()))
+    }
+}
+
+/// Binds to a random key stored in a file chosen by the user.
+pub struct KeyFileBinding {
+    path: PathBuf,
+}
+
+impl KeyFileBinding {
+    pub fn new(path: &str) -> Self {
+        Self { path: PathBuf::from(path) }
+    }
+
+    /// Writes a fresh key file if none exists yet.
+    pub fn provision(&self) -> Result<()> {
+        ensure_key_file(&self.path)
+    }
+}
+
+impl HardwareBinding for KeyFileBinding {
+    fn kind(&self) -> BindingKind { BindingKind::KeyFile }
+
+    fn reference(&self) -> String { self.path.to_string_lossy().to_string() }
+
+    fn secret(&self) -> Result<[u8; 32]> {
+        let bytes = fs::read(&self.path).map_err(|_| anyhow!("Key file unavailable"))?;
+        Ok(domain_hash("key_file", &bytes))
+    }
+}
+
+/// Stand-in for the platform keystore (Android Keystore / Secure Enclave).
+/// Keys live under the app storage directory until native plugins land.
+pub struct KeystoreBinding {
+    path: PathBuf,
+    alias: String,
+}
+
+impl KeystoreBinding {
+    pub fn new(base_path: &Path, alias: &str) -> Self {
+        let mut path = base_path.to_path_buf();
+        path.push(KEYSTORE_DIR);
+        path.push(format!("{}.key", alias));
+        Self { path, alias: alias.to_string() }
+    }
+}
+
+impl HardwareBinding for KeystoreBinding {
+    fn kind(&self) -> BindingKind { BindingKind::Keystore }
+
+    fn reference(&self) -> String { self.alias.clone() }
+
+    fn secret(&self) -> Result<[u8; 32]> {
+        ensure_key_file(&self.path)?;
+        let bytes = fs::read(&self.path).map_err(|_| anyhow!("Keystore entry unavailable"))?;
+        Ok(domain_hash("keystore", &bytes))
+    }
+}
+
+pub fn is_unstable_device_id(device_id: &str) -> bool {
+    let id = device_id.trim();
+    id.is_empty()
+        || UNSTABLE_DEVICE_IDS.contains(&id)
+        || UNSTABLE_DEVICE_PREFIXES.iter().any(|p| id.starts_with(p))
+}
+
+/// Rebuilds the binding recorded in a slot for the current launch.
+pub fn resolve(kind: BindingKind, reference: &str, hw_id: &str, base_path: &Path) -> Box<dyn HardwareBinding> {
+    match kind {
+        BindingKind::None => Box::new(NoBinding),
+        BindingKind::DeviceId => Box::new(DeviceIdBinding::new(hw_id)),
+        BindingKind::KeyFile => Box::new(KeyFileBinding::new(reference)),
+        BindingKind::Keystore => Box::new(KeystoreBinding::new(base_path, reference)),
+    }
+}
+
+/// Picks a binding for a new slot. Unstable device ids fall back to the keystore.
+pub fn default_for(hw_id: &str, base_path: &Path) -> Box<dyn HardwareBinding> {
+    if is_unstable_device_id(hw_id) {
+        Box::new(KeystoreBinding::new(base_path, "vault"))
+    } else {
+        Box::new(DeviceIdBinding::new(hw_id))
+    }
+}
+
+/// Builds a binding of the requested kind, provisioning key material as needed.
+pub fn create(kind: BindingKind, hw_id: &str, key_file_path: Option<&str>, base_path: &Path) -> Result<Box<dyn HardwareBinding>> {
+    match kind {
+        BindingKind::None => Ok(Box::new(NoBinding)),
+        BindingKind::DeviceId => {
+            if is_unstable_device_id(hw_id) {
+                return Err(anyhow!("Device id is not stable enough to bind to"));
+            }
+            Ok(Box::new(DeviceIdBinding::new(hw_id)))
+        },
+        BindingKind::KeyFile => {
+            let path = key_file_path.ok_or_else(|| anyhow!("Key file path required"))?;
+            let binding = KeyFileBinding::new(path);
+            binding.provision()?;
+            Ok(Box::new(binding))
+        },
+        BindingKind::Keystore => Ok(Box::new(KeystoreBinding::new(base_path, "vault"))),
+    }
+}
+
+fn ensure_key_file(path: &Path) -> Result<()> {
+    if path.exists() { return Ok(()); }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let key: [u8; 32] = rand::random();
+    fs::write(path, key)?;
+    Ok(())
+}
+
+fn domain_hash(domain: &str, data: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(b"satya_binding_v1:");
+    hasher.update(domain.as_bytes());
+    hasher.update(b":");
+    hasher.update(data);
+    hasher.finalize().into()
+}
@@ -11,11 +11,395 @@ pub extern "C" fn wire_rust_initialize_vault(
     wire_rust_initialize_vault_impl(port_, pin, hw_id, storage_path)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_rust_unlock_vault(
+    port_: i64,
+    storage_path: *mut wire_uint_8_list,
+    hw_id: *mut wire_uint_8_list,
+    pin: *mut wire_uint_8_list,
+    recovery_code: *mut wire_uint_8_list,
+    key_file_path: *mut wire_uint_8_list,
+) {
+    wire_rust_unlock_vault_impl(
+        port_,
+        storage_path,
+        hw_id,
+        pin,
+        recovery_code,
+        key_file_path,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_restore_from_mnemonic(
+    port_: i64,
+    mnemonic: *mut wire_uint_8_list,
+    mask_passphrase: *mut wire_uint_8_list,
+    pin: *mut wire_uint_8_list,
+    hw_id: *mut wire_uint_8_list,
+    storage_path: *mut wire_uint_8_list,
+) {
+    wire_rust_restore_from_mnemonic_impl(port_, mnemonic, mask_passphrase, pin, hw_id, storage_path)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_list_relays(port_: i64) {
+    wire_rust_list_relays_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_add_relay(
+    port_: i64,
+    url: *mut wire_uint_8_list,
+    read: bool,
+    write: bool,
+) {
+    wire_rust_add_relay_impl(port_, url, read, write)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_remove_relay(port_: i64, url: *mut wire_uint_8_list) {
+    wire_rust_remove_relay_impl(port_, url)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_get_relay_status(port_: i64) {
+    wire_rust_get_relay_status_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_set_relay_auth(
+    port_: i64,
+    url: *mut wire_uint_8_list,
+    identity_id: *mut wire_uint_8_list,
+) {
+    wire_rust_set_relay_auth_impl(port_, url, identity_id)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_list_relay_auth(port_: i64) {
+    wire_rust_list_relay_auth_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_start_local_relay(
+    port_: i64,
+    bind_address: *mut wire_uint_8_list,
+    storage_path: *mut wire_uint_8_list,
+) {
+    wire_rust_start_local_relay_impl(port_, bind_address, storage_path)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_stop_local_relay(port_: i64) {
+    wire_rust_stop_local_relay_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_generate_recovery_code(port_: i64, pin: *mut wire_uint_8_list) {
+    wire_rust_generate_recovery_code_impl(port_, pin)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_enroll_unlock_factors(
+    port_: i64,
+    pin: *mut wire_uint_8_list,
+    factors: *mut wire_StringList,
+    require_all: bool,
+    key_file_path: *mut wire_uint_8_list,
+) {
+    wire_rust_enroll_unlock_factors_impl(port_, pin, factors, require_all, key_file_path)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_revoke_unlock_factor(
+    port_: i64,
+    pin: *mut wire_uint_8_list,
+    factor: *mut wire_uint_8_list,
+    key_file_path: *mut wire_uint_8_list,
+) {
+    wire_rust_revoke_unlock_factor_impl(port_, pin, factor, key_file_path)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_list_unlock_factors(port_: i64) {
+    wire_rust_list_unlock_factors_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_change_pin(
+    port_: i64,
+    old_pin: *mut wire_uint_8_list,
+    new_pin: *mut wire_uint_8_list,
+    recovery_code: *mut wire_uint_8_list,
+    key_file_path: *mut wire_uint_8_list,
+) {
+    wire_rust_change_pin_impl(port_, old_pin, new_pin, recovery_code, key_file_path)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_configure_duress_pin(
+    port_: i64,
+    pin: *mut wire_uint_8_list,
+    duress_pin: *mut wire_uint_8_list,
+) {
+    wire_rust_configure_duress_pin_impl(port_, pin, duress_pin)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_clear_duress_pin(port_: i64, pin: *mut wire_uint_8_list) {
+    wire_rust_clear_duress_pin_impl(port_, pin)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_export_mnemonic(
+    port_: i64,
+    pin: *mut wire_uint_8_list,
+    mask_passphrase: *mut wire_uint_8_list,
+) {
+    wire_rust_export_mnemonic_impl(port_, pin, mask_passphrase)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_verify_mnemonic(
+    port_: i64,
+    mnemonic: *mut wire_uint_8_list,
+    mask_passphrase: *mut wire_uint_8_list,
+) {
+    wire_rust_verify_mnemonic_impl(port_, mnemonic, mask_passphrase)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_get_unlock_status(
+    port_: i64,
+    storage_path: *mut wire_uint_8_list,
+    hw_id: *mut wire_uint_8_list,
+) {
+    wire_rust_get_unlock_status_impl(port_, storage_path, hw_id)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_set_wipe_after(
+    port_: i64,
+    pin: *mut wire_uint_8_list,
+    limit: *mut u32,
+) {
+    wire_rust_set_wipe_after_impl(port_, pin, limit)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_set_hardware_binding(
+    port_: i64,
+    pin: *mut wire_uint_8_list,
+    strategy: *mut wire_uint_8_list,
+    key_file_path: *mut wire_uint_8_list,
+) {
+    wire_rust_set_hardware_binding_impl(port_, pin, strategy, key_file_path)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_rebind_device(
+    port_: i64,
+    pin: *mut wire_uint_8_list,
+    recovery_code: *mut wire_uint_8_list,
+    hw_id: *mut wire_uint_8_list,
+    storage_path: *mut wire_uint_8_list,
+    strategy: *mut wire_uint_8_list,
+    key_file_path: *mut wire_uint_8_list,
+) {
+    wire_rust_rebind_device_impl(
+        port_,
+        pin,
+        recovery_code,
+        hw_id,
+        storage_path,
+        strategy,
+        key_file_path,
+    )
+}
+
 #[no_mangle]
 pub extern "C" fn wire_rust_create_identity(port_: i64, label: *mut wire_uint_8_list) {
     wire_rust_create_identity_impl(port_, label)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_rust_publish_identity_profile(
+    port_: i64,
+    identity_id: *mut wire_uint_8_list,
+) {
+    wire_rust_publish_identity_profile_impl(port_, identity_id)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_discover_identities(port_: i64, gap_limit: *mut u32) {
+    wire_rust_discover_identities_impl(port_, gap_limit)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_get_guardian_pubkey(port_: i64) {
+    wire_rust_get_guardian_pubkey_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_create_social_recovery(
+    port_: i64,
+    pin: *mut wire_uint_8_list,
+    guardians: *mut wire_StringList,
+    threshold: u32,
+) {
+    wire_rust_create_social_recovery_impl(port_, pin, guardians, threshold)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_fetch_guardian_shares(port_: i64) {
+    wire_rust_fetch_guardian_shares_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_release_guardian_share(
+    port_: i64,
+    pin: *mut wire_uint_8_list,
+    envelope: *mut wire_uint_8_list,
+    requester_pubkey: *mut wire_uint_8_list,
+) {
+    wire_rust_release_guardian_share_impl(port_, pin, envelope, requester_pubkey)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_begin_social_recovery(port_: i64) {
+    wire_rust_begin_social_recovery_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_collect_recovery_shares(port_: i64) {
+    wire_rust_collect_recovery_shares_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_recover_from_shares(
+    port_: i64,
+    envelopes: *mut wire_StringList,
+    pin: *mut wire_uint_8_list,
+    hw_id: *mut wire_uint_8_list,
+    storage_path: *mut wire_uint_8_list,
+) {
+    wire_rust_recover_from_shares_impl(port_, envelopes, pin, hw_id, storage_path)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_rename_identity(
+    port_: i64,
+    identity_id: *mut wire_uint_8_list,
+    label: *mut wire_uint_8_list,
+) {
+    wire_rust_rename_identity_impl(port_, identity_id, label)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_archive_identity(port_: i64, identity_id: *mut wire_uint_8_list) {
+    wire_rust_archive_identity_impl(port_, identity_id)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_unarchive_identity(port_: i64, identity_id: *mut wire_uint_8_list) {
+    wire_rust_unarchive_identity_impl(port_, identity_id)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_delete_identity(
+    port_: i64,
+    pin: *mut wire_uint_8_list,
+    identity_id: *mut wire_uint_8_list,
+    publish_retirement: bool,
+) {
+    wire_rust_delete_identity_impl(port_, pin, identity_id, publish_retirement)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_get_tombstones(port_: i64) {
+    wire_rust_get_tombstones_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_rotate_identity_key(
+    port_: i64,
+    pin: *mut wire_uint_8_list,
+    identity_id: *mut wire_uint_8_list,
+) {
+    wire_rust_rotate_identity_key_impl(port_, pin, identity_id)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_get_key_history(port_: i64, identity_id: *mut wire_uint_8_list) {
+    wire_rust_get_key_history_impl(port_, identity_id)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_publish_revocation_list(
+    port_: i64,
+    pin: *mut wire_uint_8_list,
+    identity_id: *mut wire_uint_8_list,
+    public_keys: *mut wire_StringList,
+    revoke_identity: bool,
+    since: *mut u64,
+    reason: *mut wire_uint_8_list,
+) {
+    wire_rust_publish_revocation_list_impl(
+        port_,
+        pin,
+        identity_id,
+        public_keys,
+        revoke_identity,
+        since,
+        reason,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_get_revocation_status(port_: i64, did: *mut wire_uint_8_list) {
+    wire_rust_get_revocation_status_impl(port_, did)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_verify_intent(port_: i64, signed_json: *mut wire_uint_8_list) {
+    wire_rust_verify_intent_impl(port_, signed_json)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_get_did_document(port_: i64, identity_id: *mut wire_uint_8_list) {
+    wire_rust_get_did_document_impl(port_, identity_id)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_publish_did_document(port_: i64, identity_id: *mut wire_uint_8_list) {
+    wire_rust_publish_did_document_impl(port_, identity_id)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_resolve_did(port_: i64, did: *mut wire_uint_8_list) {
+    wire_rust_resolve_did_impl(port_, did)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_pin_did_key(
+    port_: i64,
+    did: *mut wire_uint_8_list,
+    public_key_hex: *mut wire_uint_8_list,
+) {
+    wire_rust_pin_did_key_impl(port_, did, public_key_hex)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_unpin_did_key(port_: i64, did: *mut wire_uint_8_list) {
+    wire_rust_unpin_did_key_impl(port_, did)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_list_pinned_keys(port_: i64) {
+    wire_rust_list_pinned_keys_impl(port_)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_rust_get_identities(port_: i64) {
     wire_rust_get_identities_impl(port_)
@@ -26,6 +410,34 @@ pub extern "C" fn wire_rust_scan_qr(port_: i64, raw_qr_string: *mut wire_uint_8_
     wire_rust_scan_qr_impl(port_, raw_qr_string)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_rust_scan_paper_share(port_: i64, raw_qr_string: *mut wire_uint_8_list) {
+    wire_rust_scan_paper_share_impl(port_, raw_qr_string)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_export_paper_shares(
+    port_: i64,
+    pin: *mut wire_uint_8_list,
+    threshold: u32,
+    count: u32,
+    passphrase: *mut wire_uint_8_list,
+) {
+    wire_rust_export_paper_shares_impl(port_, pin, threshold, count, passphrase)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_restore_from_paper_shares(
+    port_: i64,
+    shares: *mut wire_StringList,
+    passphrase: *mut wire_uint_8_list,
+    pin: *mut wire_uint_8_list,
+    hw_id: *mut wire_uint_8_list,
+    storage_path: *mut wire_uint_8_list,
+) {
+    wire_rust_restore_from_paper_shares_impl(port_, shares, passphrase, pin, hw_id, storage_path)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_rust_sign_intent(
     port_: i64,
@@ -35,16 +447,148 @@ pub extern "C" fn wire_rust_sign_intent(
     wire_rust_sign_intent_impl(port_, identity_id, upi_url)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_rust_sign_pairwise_intent(
+    port_: i64,
+    identity_id: *mut wire_uint_8_list,
+    upi_url: *mut wire_uint_8_list,
+) {
+    wire_rust_sign_pairwise_intent_impl(port_, identity_id, upi_url)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_get_pairwise_did(
+    port_: i64,
+    identity_id: *mut wire_uint_8_list,
+    counterparty: *mut wire_uint_8_list,
+) {
+    wire_rust_get_pairwise_did_impl(port_, identity_id, counterparty)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_list_pairwise_dids(port_: i64, identity_id: *mut wire_uint_8_list) {
+    wire_rust_list_pairwise_dids_impl(port_, identity_id)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_prove_linkage(
+    port_: i64,
+    pin: *mut wire_uint_8_list,
+    identity_id: *mut wire_uint_8_list,
+    counterparty: *mut wire_uint_8_list,
+) {
+    wire_rust_prove_linkage_impl(port_, pin, identity_id, counterparty)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_verify_linkage(port_: i64, proof_json: *mut wire_uint_8_list) {
+    wire_rust_verify_linkage_impl(port_, proof_json)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_rust_publish_to_nostr(port_: i64, signed_json: *mut wire_uint_8_list) {
     wire_rust_publish_to_nostr_impl(port_, signed_json)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_rust_publish_intent(port_: i64, signed_json: *mut wire_uint_8_list) {
+    wire_rust_publish_intent_impl(port_, signed_json)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_publish_private_intent(
+    port_: i64,
+    signed_json: *mut wire_uint_8_list,
+    recipient: *mut wire_uint_8_list,
+) {
+    wire_rust_publish_private_intent_impl(port_, signed_json, recipient)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_fetch_private_intents(port_: i64, identity_id: *mut wire_uint_8_list) {
+    wire_rust_fetch_private_intents_impl(port_, identity_id)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_open_private_intent(
+    port_: i64,
+    identity_id: *mut wire_uint_8_list,
+    wrap_json: *mut wire_uint_8_list,
+) {
+    wire_rust_open_private_intent_impl(port_, identity_id, wrap_json)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_publish_intent_commitment(
+    port_: i64,
+    signed_json: *mut wire_uint_8_list,
+) {
+    wire_rust_publish_intent_commitment_impl(port_, signed_json)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_list_commitments(port_: i64) {
+    wire_rust_list_commitments_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_open_commitment(port_: i64, event_id: *mut wire_uint_8_list) {
+    wire_rust_open_commitment_impl(port_, event_id)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_verify_commitment_opening(
+    port_: i64,
+    opening_json: *mut wire_uint_8_list,
+) {
+    wire_rust_verify_commitment_opening_impl(port_, opening_json)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_get_outbox(port_: i64) {
+    wire_rust_get_outbox_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_retry_outbox(port_: i64) {
+    wire_rust_retry_outbox_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_drop_outbox_item(port_: i64, event_id: *mut wire_uint_8_list) {
+    wire_rust_drop_outbox_item_impl(port_, event_id)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_set_publish_policy(port_: i64, policy: *mut wire_uint_8_list) {
+    wire_rust_set_publish_policy_impl(port_, policy)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_get_nostr_public_key(port_: i64, identity_id: *mut wire_uint_8_list) {
+    wire_rust_get_nostr_public_key_impl(port_, identity_id)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_rust_fetch_interaction_history(port_: i64) {
     wire_rust_fetch_interaction_history_impl(port_)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_rust_query_interaction_history(port_: i64, query: *mut wire_HistoryQuery) {
+    wire_rust_query_interaction_history_impl(port_, query)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_subscribe_events(port_: i64, watch_dids: *mut wire_StringList) {
+    wire_rust_subscribe_events_impl(port_, watch_dids)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_rust_unsubscribe_events(port_: i64, handle: *mut wire_uint_8_list) {
+    wire_rust_unsubscribe_events_impl(port_, handle)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_rust_reset_vault(port_: i64, storage_path: *mut wire_uint_8_list) {
     wire_rust_reset_vault_impl(port_, storage_path)
@@ -52,6 +596,40 @@ pub extern "C" fn wire_rust_reset_vault(port_: i64, storage_path: *mut wire_uint
 
 // Section: allocate functions
 
+#[no_mangle]
+pub extern "C" fn new_StringList_0(len: i32) -> *mut wire_StringList {
+    let wrap = wire_StringList {
+        ptr: support::new_leak_vec_ptr(<*mut wire_uint_8_list>::new_with_null_ptr(), len),
+        len,
+    };
+    support::new_leak_box_ptr(wrap)
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_bool_0(value: bool) -> *mut bool {
+    support::new_leak_box_ptr(value)
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_history_query_0() -> *mut wire_HistoryQuery {
+    support::new_leak_box_ptr(wire_HistoryQuery::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_interaction_type_0(value: i32) -> *mut i32 {
+    support::new_leak_box_ptr(value)
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_u32_0(value: u32) -> *mut u32 {
+    support::new_leak_box_ptr(value)
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_u64_0(value: u64) -> *mut u64 {
+    support::new_leak_box_ptr(value)
+}
+
 #[no_mangle]
 pub extern "C" fn new_uint_8_list_0(len: i32) -> *mut wire_uint_8_list {
     let ans = wire_uint_8_list {
@@ -71,7 +649,57 @@ impl Wire2Api<String> for *mut wire_uint_8_list {
         String::from_utf8_lossy(&vec).into_owned()
     }
 }
-
+impl Wire2Api<Vec<String>> for *mut wire_StringList {
+    fn wire2api(self) -> Vec<String> {
+        let vec = unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            support::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(Wire2Api::wire2api).collect()
+    }
+}
+impl Wire2Api<bool> for *mut bool {
+    fn wire2api(self) -> bool {
+        unsafe { *support::box_from_leak_ptr(self) }
+    }
+}
+impl Wire2Api<HistoryQuery> for *mut wire_HistoryQuery {
+    fn wire2api(self) -> HistoryQuery {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<HistoryQuery>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<InteractionType> for *mut i32 {
+    fn wire2api(self) -> InteractionType {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<InteractionType>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<u32> for *mut u32 {
+    fn wire2api(self) -> u32 {
+        unsafe { *support::box_from_leak_ptr(self) }
+    }
+}
+impl Wire2Api<u64> for *mut u64 {
+    fn wire2api(self) -> u64 {
+        unsafe { *support::box_from_leak_ptr(self) }
+    }
+}
+impl Wire2Api<HistoryQuery> for wire_HistoryQuery {
+    fn wire2api(self) -> HistoryQuery {
+        HistoryQuery {
+            identity_ids: self.identity_ids.wire2api(),
+            counterparty: self.counterparty.wire2api(),
+            since: self.since.wire2api(),
+            until: self.until.wire2api(),
+            interaction_type: self.interaction_type.wire2api(),
+            verified: self.verified.wire2api(),
+            page_size: self.page_size.wire2api(),
+            cursor: self.cursor.wire2api(),
+            timeout_secs: self.timeout_secs.wire2api(),
+        }
+    }
+}
 impl Wire2Api<Vec<u8>> for *mut wire_uint_8_list {
     fn wire2api(self) -> Vec<u8> {
         unsafe {
@@ -82,6 +710,27 @@ impl Wire2Api<Vec<u8>> for *mut wire_uint_8_list {
 }
 // Section: wire structs
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_StringList {
+    ptr: *mut *mut wire_uint_8_list,
+    len: i32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_HistoryQuery {
+    identity_ids: *mut wire_StringList,
+    counterparty: *mut wire_uint_8_list,
+    since: *mut u64,
+    until: *mut u64,
+    interaction_type: *mut i32,
+    verified: *mut bool,
+    page_size: *mut u32,
+    cursor: *mut wire_uint_8_list,
+    timeout_secs: *mut u64,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_uint_8_list {
@@ -101,6 +750,28 @@ impl<T> NewWithNullPtr for *mut T {
     }
 }
 
+impl NewWithNullPtr for wire_HistoryQuery {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            identity_ids: core::ptr::null_mut(),
+            counterparty: core::ptr::null_mut(),
+            since: core::ptr::null_mut(),
+            until: core::ptr::null_mut(),
+            interaction_type: core::ptr::null_mut(),
+            verified: core::ptr::null_mut(),
+            page_size: core::ptr::null_mut(),
+            cursor: core::ptr::null_mut(),
+            timeout_secs: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_HistoryQuery {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 // Section: sync execution mode utility
 
 #[no_mangle]
@@ -20,7 +20,34 @@ use std::sync::Arc;
 
 // Section: imports
 
+use crate::commitment::LedgerEntry;
+use crate::commitment::VerifiedOpening;
+use crate::domain::IdentityStatus;
+use crate::domain::IdentityTombstone;
+use crate::domain::IntentPayload;
+use crate::domain::InteractionType;
+use crate::domain::KeyRecord;
+use crate::domain::PairwiseLink;
+use crate::domain::PinnedKey;
+use crate::domain::RelayAuthGrant;
+use crate::domain::RelayConfig;
+use crate::domain::RevokedKey;
 use crate::domain::SatyaIdentity;
+use crate::domain::SignedIntent;
+use crate::domain::UpiIntent;
+use crate::history::HistoryItem;
+use crate::history::HistoryPage;
+use crate::history::HistoryQuery;
+use crate::lockout::UnlockStatus;
+use crate::outbox::OutboxItem;
+use crate::paper::PaperShare;
+use crate::paper::ShareScan;
+use crate::private::PrivateIntent;
+use crate::publishing::PublishResult;
+use crate::publishing::PublishStatus;
+use crate::publishing::RelayOutcome;
+use crate::relays::RelayHealth;
+use crate::revocation::RevocationState;
 
 // Section: wire functions
 
@@ -44,145 +71,1887 @@ fn wire_rust_initialize_vault_impl(
         },
     )
 }
-fn wire_rust_create_identity_impl(port_: MessagePort, label: impl Wire2Api<String> + UnwindSafe) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, SatyaIdentity, _>(
+fn wire_rust_unlock_vault_impl(
+    port_: MessagePort,
+    storage_path: impl Wire2Api<String> + UnwindSafe,
+    hw_id: impl Wire2Api<String> + UnwindSafe,
+    pin: impl Wire2Api<Option<String>> + UnwindSafe,
+    recovery_code: impl Wire2Api<Option<String>> + UnwindSafe,
+    key_file_path: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
         WrapInfo {
-            debug_name: "rust_create_identity",
+            debug_name: "rust_unlock_vault",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_label = label.wire2api();
-            move |task_callback| rust_create_identity(api_label)
+            let api_storage_path = storage_path.wire2api();
+            let api_hw_id = hw_id.wire2api();
+            let api_pin = pin.wire2api();
+            let api_recovery_code = recovery_code.wire2api();
+            let api_key_file_path = key_file_path.wire2api();
+            move |task_callback| {
+                rust_unlock_vault(
+                    api_storage_path,
+                    api_hw_id,
+                    api_pin,
+                    api_recovery_code,
+                    api_key_file_path,
+                )
+            }
         },
     )
 }
-fn wire_rust_get_identities_impl(port_: MessagePort) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<SatyaIdentity>, _>(
+fn wire_rust_restore_from_mnemonic_impl(
+    port_: MessagePort,
+    mnemonic: impl Wire2Api<String> + UnwindSafe,
+    mask_passphrase: impl Wire2Api<Option<String>> + UnwindSafe,
+    pin: impl Wire2Api<String> + UnwindSafe,
+    hw_id: impl Wire2Api<String> + UnwindSafe,
+    storage_path: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
         WrapInfo {
-            debug_name: "rust_get_identities",
+            debug_name: "rust_restore_from_mnemonic",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| rust_get_identities(),
+        move || {
+            let api_mnemonic = mnemonic.wire2api();
+            let api_mask_passphrase = mask_passphrase.wire2api();
+            let api_pin = pin.wire2api();
+            let api_hw_id = hw_id.wire2api();
+            let api_storage_path = storage_path.wire2api();
+            move |task_callback| {
+                rust_restore_from_mnemonic(
+                    api_mnemonic,
+                    api_mask_passphrase,
+                    api_pin,
+                    api_hw_id,
+                    api_storage_path,
+                )
+            }
+        },
     )
 }
-fn wire_rust_scan_qr_impl(port_: MessagePort, raw_qr_string: impl Wire2Api<String> + UnwindSafe) {
+fn wire_rust_list_relays_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<RelayConfig>, _>(
+        WrapInfo {
+            debug_name: "rust_list_relays",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| rust_list_relays(),
+    )
+}
+fn wire_rust_add_relay_impl(
+    port_: MessagePort,
+    url: impl Wire2Api<String> + UnwindSafe,
+    read: impl Wire2Api<bool> + UnwindSafe,
+    write: impl Wire2Api<bool> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
+        WrapInfo {
+            debug_name: "rust_add_relay",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_url = url.wire2api();
+            let api_read = read.wire2api();
+            let api_write = write.wire2api();
+            move |task_callback| rust_add_relay(api_url, api_read, api_write)
+        },
+    )
+}
+fn wire_rust_remove_relay_impl(port_: MessagePort, url: impl Wire2Api<String> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
+        WrapInfo {
+            debug_name: "rust_remove_relay",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_url = url.wire2api();
+            move |task_callback| rust_remove_relay(api_url)
+        },
+    )
+}
+fn wire_rust_get_relay_status_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<RelayHealth>, _>(
+        WrapInfo {
+            debug_name: "rust_get_relay_status",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| rust_get_relay_status(),
+    )
+}
+fn wire_rust_set_relay_auth_impl(
+    port_: MessagePort,
+    url: impl Wire2Api<String> + UnwindSafe,
+    identity_id: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
+        WrapInfo {
+            debug_name: "rust_set_relay_auth",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_url = url.wire2api();
+            let api_identity_id = identity_id.wire2api();
+            move |task_callback| rust_set_relay_auth(api_url, api_identity_id)
+        },
+    )
+}
+fn wire_rust_list_relay_auth_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<RelayAuthGrant>, _>(
+        WrapInfo {
+            debug_name: "rust_list_relay_auth",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| rust_list_relay_auth(),
+    )
+}
+fn wire_rust_start_local_relay_impl(
+    port_: MessagePort,
+    bind_address: impl Wire2Api<String> + UnwindSafe,
+    storage_path: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
         WrapInfo {
-            debug_name: "rust_scan_qr",
+            debug_name: "rust_start_local_relay",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_raw_qr_string = raw_qr_string.wire2api();
-            move |task_callback| rust_scan_qr(api_raw_qr_string)
+            let api_bind_address = bind_address.wire2api();
+            let api_storage_path = storage_path.wire2api();
+            move |task_callback| rust_start_local_relay(api_bind_address, api_storage_path)
         },
     )
 }
-fn wire_rust_sign_intent_impl(
+fn wire_rust_stop_local_relay_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
+        WrapInfo {
+            debug_name: "rust_stop_local_relay",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| rust_stop_local_relay(),
+    )
+}
+fn wire_rust_generate_recovery_code_impl(
     port_: MessagePort,
-    identity_id: impl Wire2Api<String> + UnwindSafe,
-    upi_url: impl Wire2Api<String> + UnwindSafe,
+    pin: impl Wire2Api<String> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
         WrapInfo {
-            debug_name: "rust_sign_intent",
+            debug_name: "rust_generate_recovery_code",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_identity_id = identity_id.wire2api();
-            let api_upi_url = upi_url.wire2api();
-            move |task_callback| rust_sign_intent(api_identity_id, api_upi_url)
+            let api_pin = pin.wire2api();
+            move |task_callback| rust_generate_recovery_code(api_pin)
         },
     )
 }
-fn wire_rust_publish_to_nostr_impl(
+fn wire_rust_enroll_unlock_factors_impl(
     port_: MessagePort,
-    signed_json: impl Wire2Api<String> + UnwindSafe,
+    pin: impl Wire2Api<String> + UnwindSafe,
+    factors: impl Wire2Api<Vec<String>> + UnwindSafe,
+    require_all: impl Wire2Api<bool> + UnwindSafe,
+    key_file_path: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Option<String>, _>(
+        WrapInfo {
+            debug_name: "rust_enroll_unlock_factors",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_pin = pin.wire2api();
+            let api_factors = factors.wire2api();
+            let api_require_all = require_all.wire2api();
+            let api_key_file_path = key_file_path.wire2api();
+            move |task_callback| {
+                rust_enroll_unlock_factors(api_pin, api_factors, api_require_all, api_key_file_path)
+            }
+        },
+    )
+}
+fn wire_rust_revoke_unlock_factor_impl(
+    port_: MessagePort,
+    pin: impl Wire2Api<String> + UnwindSafe,
+    factor: impl Wire2Api<String> + UnwindSafe,
+    key_file_path: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
         WrapInfo {
-            debug_name: "rust_publish_to_nostr",
+            debug_name: "rust_revoke_unlock_factor",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_signed_json = signed_json.wire2api();
-            move |task_callback| rust_publish_to_nostr(api_signed_json)
+            let api_pin = pin.wire2api();
+            let api_factor = factor.wire2api();
+            let api_key_file_path = key_file_path.wire2api();
+            move |task_callback| rust_revoke_unlock_factor(api_pin, api_factor, api_key_file_path)
         },
     )
 }
-fn wire_rust_fetch_interaction_history_impl(port_: MessagePort) {
+fn wire_rust_list_unlock_factors_impl(port_: MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<String>, _>(
         WrapInfo {
-            debug_name: "rust_fetch_interaction_history",
+            debug_name: "rust_list_unlock_factors",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| rust_fetch_interaction_history(),
+        move || move |task_callback| rust_list_unlock_factors(),
     )
 }
-fn wire_rust_reset_vault_impl(
+fn wire_rust_change_pin_impl(
     port_: MessagePort,
-    storage_path: impl Wire2Api<String> + UnwindSafe,
+    old_pin: impl Wire2Api<String> + UnwindSafe,
+    new_pin: impl Wire2Api<String> + UnwindSafe,
+    recovery_code: impl Wire2Api<Option<String>> + UnwindSafe,
+    key_file_path: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
         WrapInfo {
-            debug_name: "rust_reset_vault",
+            debug_name: "rust_change_pin",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_storage_path = storage_path.wire2api();
-            move |task_callback| rust_reset_vault(api_storage_path)
+            let api_old_pin = old_pin.wire2api();
+            let api_new_pin = new_pin.wire2api();
+            let api_recovery_code = recovery_code.wire2api();
+            let api_key_file_path = key_file_path.wire2api();
+            move |task_callback| {
+                rust_change_pin(
+                    api_old_pin,
+                    api_new_pin,
+                    api_recovery_code,
+                    api_key_file_path,
+                )
+            }
         },
     )
 }
-// Section: wrapper structs
-
-// Section: static checks
-
-// Section: allocate functions
-
-// Section: related functions
-
-// Section: impl Wire2Api
-
-pub trait Wire2Api<T> {
-    fn wire2api(self) -> T;
+fn wire_rust_configure_duress_pin_impl(
+    port_: MessagePort,
+    pin: impl Wire2Api<String> + UnwindSafe,
+    duress_pin: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
+        WrapInfo {
+            debug_name: "rust_configure_duress_pin",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_pin = pin.wire2api();
+            let api_duress_pin = duress_pin.wire2api();
+            move |task_callback| rust_configure_duress_pin(api_pin, api_duress_pin)
+        },
+    )
 }
-
-impl<T, S> Wire2Api<Option<T>> for *mut S
-where
-    *mut S: Wire2Api<T>,
-{
-    fn wire2api(self) -> Option<T> {
-        (!self.is_null()).then(|| self.wire2api())
-    }
+fn wire_rust_clear_duress_pin_impl(port_: MessagePort, pin: impl Wire2Api<String> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
+        WrapInfo {
+            debug_name: "rust_clear_duress_pin",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_pin = pin.wire2api();
+            move |task_callback| rust_clear_duress_pin(api_pin)
+        },
+    )
 }
-
-impl Wire2Api<u8> for u8 {
-    fn wire2api(self) -> u8 {
-        self
-    }
+fn wire_rust_export_mnemonic_impl(
+    port_: MessagePort,
+    pin: impl Wire2Api<String> + UnwindSafe,
+    mask_passphrase: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+        WrapInfo {
+            debug_name: "rust_export_mnemonic",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_pin = pin.wire2api();
+            let api_mask_passphrase = mask_passphrase.wire2api();
+            move |task_callback| rust_export_mnemonic(api_pin, api_mask_passphrase)
+        },
+    )
 }
-
-// Section: impl IntoDart
-
-impl support::IntoDart for SatyaIdentity {
-    fn into_dart(self) -> support::DartAbi {
-        vec![
-            self.id.into_into_dart().into_dart(),
-            self.label.into_into_dart().into_dart(),
-            self.did.into_into_dart().into_dart(),
-        ]
-        .into_dart()
-    }
+fn wire_rust_verify_mnemonic_impl(
+    port_: MessagePort,
+    mnemonic: impl Wire2Api<String> + UnwindSafe,
+    mask_passphrase: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
+        WrapInfo {
+            debug_name: "rust_verify_mnemonic",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_mnemonic = mnemonic.wire2api();
+            let api_mask_passphrase = mask_passphrase.wire2api();
+            move |task_callback| rust_verify_mnemonic(api_mnemonic, api_mask_passphrase)
+        },
+    )
 }
-impl support::IntoDartExceptPrimitive for SatyaIdentity {}
-impl rust2dart::IntoIntoDart<SatyaIdentity> for SatyaIdentity {
+fn wire_rust_get_unlock_status_impl(
+    port_: MessagePort,
+    storage_path: impl Wire2Api<String> + UnwindSafe,
+    hw_id: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, UnlockStatus, _>(
+        WrapInfo {
+            debug_name: "rust_get_unlock_status",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_storage_path = storage_path.wire2api();
+            let api_hw_id = hw_id.wire2api();
+            move |task_callback| rust_get_unlock_status(api_storage_path, api_hw_id)
+        },
+    )
+}
+fn wire_rust_set_wipe_after_impl(
+    port_: MessagePort,
+    pin: impl Wire2Api<String> + UnwindSafe,
+    limit: impl Wire2Api<Option<u32>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
+        WrapInfo {
+            debug_name: "rust_set_wipe_after",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_pin = pin.wire2api();
+            let api_limit = limit.wire2api();
+            move |task_callback| rust_set_wipe_after(api_pin, api_limit)
+        },
+    )
+}
+fn wire_rust_set_hardware_binding_impl(
+    port_: MessagePort,
+    pin: impl Wire2Api<String> + UnwindSafe,
+    strategy: impl Wire2Api<String> + UnwindSafe,
+    key_file_path: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
+        WrapInfo {
+            debug_name: "rust_set_hardware_binding",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_pin = pin.wire2api();
+            let api_strategy = strategy.wire2api();
+            let api_key_file_path = key_file_path.wire2api();
+            move |task_callback| rust_set_hardware_binding(api_pin, api_strategy, api_key_file_path)
+        },
+    )
+}
+fn wire_rust_rebind_device_impl(
+    port_: MessagePort,
+    pin: impl Wire2Api<String> + UnwindSafe,
+    recovery_code: impl Wire2Api<String> + UnwindSafe,
+    hw_id: impl Wire2Api<String> + UnwindSafe,
+    storage_path: impl Wire2Api<String> + UnwindSafe,
+    strategy: impl Wire2Api<String> + UnwindSafe,
+    key_file_path: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
+        WrapInfo {
+            debug_name: "rust_rebind_device",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_pin = pin.wire2api();
+            let api_recovery_code = recovery_code.wire2api();
+            let api_hw_id = hw_id.wire2api();
+            let api_storage_path = storage_path.wire2api();
+            let api_strategy = strategy.wire2api();
+            let api_key_file_path = key_file_path.wire2api();
+            move |task_callback| {
+                rust_rebind_device(
+                    api_pin,
+                    api_recovery_code,
+                    api_hw_id,
+                    api_storage_path,
+                    api_strategy,
+                    api_key_file_path,
+                )
+            }
+        },
+    )
+}
+fn wire_rust_create_identity_impl(port_: MessagePort, label: impl Wire2Api<String> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, SatyaIdentity, _>(
+        WrapInfo {
+            debug_name: "rust_create_identity",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_label = label.wire2api();
+            move |task_callback| rust_create_identity(api_label)
+        },
+    )
+}
+fn wire_rust_publish_identity_profile_impl(
+    port_: MessagePort,
+    identity_id: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
+        WrapInfo {
+            debug_name: "rust_publish_identity_profile",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_identity_id = identity_id.wire2api();
+            move |task_callback| rust_publish_identity_profile(api_identity_id)
+        },
+    )
+}
+fn wire_rust_discover_identities_impl(
+    port_: MessagePort,
+    gap_limit: impl Wire2Api<Option<u32>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<SatyaIdentity>, _>(
+        WrapInfo {
+            debug_name: "rust_discover_identities",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_gap_limit = gap_limit.wire2api();
+            move |task_callback| rust_discover_identities(api_gap_limit)
+        },
+    )
+}
+fn wire_rust_get_guardian_pubkey_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+        WrapInfo {
+            debug_name: "rust_get_guardian_pubkey",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| rust_get_guardian_pubkey(),
+    )
+}
+fn wire_rust_create_social_recovery_impl(
+    port_: MessagePort,
+    pin: impl Wire2Api<String> + UnwindSafe,
+    guardians: impl Wire2Api<Vec<String>> + UnwindSafe,
+    threshold: impl Wire2Api<u32> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<String>, _>(
+        WrapInfo {
+            debug_name: "rust_create_social_recovery",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_pin = pin.wire2api();
+            let api_guardians = guardians.wire2api();
+            let api_threshold = threshold.wire2api();
+            move |task_callback| rust_create_social_recovery(api_pin, api_guardians, api_threshold)
+        },
+    )
+}
+fn wire_rust_fetch_guardian_shares_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<String>, _>(
+        WrapInfo {
+            debug_name: "rust_fetch_guardian_shares",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| rust_fetch_guardian_shares(),
+    )
+}
+fn wire_rust_release_guardian_share_impl(
+    port_: MessagePort,
+    pin: impl Wire2Api<String> + UnwindSafe,
+    envelope: impl Wire2Api<String> + UnwindSafe,
+    requester_pubkey: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+        WrapInfo {
+            debug_name: "rust_release_guardian_share",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_pin = pin.wire2api();
+            let api_envelope = envelope.wire2api();
+            let api_requester_pubkey = requester_pubkey.wire2api();
+            move |task_callback| {
+                rust_release_guardian_share(api_pin, api_envelope, api_requester_pubkey)
+            }
+        },
+    )
+}
+fn wire_rust_begin_social_recovery_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+        WrapInfo {
+            debug_name: "rust_begin_social_recovery",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| rust_begin_social_recovery(),
+    )
+}
+fn wire_rust_collect_recovery_shares_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<String>, _>(
+        WrapInfo {
+            debug_name: "rust_collect_recovery_shares",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| rust_collect_recovery_shares(),
+    )
+}
+fn wire_rust_recover_from_shares_impl(
+    port_: MessagePort,
+    envelopes: impl Wire2Api<Vec<String>> + UnwindSafe,
+    pin: impl Wire2Api<String> + UnwindSafe,
+    hw_id: impl Wire2Api<String> + UnwindSafe,
+    storage_path: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
+        WrapInfo {
+            debug_name: "rust_recover_from_shares",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_envelopes = envelopes.wire2api();
+            let api_pin = pin.wire2api();
+            let api_hw_id = hw_id.wire2api();
+            let api_storage_path = storage_path.wire2api();
+            move |task_callback| {
+                rust_recover_from_shares(api_envelopes, api_pin, api_hw_id, api_storage_path)
+            }
+        },
+    )
+}
+fn wire_rust_rename_identity_impl(
+    port_: MessagePort,
+    identity_id: impl Wire2Api<String> + UnwindSafe,
+    label: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, SatyaIdentity, _>(
+        WrapInfo {
+            debug_name: "rust_rename_identity",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_identity_id = identity_id.wire2api();
+            let api_label = label.wire2api();
+            move |task_callback| rust_rename_identity(api_identity_id, api_label)
+        },
+    )
+}
+fn wire_rust_archive_identity_impl(
+    port_: MessagePort,
+    identity_id: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, SatyaIdentity, _>(
+        WrapInfo {
+            debug_name: "rust_archive_identity",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_identity_id = identity_id.wire2api();
+            move |task_callback| rust_archive_identity(api_identity_id)
+        },
+    )
+}
+fn wire_rust_unarchive_identity_impl(
+    port_: MessagePort,
+    identity_id: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, SatyaIdentity, _>(
+        WrapInfo {
+            debug_name: "rust_unarchive_identity",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_identity_id = identity_id.wire2api();
+            move |task_callback| rust_unarchive_identity(api_identity_id)
+        },
+    )
+}
+fn wire_rust_delete_identity_impl(
+    port_: MessagePort,
+    pin: impl Wire2Api<String> + UnwindSafe,
+    identity_id: impl Wire2Api<String> + UnwindSafe,
+    publish_retirement: impl Wire2Api<bool> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Option<String>, _>(
+        WrapInfo {
+            debug_name: "rust_delete_identity",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_pin = pin.wire2api();
+            let api_identity_id = identity_id.wire2api();
+            let api_publish_retirement = publish_retirement.wire2api();
+            move |task_callback| {
+                rust_delete_identity(api_pin, api_identity_id, api_publish_retirement)
+            }
+        },
+    )
+}
+fn wire_rust_get_tombstones_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<IdentityTombstone>, _>(
+        WrapInfo {
+            debug_name: "rust_get_tombstones",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| rust_get_tombstones(),
+    )
+}
+fn wire_rust_rotate_identity_key_impl(
+    port_: MessagePort,
+    pin: impl Wire2Api<String> + UnwindSafe,
+    identity_id: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+        WrapInfo {
+            debug_name: "rust_rotate_identity_key",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_pin = pin.wire2api();
+            let api_identity_id = identity_id.wire2api();
+            move |task_callback| rust_rotate_identity_key(api_pin, api_identity_id)
+        },
+    )
+}
+fn wire_rust_get_key_history_impl(
+    port_: MessagePort,
+    identity_id: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<KeyRecord>, _>(
+        WrapInfo {
+            debug_name: "rust_get_key_history",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_identity_id = identity_id.wire2api();
+            move |task_callback| rust_get_key_history(api_identity_id)
+        },
+    )
+}
+fn wire_rust_publish_revocation_list_impl(
+    port_: MessagePort,
+    pin: impl Wire2Api<String> + UnwindSafe,
+    identity_id: impl Wire2Api<String> + UnwindSafe,
+    public_keys: impl Wire2Api<Vec<String>> + UnwindSafe,
+    revoke_identity: impl Wire2Api<bool> + UnwindSafe,
+    since: impl Wire2Api<Option<u64>> + UnwindSafe,
+    reason: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+        WrapInfo {
+            debug_name: "rust_publish_revocation_list",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_pin = pin.wire2api();
+            let api_identity_id = identity_id.wire2api();
+            let api_public_keys = public_keys.wire2api();
+            let api_revoke_identity = revoke_identity.wire2api();
+            let api_since = since.wire2api();
+            let api_reason = reason.wire2api();
+            move |task_callback| {
+                rust_publish_revocation_list(
+                    api_pin,
+                    api_identity_id,
+                    api_public_keys,
+                    api_revoke_identity,
+                    api_since,
+                    api_reason,
+                )
+            }
+        },
+    )
+}
+fn wire_rust_get_revocation_status_impl(
+    port_: MessagePort,
+    did: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, RevocationState, _>(
+        WrapInfo {
+            debug_name: "rust_get_revocation_status",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_did = did.wire2api();
+            move |task_callback| rust_get_revocation_status(api_did)
+        },
+    )
+}
+fn wire_rust_verify_intent_impl(
+    port_: MessagePort,
+    signed_json: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
+        WrapInfo {
+            debug_name: "rust_verify_intent",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_signed_json = signed_json.wire2api();
+            move |task_callback| rust_verify_intent(api_signed_json)
+        },
+    )
+}
+fn wire_rust_get_did_document_impl(
+    port_: MessagePort,
+    identity_id: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+        WrapInfo {
+            debug_name: "rust_get_did_document",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_identity_id = identity_id.wire2api();
+            move |task_callback| rust_get_did_document(api_identity_id)
+        },
+    )
+}
+fn wire_rust_publish_did_document_impl(
+    port_: MessagePort,
+    identity_id: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
+        WrapInfo {
+            debug_name: "rust_publish_did_document",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_identity_id = identity_id.wire2api();
+            move |task_callback| rust_publish_did_document(api_identity_id)
+        },
+    )
+}
+fn wire_rust_resolve_did_impl(port_: MessagePort, did: impl Wire2Api<String> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+        WrapInfo {
+            debug_name: "rust_resolve_did",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_did = did.wire2api();
+            move |task_callback| rust_resolve_did(api_did)
+        },
+    )
+}
+fn wire_rust_pin_did_key_impl(
+    port_: MessagePort,
+    did: impl Wire2Api<String> + UnwindSafe,
+    public_key_hex: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
+        WrapInfo {
+            debug_name: "rust_pin_did_key",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_did = did.wire2api();
+            let api_public_key_hex = public_key_hex.wire2api();
+            move |task_callback| rust_pin_did_key(api_did, api_public_key_hex)
+        },
+    )
+}
+fn wire_rust_unpin_did_key_impl(port_: MessagePort, did: impl Wire2Api<String> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
+        WrapInfo {
+            debug_name: "rust_unpin_did_key",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_did = did.wire2api();
+            move |task_callback| rust_unpin_did_key(api_did)
+        },
+    )
+}
+fn wire_rust_list_pinned_keys_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<PinnedKey>, _>(
+        WrapInfo {
+            debug_name: "rust_list_pinned_keys",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| rust_list_pinned_keys(),
+    )
+}
+fn wire_rust_get_identities_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<SatyaIdentity>, _>(
+        WrapInfo {
+            debug_name: "rust_get_identities",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| rust_get_identities(),
+    )
+}
+fn wire_rust_scan_qr_impl(port_: MessagePort, raw_qr_string: impl Wire2Api<String> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+        WrapInfo {
+            debug_name: "rust_scan_qr",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_raw_qr_string = raw_qr_string.wire2api();
+            move |task_callback| rust_scan_qr(api_raw_qr_string)
+        },
+    )
+}
+fn wire_rust_scan_paper_share_impl(
+    port_: MessagePort,
+    raw_qr_string: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, ShareScan, _>(
+        WrapInfo {
+            debug_name: "rust_scan_paper_share",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_raw_qr_string = raw_qr_string.wire2api();
+            move |task_callback| rust_scan_paper_share(api_raw_qr_string)
+        },
+    )
+}
+fn wire_rust_export_paper_shares_impl(
+    port_: MessagePort,
+    pin: impl Wire2Api<String> + UnwindSafe,
+    threshold: impl Wire2Api<u32> + UnwindSafe,
+    count: impl Wire2Api<u32> + UnwindSafe,
+    passphrase: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<PaperShare>, _>(
+        WrapInfo {
+            debug_name: "rust_export_paper_shares",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_pin = pin.wire2api();
+            let api_threshold = threshold.wire2api();
+            let api_count = count.wire2api();
+            let api_passphrase = passphrase.wire2api();
+            move |task_callback| {
+                rust_export_paper_shares(api_pin, api_threshold, api_count, api_passphrase)
+            }
+        },
+    )
+}
+fn wire_rust_restore_from_paper_shares_impl(
+    port_: MessagePort,
+    shares: impl Wire2Api<Vec<String>> + UnwindSafe,
+    passphrase: impl Wire2Api<Option<String>> + UnwindSafe,
+    pin: impl Wire2Api<String> + UnwindSafe,
+    hw_id: impl Wire2Api<String> + UnwindSafe,
+    storage_path: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
+        WrapInfo {
+            debug_name: "rust_restore_from_paper_shares",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_shares = shares.wire2api();
+            let api_passphrase = passphrase.wire2api();
+            let api_pin = pin.wire2api();
+            let api_hw_id = hw_id.wire2api();
+            let api_storage_path = storage_path.wire2api();
+            move |task_callback| {
+                rust_restore_from_paper_shares(
+                    api_shares,
+                    api_passphrase,
+                    api_pin,
+                    api_hw_id,
+                    api_storage_path,
+                )
+            }
+        },
+    )
+}
+fn wire_rust_sign_intent_impl(
+    port_: MessagePort,
+    identity_id: impl Wire2Api<String> + UnwindSafe,
+    upi_url: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+        WrapInfo {
+            debug_name: "rust_sign_intent",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_identity_id = identity_id.wire2api();
+            let api_upi_url = upi_url.wire2api();
+            move |task_callback| rust_sign_intent(api_identity_id, api_upi_url)
+        },
+    )
+}
+fn wire_rust_sign_pairwise_intent_impl(
+    port_: MessagePort,
+    identity_id: impl Wire2Api<String> + UnwindSafe,
+    upi_url: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+        WrapInfo {
+            debug_name: "rust_sign_pairwise_intent",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_identity_id = identity_id.wire2api();
+            let api_upi_url = upi_url.wire2api();
+            move |task_callback| rust_sign_pairwise_intent(api_identity_id, api_upi_url)
+        },
+    )
+}
+fn wire_rust_get_pairwise_did_impl(
+    port_: MessagePort,
+    identity_id: impl Wire2Api<String> + UnwindSafe,
+    counterparty: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, PairwiseLink, _>(
+        WrapInfo {
+            debug_name: "rust_get_pairwise_did",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_identity_id = identity_id.wire2api();
+            let api_counterparty = counterparty.wire2api();
+            move |task_callback| rust_get_pairwise_did(api_identity_id, api_counterparty)
+        },
+    )
+}
+fn wire_rust_list_pairwise_dids_impl(
+    port_: MessagePort,
+    identity_id: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<PairwiseLink>, _>(
+        WrapInfo {
+            debug_name: "rust_list_pairwise_dids",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_identity_id = identity_id.wire2api();
+            move |task_callback| rust_list_pairwise_dids(api_identity_id)
+        },
+    )
+}
+fn wire_rust_prove_linkage_impl(
+    port_: MessagePort,
+    pin: impl Wire2Api<String> + UnwindSafe,
+    identity_id: impl Wire2Api<String> + UnwindSafe,
+    counterparty: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+        WrapInfo {
+            debug_name: "rust_prove_linkage",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_pin = pin.wire2api();
+            let api_identity_id = identity_id.wire2api();
+            let api_counterparty = counterparty.wire2api();
+            move |task_callback| rust_prove_linkage(api_pin, api_identity_id, api_counterparty)
+        },
+    )
+}
+fn wire_rust_verify_linkage_impl(
+    port_: MessagePort,
+    proof_json: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
+        WrapInfo {
+            debug_name: "rust_verify_linkage",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_proof_json = proof_json.wire2api();
+            move |task_callback| rust_verify_linkage(api_proof_json)
+        },
+    )
+}
+fn wire_rust_publish_to_nostr_impl(
+    port_: MessagePort,
+    signed_json: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
+        WrapInfo {
+            debug_name: "rust_publish_to_nostr",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_signed_json = signed_json.wire2api();
+            move |task_callback| rust_publish_to_nostr(api_signed_json)
+        },
+    )
+}
+fn wire_rust_publish_intent_impl(
+    port_: MessagePort,
+    signed_json: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, PublishResult, _>(
+        WrapInfo {
+            debug_name: "rust_publish_intent",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_signed_json = signed_json.wire2api();
+            move |task_callback| rust_publish_intent(api_signed_json)
+        },
+    )
+}
+fn wire_rust_publish_private_intent_impl(
+    port_: MessagePort,
+    signed_json: impl Wire2Api<String> + UnwindSafe,
+    recipient: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, PublishResult, _>(
+        WrapInfo {
+            debug_name: "rust_publish_private_intent",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_signed_json = signed_json.wire2api();
+            let api_recipient = recipient.wire2api();
+            move |task_callback| rust_publish_private_intent(api_signed_json, api_recipient)
+        },
+    )
+}
+fn wire_rust_fetch_private_intents_impl(
+    port_: MessagePort,
+    identity_id: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<PrivateIntent>, _>(
+        WrapInfo {
+            debug_name: "rust_fetch_private_intents",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_identity_id = identity_id.wire2api();
+            move |task_callback| rust_fetch_private_intents(api_identity_id)
+        },
+    )
+}
+fn wire_rust_open_private_intent_impl(
+    port_: MessagePort,
+    identity_id: impl Wire2Api<String> + UnwindSafe,
+    wrap_json: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, PrivateIntent, _>(
+        WrapInfo {
+            debug_name: "rust_open_private_intent",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_identity_id = identity_id.wire2api();
+            let api_wrap_json = wrap_json.wire2api();
+            move |task_callback| rust_open_private_intent(api_identity_id, api_wrap_json)
+        },
+    )
+}
+fn wire_rust_publish_intent_commitment_impl(
+    port_: MessagePort,
+    signed_json: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, PublishResult, _>(
+        WrapInfo {
+            debug_name: "rust_publish_intent_commitment",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_signed_json = signed_json.wire2api();
+            move |task_callback| rust_publish_intent_commitment(api_signed_json)
+        },
+    )
+}
+fn wire_rust_list_commitments_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<LedgerEntry>, _>(
+        WrapInfo {
+            debug_name: "rust_list_commitments",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| rust_list_commitments(),
+    )
+}
+fn wire_rust_open_commitment_impl(
+    port_: MessagePort,
+    event_id: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+        WrapInfo {
+            debug_name: "rust_open_commitment",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_event_id = event_id.wire2api();
+            move |task_callback| rust_open_commitment(api_event_id)
+        },
+    )
+}
+fn wire_rust_verify_commitment_opening_impl(
+    port_: MessagePort,
+    opening_json: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, VerifiedOpening, _>(
+        WrapInfo {
+            debug_name: "rust_verify_commitment_opening",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_opening_json = opening_json.wire2api();
+            move |task_callback| rust_verify_commitment_opening(api_opening_json)
+        },
+    )
+}
+fn wire_rust_get_outbox_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<OutboxItem>, _>(
+        WrapInfo {
+            debug_name: "rust_get_outbox",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| rust_get_outbox(),
+    )
+}
+fn wire_rust_retry_outbox_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, u32, _>(
+        WrapInfo {
+            debug_name: "rust_retry_outbox",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| rust_retry_outbox(),
+    )
+}
+fn wire_rust_drop_outbox_item_impl(
+    port_: MessagePort,
+    event_id: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
+        WrapInfo {
+            debug_name: "rust_drop_outbox_item",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_event_id = event_id.wire2api();
+            move |task_callback| rust_drop_outbox_item(api_event_id)
+        },
+    )
+}
+fn wire_rust_set_publish_policy_impl(
+    port_: MessagePort,
+    policy: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
+        WrapInfo {
+            debug_name: "rust_set_publish_policy",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_policy = policy.wire2api();
+            move |task_callback| rust_set_publish_policy(api_policy)
+        },
+    )
+}
+fn wire_rust_get_nostr_public_key_impl(
+    port_: MessagePort,
+    identity_id: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+        WrapInfo {
+            debug_name: "rust_get_nostr_public_key",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_identity_id = identity_id.wire2api();
+            move |task_callback| rust_get_nostr_public_key(api_identity_id)
+        },
+    )
+}
+fn wire_rust_fetch_interaction_history_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<String>, _>(
+        WrapInfo {
+            debug_name: "rust_fetch_interaction_history",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| rust_fetch_interaction_history(),
+    )
+}
+fn wire_rust_query_interaction_history_impl(
+    port_: MessagePort,
+    query: impl Wire2Api<HistoryQuery> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, HistoryPage, _>(
+        WrapInfo {
+            debug_name: "rust_query_interaction_history",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_query = query.wire2api();
+            move |task_callback| rust_query_interaction_history(api_query)
+        },
+    )
+}
+fn wire_rust_subscribe_events_impl(
+    port_: MessagePort,
+    watch_dids: impl Wire2Api<Vec<String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "rust_subscribe_events",
+            port: Some(port_),
+            mode: FfiCallMode::Stream,
+        },
+        move || {
+            let api_watch_dids = watch_dids.wire2api();
+            move |task_callback| {
+                rust_subscribe_events(api_watch_dids, task_callback.stream_sink::<_, String>())
+            }
+        },
+    )
+}
+fn wire_rust_unsubscribe_events_impl(
+    port_: MessagePort,
+    handle: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
+        WrapInfo {
+            debug_name: "rust_unsubscribe_events",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_handle = handle.wire2api();
+            move |task_callback| rust_unsubscribe_events(api_handle)
+        },
+    )
+}
+fn wire_rust_reset_vault_impl(
+    port_: MessagePort,
+    storage_path: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
+        WrapInfo {
+            debug_name: "rust_reset_vault",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_storage_path = storage_path.wire2api();
+            move |task_callback| rust_reset_vault(api_storage_path)
+        },
+    )
+}
+// Section: wrapper structs
+
+// Section: static checks
+
+// Section: allocate functions
+
+// Section: related functions
+
+// Section: impl Wire2Api
+
+pub trait Wire2Api<T> {
+    fn wire2api(self) -> T;
+}
+
+impl<T, S> Wire2Api<Option<T>> for *mut S
+where
+    *mut S: Wire2Api<T>,
+{
+    fn wire2api(self) -> Option<T> {
+        (!self.is_null()).then(|| self.wire2api())
+    }
+}
+
+impl Wire2Api<bool> for bool {
+    fn wire2api(self) -> bool {
+        self
+    }
+}
+impl Wire2Api<i32> for i32 {
+    fn wire2api(self) -> i32 {
+        self
+    }
+}
+impl Wire2Api<InteractionType> for i32 {
+    fn wire2api(self) -> InteractionType {
+        match self {
+            0 => InteractionType::PaymentIntent,
+            1 => InteractionType::IdentityVerification,
+            _ => unreachable!("Invalid variant for InteractionType: {}", self),
+        }
+    }
+}
+impl Wire2Api<u32> for u32 {
+    fn wire2api(self) -> u32 {
+        self
+    }
+}
+impl Wire2Api<u64> for u64 {
+    fn wire2api(self) -> u64 {
+        self
+    }
+}
+impl Wire2Api<u8> for u8 {
+    fn wire2api(self) -> u8 {
+        self
+    }
+}
+
+// Section: impl IntoDart
+
+impl support::IntoDart for HistoryItem {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.event_id.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.seen_on.into_into_dart().into_dart(),
+            self.intent.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for HistoryItem {}
+impl rust2dart::IntoIntoDart<HistoryItem> for HistoryItem {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for HistoryPage {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.items.into_into_dart().into_dart(),
+            self.next_cursor.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for HistoryPage {}
+impl rust2dart::IntoIntoDart<HistoryPage> for HistoryPage {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for IdentityStatus {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Active => 0,
+            Self::Archived => 1,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for IdentityStatus {}
+impl rust2dart::IntoIntoDart<IdentityStatus> for IdentityStatus {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for IdentityTombstone {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.id.into_into_dart().into_dart(),
+            self.did.into_into_dart().into_dart(),
+            self.derivation_path.into_into_dart().into_dart(),
+            self.deleted_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for IdentityTombstone {}
+impl rust2dart::IntoIntoDart<IdentityTombstone> for IdentityTombstone {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for IntentPayload {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.version.into_into_dart().into_dart(),
+            self.interaction_type.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+            self.upi_data.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for IntentPayload {}
+impl rust2dart::IntoIntoDart<IntentPayload> for IntentPayload {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for InteractionType {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::PaymentIntent => 0,
+            Self::IdentityVerification => 1,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for InteractionType {}
+impl rust2dart::IntoIntoDart<InteractionType> for InteractionType {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for KeyRecord {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.public_key_hex.into_into_dart().into_dart(),
+            self.derivation_path.into_into_dart().into_dart(),
+            self.valid_from.into_into_dart().into_dart(),
+            self.valid_until.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for KeyRecord {}
+impl rust2dart::IntoIntoDart<KeyRecord> for KeyRecord {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for LedgerEntry {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.event_id.into_into_dart().into_dart(),
+            self.commitment_hex.into_into_dart().into_dart(),
+            self.salt_hex.into_into_dart().into_dart(),
+            self.intent.into_into_dart().into_dart(),
+            self.committed_at.into_into_dart().into_dart(),
+            self.author_binding_hex.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for LedgerEntry {}
+impl rust2dart::IntoIntoDart<LedgerEntry> for LedgerEntry {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for OutboxItem {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.event_id.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.event_json.into_into_dart().into_dart(),
+            self.queued_at.into_into_dart().into_dart(),
+            self.attempts.into_into_dart().into_dart(),
+            self.next_attempt_at.into_into_dart().into_dart(),
+            self.last_error.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for OutboxItem {}
+impl rust2dart::IntoIntoDart<OutboxItem> for OutboxItem {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for PairwiseLink {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.identity_id.into_into_dart().into_dart(),
+            self.counterparty.into_into_dart().into_dart(),
+            self.did.into_into_dart().into_dart(),
+            self.derivation_path.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PairwiseLink {}
+impl rust2dart::IntoIntoDart<PairwiseLink> for PairwiseLink {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for PaperShare {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.index.into_into_dart().into_dart(),
+            self.mnemonic.into_into_dart().into_dart(),
+            self.qr_svg.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PaperShare {}
+impl rust2dart::IntoIntoDart<PaperShare> for PaperShare {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for PinnedKey {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.did.into_into_dart().into_dart(),
+            self.public_key_hex.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PinnedKey {}
+impl rust2dart::IntoIntoDart<PinnedKey> for PinnedKey {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for PrivateIntent {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.event_id.into_into_dart().into_dart(),
+            self.sender_public_key.into_into_dart().into_dart(),
+            self.sent_at.into_into_dart().into_dart(),
+            self.intent.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PrivateIntent {}
+impl rust2dart::IntoIntoDart<PrivateIntent> for PrivateIntent {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for PublishResult {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.event_id.into_into_dart().into_dart(),
+            self.acknowledgments.into_into_dart().into_dart(),
+            self.success.into_into_dart().into_dart(),
+            self.outcomes.into_into_dart().into_dart(),
+            self.queued.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PublishResult {}
+impl rust2dart::IntoIntoDart<PublishResult> for PublishResult {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for PublishStatus {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Ok => 0,
+            Self::Duplicate => 1,
+            Self::Blocked => 2,
+            Self::RateLimited => 3,
+            Self::Invalid => 4,
+            Self::AuthRequired => 5,
+            Self::Rejected => 6,
+            Self::Timeout => 7,
+            Self::Failed => 8,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PublishStatus {}
+impl rust2dart::IntoIntoDart<PublishStatus> for PublishStatus {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for RelayAuthGrant {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.url.into_into_dart().into_dart(),
+            self.identity_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for RelayAuthGrant {}
+impl rust2dart::IntoIntoDart<RelayAuthGrant> for RelayAuthGrant {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for RelayConfig {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.url.into_into_dart().into_dart(),
+            self.read.into_into_dart().into_dart(),
+            self.write.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for RelayConfig {}
+impl rust2dart::IntoIntoDart<RelayConfig> for RelayConfig {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for RelayHealth {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.url.into_into_dart().into_dart(),
+            self.read.into_into_dart().into_dart(),
+            self.write.into_into_dart().into_dart(),
+            self.connected.into_into_dart().into_dart(),
+            self.latency_ms.into_dart(),
+            self.last_error.into_dart(),
+            self.consecutive_failures.into_into_dart().into_dart(),
+            self.next_retry_at.into_into_dart().into_dart(),
+            self.in_write_set.into_into_dart().into_dart(),
+            self.auth_requested.into_into_dart().into_dart(),
+            self.authenticated_as.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for RelayHealth {}
+impl rust2dart::IntoIntoDart<RelayHealth> for RelayHealth {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for RelayOutcome {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.url.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for RelayOutcome {}
+impl rust2dart::IntoIntoDart<RelayOutcome> for RelayOutcome {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for RevocationState {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.did.into_into_dart().into_dart(),
+            self.recovery_public_key_hex.into_into_dart().into_dart(),
+            self.revoked_keys.into_into_dart().into_dart(),
+            self.identity_revoked_at.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for RevocationState {}
+impl rust2dart::IntoIntoDart<RevocationState> for RevocationState {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for RevokedKey {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.public_key_hex.into_into_dart().into_dart(),
+            self.revoked_at.into_into_dart().into_dart(),
+            self.reason.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for RevokedKey {}
+impl rust2dart::IntoIntoDart<RevokedKey> for RevokedKey {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for SatyaIdentity {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.id.into_into_dart().into_dart(),
+            self.label.into_into_dart().into_dart(),
+            self.did.into_into_dart().into_dart(),
+            self.derivation_path.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for SatyaIdentity {}
+impl rust2dart::IntoIntoDart<SatyaIdentity> for SatyaIdentity {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for ShareScan {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.collected.into_into_dart().into_dart(),
+            self.required.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ShareScan {}
+impl rust2dart::IntoIntoDart<ShareScan> for ShareScan {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for SignedIntent {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.payload.into_into_dart().into_dart(),
+            self.signature_hex.into_into_dart().into_dart(),
+            self.signer_did.into_into_dart().into_dart(),
+            self.signer_public_key_hex.into_into_dart().into_dart(),
+            self.is_verified.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for SignedIntent {}
+impl rust2dart::IntoIntoDart<SignedIntent> for SignedIntent {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for UnlockStatus {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.failed_attempts.into_into_dart().into_dart(),
+            self.retry_after_secs.into_into_dart().into_dart(),
+            self.attempts_before_wipe.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for UnlockStatus {}
+impl rust2dart::IntoIntoDart<UnlockStatus> for UnlockStatus {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for UpiIntent {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.vpa.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.amount.into_into_dart().into_dart(),
+            self.currency.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for UpiIntent {}
+impl rust2dart::IntoIntoDart<UpiIntent> for UpiIntent {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for VerifiedOpening {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.event_id.into_into_dart().into_dart(),
+            self.committed_at.into_into_dart().into_dart(),
+            self.intent.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for VerifiedOpening {}
+impl rust2dart::IntoIntoDart<VerifiedOpening> for VerifiedOpening {
     fn into_into_dart(self) -> Self {
         self
     }
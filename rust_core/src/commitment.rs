@@ -0,0 +1,127 @@
+/**
+ * FILE: rust_core/src/commitment.rs
+ * VERSION: 1.0.0
+ * PHASE: Phase 11.18 (Intent Commitments)
+ * PURPOSE: Salted hash commitments to intent payloads and the local ledger
+ * of their openings. The ledger is sealed like the outbox: losing it makes
+ * the published commitments impossible to open.
+ */
+use crate::crypto::{VaultKey, seal, open};
+use crate::domain::{IntentCommitment, IntentOpening, IntentPayload, SignedIntent, PROTOCOL_VERSION};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+
+/// A commitment this device published, with what is needed to open it.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct LedgerEntry {
+    pub event_id: String,
+    pub commitment_hex: String,
+    pub salt_hex: String,
+    pub intent: SignedIntent,
+    pub committed_at: u64,
+    #[serde(default)]
+    pub author_binding_hex: String,
+}
+
+impl LedgerEntry {
+    pub fn opening(&self) -> IntentOpening {
+        IntentOpening {
+            version: PROTOCOL_VERSION.to_string(),
+            event_id: self.event_id.clone(),
+            salt_hex: self.salt_hex.clone(),
+            intent: self.intent.clone(),
+            author_binding_hex: self.author_binding_hex.clone(),
+        }
+    }
+}
+
+/// What a verifier learns from an opening that matches a commitment
+/// published by the intent's signer. `intent.is_verified` reports whether
+/// that signer key belongs to the signer's DID.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct VerifiedOpening {
+    pub event_id: String,
+    /// `created_at` of the commitment event. Asserted by its author, not by
+    /// the relays: it dates the intent only as far as the signer is trusted.
+    pub committed_at: u64,
+    pub intent: SignedIntent,
+}
+
+/// The bytes committed to: the payload JSON exactly as it is signed.
+pub fn canonical_payload(payload: &IntentPayload) -> Result<Vec<u8>> {
+    Ok(serde_json::to_vec(payload)?)
+}
+
+/// SHA-256 over a domain tag, the 32-byte salt and the canonical payload.
+pub fn commit(payload: &IntentPayload, salt: &[u8]) -> Result<IntentCommitment> {
+    let mut hasher = Sha256::new();
+    hasher.update(b"satya_commitment_v1");
+    hasher.update(salt);
+    hasher.update(canonical_payload(payload)?);
+    Ok(IntentCommitment { version: PROTOCOL_VERSION.to_string(), commitment_hex: hex::encode(hasher.finalize()) })
+}
+
+/// Whether `opening` reveals the payload behind `published`.
+pub fn opens(published: &IntentCommitment, opening: &IntentOpening) -> Result<bool> {
+    let salt = hex::decode(&opening.salt_hex)?;
+    Ok(salt.len() == 32 && commit(&opening.intent.payload, &salt)?.commitment_hex == published.commitment_hex)
+}
+
+pub struct CommitmentLedger {
+    path: PathBuf,
+    key: Option<VaultKey>,
+    entries: Vec<LedgerEntry>,
+}
+
+impl CommitmentLedger {
+    /// Opens the ledger of the vault at `base_path`. An unreadable file is
+    /// treated as empty and replaced on the next write.
+    pub fn new(base_path: &Path, data_key: &VaultKey) -> Result<Self> {
+        let mut path = base_path.to_path_buf();
+        path.push("satya_vault/ledger.bin");
+        let key = data_key.chain("satya_ledger_v1", b"")?;
+        let entries = fs::read(&path).ok()
+            .and_then(|raw| {
+                let (nonce, ciphertext) = raw.split_at_checked(12)?;
+                open(&key, nonce, ciphertext).ok()
+            })
+            .and_then(|plain| serde_json::from_slice(&plain).ok())
+            .unwrap_or_default();
+        Ok(Self { path, key: Some(key), entries })
+    }
+
+    /// In-memory ledger (nothing is persisted), e.g. for a decoy session.
+    pub fn ephemeral() -> Self {
+        Self { path: PathBuf::new(), key: None, entries: Vec::new() }
+    }
+
+    pub fn entries(&self) -> &[LedgerEntry] {
+        &self.entries
+    }
+
+    pub fn find(&self, event_id: &str) -> Option<&LedgerEntry> {
+        self.entries.iter().find(|e| e.event_id == event_id)
+    }
+
+    pub fn record(&mut self, entry: LedgerEntry) -> Result<()> {
+        if self.find(&entry.event_id).is_some() { return Ok(()); }
+        self.entries.push(entry);
+        self.store()
+    }
+
+    fn store(&self) -> Result<()> {
+        let Some(key) = &self.key else { return Ok(()) };
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let (nonce, ciphertext) = seal(key, &serde_json::to_vec(&self.entries)?)?;
+        let tmp_path = self.path.with_extension("tmp");
+        fs::write(&tmp_path, [nonce, ciphertext].concat())?;
+        File::open(&tmp_path)?.sync_all()?;
+        fs::rename(&tmp_path, &self.path)?;
+        Ok(())
+    }
+}
@@ -4,10 +4,9 @@
  * PHASE: Phase 9.0 (Verified Swarm)
  * PURPOSE: Core cryptographic primitives with signature verification.
  */
-
 use argon2::{
     password_hash::{PasswordHasher, SaltString},
-    Argon2,
+    Algorithm, Argon2, Params, Version,
 };
 use chacha20poly1305::{
     aead::{Aead, KeyInit},
@@ -15,10 +14,66 @@ use chacha20poly1305::{
 };
 use anyhow::{Result, anyhow};
 use ed25519_dalek::{Keypair, Signer, Verifier, SecretKey, PublicKey, Signature};
+use hmac::Mac;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+/// Crockford base32 alphabet: no I, L, O or U to survive handwriting.
+const RECOVERY_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const RECOVERY_CODE_BYTES: usize = 20;
+
+/// Argon2id cost parameters persisted with every PIN derivation.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KdfParams {
+    pub m_cost_kib: u32,
+    pub t_cost: u32,
+    pub p_cost: u32,
+}
+
+impl KdfParams {
+    /// Current recommendation; raised over time, old vaults keep their own.
+    pub fn recommended() -> Self {
+        KdfParams { m_cost_kib: 19 * 1024, t_cost: 2, p_cost: 1 }
+    }
+}
 
 pub struct VaultKey([u8; 32]);
 
 impl VaultKey {
+    pub fn generate() -> Self {
+        VaultKey(rand::random())
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let key: [u8; 32] = bytes.try_into().map_err(|_| anyhow!("Invalid key length"))?;
+        Ok(VaultKey(key))
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// Folds one factor secret into a slot key under a domain tag.
+    pub fn chain(&self, tag: &str, secret: &[u8]) -> Result<Self> {
+        let mut mac = <hmac::Hmac<Sha256> as Mac>::new_from_slice(&self.0)
+            .map_err(|_| anyhow!("Derivation Error"))?;
+        mac.update(tag.as_bytes());
+        mac.update(secret);
+        let mut key = [0u8; 32];
+        key.copy_from_slice(&mac.finalize().into_bytes());
+        Ok(VaultKey(key))
+    }
+
+    pub fn from_pin_with(pin: &str, salt: &[u8], params: &KdfParams) -> Result<Self> {
+        let params = Params::new(params.m_cost_kib, params.t_cost, params.p_cost, Some(32))
+            .map_err(|_| anyhow!("Invalid KDF parameters"))?;
+        let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, params);
+        let mut key = [0u8; 32];
+        argon2.hash_password_into(pin.as_bytes(), salt, &mut key)
+            .map_err(|_| anyhow!("Argon2 error"))?;
+        Ok(VaultKey(key))
+    }
+
     pub fn from_pin(pin: &str, salt: &[u8]) -> Result<Self> {
         let argon2 = Argon2::default();
         let salt_string = SaltString::encode_b64(salt).map_err(|_| anyhow!("Salt error"))?;
@@ -34,15 +89,23 @@ impl VaultKey {
     }
 }
 
-pub fn encrypt_with_binding(key: &VaultKey, hw_id: &[u8], data: &[u8]) -> Result<Vec<u8>> {
+/// Encrypts under a fresh random nonce. Returns (nonce, ciphertext).
+pub fn seal(key: &VaultKey, data: &[u8]) -> Result<(Vec<u8>, Vec<u8>)> {
     let cipher = ChaCha20Poly1305::new(&key.0.into());
-    let mut nonce_bytes = [0u8; 12];
-    let binding = ring::digest::digest(&ring::digest::SHA256, hw_id);
-    nonce_bytes.copy_from_slice(&binding.as_ref()[..12]);
+    let nonce_bytes: [u8; 12] = rand::random();
     let nonce = Nonce::from_slice(&nonce_bytes);
-    cipher.encrypt(nonce, data).map_err(|_| anyhow!("Encryption failed"))
+    let ciphertext = cipher.encrypt(nonce, data).map_err(|_| anyhow!("Encryption failed"))?;
+    Ok((nonce_bytes.to_vec(), ciphertext))
 }
 
+pub fn open(key: &VaultKey, nonce: &[u8], data: &[u8]) -> Result<Vec<u8>> {
+    if nonce.len() != 12 { return Err(anyhow!("Invalid nonce")); }
+    let cipher = ChaCha20Poly1305::new(&key.0.into());
+    cipher.decrypt(Nonce::from_slice(nonce), data).map_err(|_| anyhow!("Decryption failed"))
+}
+
+/// Legacy (pre-envelope) vault format: nonce derived from the raw hardware id.
+/// Only used to read and migrate vaults written before Phase 10.1.
 pub fn decrypt_with_binding(key: &VaultKey, hw_id: &[u8], data: &[u8]) -> Result<Vec<u8>> {
     let cipher = ChaCha20Poly1305::new(&key.0.into());
     let mut nonce_bytes = [0u8; 12];
@@ -60,9 +123,49 @@ pub fn sign_with_key(priv_key: &[u8], message: &[u8]) -> Result<Vec<u8>> {
     Ok(signature.to_bytes().to_vec())
 }
 
+pub fn public_key_from_secret(priv_key: &[u8]) -> Result<Vec<u8>> {
+    let secret = SecretKey::from_bytes(priv_key).map_err(|_| anyhow!("Invalid secret"))?;
+    let public: PublicKey = (&secret).into();
+    Ok(public.to_bytes().to_vec())
+}
+
 pub fn verify_with_key(pub_key_bytes: &[u8], message: &[u8], signature_bytes: &[u8]) -> Result<bool> {
     let public = PublicKey::from_bytes(pub_key_bytes).map_err(|_| anyhow!("Invalid public key"))?;
     let signature = Signature::from_bytes(signature_bytes).map_err(|_| anyhow!("Invalid signature format"))?;
     public.verify(message, &signature).map_err(|e| anyhow!("Verification failed: {}", e))?;
     Ok(true)
-}
\ No newline at end of file
+}
+
+/// Printable recovery code, e.g. `7K3M-...`, 32 symbols in groups of four.
+pub fn generate_recovery_code() -> String {
+    let bytes: [u8; RECOVERY_CODE_BYTES] = rand::random();
+    let mut symbols = Vec::with_capacity(32);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+    for byte in bytes {
+        buffer = (buffer << 8) | byte as u32;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            symbols.push(RECOVERY_ALPHABET[((buffer >> bits) & 31) as usize] as char);
+        }
+    }
+    symbols.chunks(4).map(|c| c.iter().collect::<String>()).collect::<Vec<_>>().join("-")
+}
+
+/// Canonical form of a typed recovery code: uppercase, no separators, lookalikes folded.
+pub fn normalize_recovery_code(code: &str) -> String {
+    code.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| match c.to_ascii_uppercase() {
+            'O' => '0',
+            'I' | 'L' => '1',
+            other => other,
+        })
+        .collect()
+}
+
+/// Length-checked comparison whose timing does not depend on where bytes differ.
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
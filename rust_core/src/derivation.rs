@@ -0,0 +1,115 @@
+/**
+ * FILE: rust_core/src/derivation.rs
+ * VERSION: 1.0.0
+ * PHASE: Phase 11.1 (Standard Derivation)
+ * PURPOSE: Identity key derivation from the master seed. New identities use
+ * SLIP-0010 hardened Ed25519 paths; the pre-11.1 HMAC scheme stays readable.
+ * Each identity also gets a BIP-32 secp256k1 Nostr key in the spirit of NIP-06.
+ */
+use anyhow::{Result, anyhow};
+use hmac::Mac;
+use nostr_sdk::bitcoin::bip32::{DerivationPath, ExtendedPrivKey};
+use nostr_sdk::bitcoin::Network;
+use nostr_sdk::{Keys, SECP256K1};
+use sha2::Sha512;
+use std::str::FromStr;
+
+/// SLIP-0044 style purpose/coin prefix for Satya identities.
+pub const IDENTITY_PATH_PREFIX: &str = "m/44'/7327'";
+/// NIP-06 purpose/coin prefix for Nostr keys.
+pub const NOSTR_PATH_PREFIX: &str = "m/44'/1237'";
+/// Paths of identities created with the original `satya_identity_{index}` scheme.
+pub const LEGACY_PATH_PREFIX: &str = "legacy";
+
+const HARDENED: u32 = 0x8000_0000;
+
+pub fn identity_path(index: u32) -> String {
+    format!("{}/{}'", IDENTITY_PATH_PREFIX, index)
+}
+
+pub fn legacy_path(index: u32) -> String {
+    format!("{}/{}", LEGACY_PATH_PREFIX, index)
+}
+
+/// Derives the 32-byte Ed25519 secret for a persisted identity path.
+pub fn derive_identity_key(master_seed: &[u8], path: &str) -> Result<Vec<u8>> {
+    if let Some(index) = path.strip_prefix(LEGACY_PATH_PREFIX).and_then(|rest| rest.strip_prefix('/')) {
+        let index: u32 = index.parse().map_err(|_| anyhow!("Invalid legacy path: {}", path))?;
+        return derive_legacy(master_seed, index);
+    }
+    let (key, _) = slip10_derive(master_seed, path)?;
+    Ok(key.to_vec())
+}
+
+/// NIP-06 style path of the Nostr key that belongs to an identity or
+/// pairwise path: the identity index becomes the account, so
+/// `m/44'/7327'/3'` maps to `m/44'/1237'/3'/0/0` and the pairwise
+/// `m/44'/7327'/3'/1'/9'` to `m/44'/1237'/3'/1'/9'`. Callers pass the
+/// identity's original path so the key survives rotations.
+pub fn nostr_path(path: &str) -> Result<String> {
+    let rest = path.strip_prefix(IDENTITY_PATH_PREFIX)
+        .or_else(|| path.strip_prefix(LEGACY_PATH_PREFIX))
+        .and_then(|rest| rest.strip_prefix('/'))
+        .ok_or_else(|| anyhow!("Not an identity path: {}", path))?;
+    let mut segments = rest.split('/');
+    let account: u32 = segments.next().unwrap_or_default().trim_end_matches('\'').parse()
+        .map_err(|_| anyhow!("Invalid identity path: {}", path))?;
+    let branch: Vec<&str> = segments.collect();
+    Ok(if branch.is_empty() {
+        format!("{}/{}'/0/0", NOSTR_PATH_PREFIX, account)
+    } else {
+        format!("{}/{}'/{}", NOSTR_PATH_PREFIX, account, branch.join("/"))
+    })
+}
+
+/// BIP-32 secp256k1 Nostr keys for a path from `nostr_path`.
+pub fn derive_nostr_keys(master_seed: &[u8], nostr_path: &str) -> Result<Keys> {
+    let root = ExtendedPrivKey::new_master(Network::Bitcoin, master_seed).map_err(|_| anyhow!("Derivation Error"))?;
+    let path = DerivationPath::from_str(nostr_path).map_err(|_| anyhow!("Invalid path: {}", nostr_path))?;
+    let child = root.derive_priv(&SECP256K1, &path).map_err(|_| anyhow!("Derivation Error"))?;
+    Ok(Keys::new(child.private_key))
+}
+
+/// Original scheme: `HMAC-SHA512(master_seed, "satya_identity_{index}")[..32]`.
+pub fn derive_legacy(master_seed: &[u8], index: u32) -> Result<Vec<u8>> {
+    let mut mac = <hmac::SimpleHmac<Sha512> as Mac>::new_from_slice(master_seed)
+        .map_err(|_| anyhow!("Derivation Error"))?;
+    mac.update(format!("satya_identity_{}", index).as_bytes());
+    Ok(mac.finalize().into_bytes()[..32].to_vec())
+}
+
+/// SLIP-0010 Ed25519 derivation. Only hardened segments exist for Ed25519.
+/// Returns (private key, chain code).
+pub fn slip10_derive(seed: &[u8], path: &str) -> Result<([u8; 32], [u8; 32])> {
+    let mut segments = path.split('/');
+    if segments.next() != Some("m") { return Err(anyhow!("Path must start with m: {}", path)); }
+
+    let (mut key, mut chain) = split(&hmac_sha512(b"ed25519 seed", &[seed])?);
+    for segment in segments {
+        let index = segment.strip_suffix('\'')
+            .ok_or_else(|| anyhow!("Ed25519 only supports hardened segments: {}", segment))?
+            .parse::<u32>()
+            .map_err(|_| anyhow!("Invalid path segment: {}", segment))?;
+        if index >= HARDENED { return Err(anyhow!("Path index out of range: {}", index)); }
+        let data = hmac_sha512(&chain, &[&[0u8], &key, &(index | HARDENED).to_be_bytes()])?;
+        (key, chain) = split(&data);
+    }
+    Ok((key, chain))
+}
+
+fn hmac_sha512(key: &[u8], parts: &[&[u8]]) -> Result<[u8; 64]> {
+    let mut mac = <hmac::SimpleHmac<Sha512> as Mac>::new_from_slice(key)
+        .map_err(|_| anyhow!("Derivation Error"))?;
+    for part in parts {
+        mac.update(part);
+    }
+    Ok(mac.finalize().into_bytes().into())
+}
+
+fn split(data: &[u8; 64]) -> ([u8; 32], [u8; 32]) {
+    let mut left = [0u8; 32];
+    let mut right = [0u8; 32];
+    left.copy_from_slice(&data[..32]);
+    right.copy_from_slice(&data[32..]);
+    (left, right)
+}
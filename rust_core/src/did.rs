@@ -0,0 +1,338 @@
+/**
+ * FILE: rust_core/src/did.rs
+ * VERSION: 1.0.0
+ * PHASE: Phase 11.8 (DID Documents)
+ * PURPOSE: W3C DID Core documents for Satya identities and pluggable DID
+ * resolution (did:satya, did:key, did:peer, did:web) used by all signature verification.
+ */
+use crate::crypto::{public_key_from_secret, sign_with_key, verify_with_key};
+use crate::domain::{KeyRecord, KeyRotation, SatyaIdentity};
+use crate::revocation::RevocationState;
+use crate::rotation;
+use anyhow::{Result, anyhow};
+use curve25519_dalek::edwards::CompressedEdwardsY;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+const DID_CONTEXT: &str = "https://www.w3.org/ns/did/v1";
+const ED25519_2020_CONTEXT: &str = "https://w3id.org/security/suites/ed25519-2020/v1";
+const X25519_2020_CONTEXT: &str = "https://w3id.org/security/suites/x25519-2020/v1";
+/// Multicodec prefixes (varint) for raw public keys.
+const ED25519_CODEC: [u8; 2] = [0xed, 0x01];
+const X25519_CODEC: [u8; 2] = [0xec, 0x01];
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationMethod {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub controller: String,
+    pub public_key_multibase: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DidService {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub service_endpoint: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DidDocument {
+    #[serde(rename = "@context")]
+    pub context: Vec<String>,
+    pub id: String,
+    #[serde(default)]
+    pub verification_method: Vec<VerificationMethod>,
+    #[serde(default)]
+    pub authentication: Vec<String>,
+    #[serde(default)]
+    pub assertion_method: Vec<String>,
+    #[serde(default)]
+    pub key_agreement: Vec<VerificationMethod>,
+    #[serde(default)]
+    pub service: Vec<DidService>,
+}
+
+impl DidDocument {
+    /// Ed25519 keys listed for assertion (signing intents), as hex.
+    pub fn assertion_keys(&self) -> Vec<String> {
+        self.verification_method.iter()
+            .filter(|m| self.assertion_method.contains(&m.id))
+            .filter_map(|m| decode_multikey(&m.public_key_multibase, ED25519_CODEC).ok())
+            .map(hex::encode)
+            .collect()
+    }
+}
+
+/// Resolves a DID to its document. Verifiers ask for key histories, which
+/// default to the document's assertion keys with no validity bounds.
+pub trait DidResolver {
+    fn resolve(&self, did: &str) -> Result<DidDocument>;
+
+    fn key_history(&self, did: &str) -> Result<Vec<KeyRecord>> {
+        Ok(self.resolve(did)?.assertion_keys().into_iter()
+            .map(|public_key_hex| KeyRecord { public_key_hex, derivation_path: String::new(), valid_from: 0, valid_until: None })
+            .collect())
+    }
+}
+
+/// Builds the document of an Ed25519-keyed DID with a derived X25519
+/// key-agreement key and Nostr relay service endpoints.
+pub fn document_for_key(did: &str, public_key: &[u8], relays: &[String]) -> Result<DidDocument> {
+    let key_id = format!("{}#key-1", did);
+    let mut document = DidDocument {
+        context: vec![DID_CONTEXT.into(), ED25519_2020_CONTEXT.into(), X25519_2020_CONTEXT.into()],
+        id: did.to_string(),
+        verification_method: vec![VerificationMethod {
+            id: key_id.clone(),
+            kind: "Ed25519VerificationKey2020".into(),
+            controller: did.to_string(),
+            public_key_multibase: encode_multikey(ED25519_CODEC, public_key),
+        }],
+        authentication: vec![key_id.clone()],
+        assertion_method: vec![key_id],
+        key_agreement: vec![VerificationMethod {
+            id: format!("{}#key-agreement-1", did),
+            kind: "X25519KeyAgreementKey2020".into(),
+            controller: did.to_string(),
+            public_key_multibase: encode_multikey(X25519_CODEC, &ed25519_to_x25519(public_key)?),
+        }],
+        service: Vec::new(),
+    };
+    if !relays.is_empty() {
+        document.service.push(DidService {
+            id: format!("{}#nostr", did),
+            kind: "NostrRelay".into(),
+            service_endpoint: relays.to_vec(),
+        });
+    }
+    Ok(document)
+}
+
+/// Document of one of the vault's own identities.
+pub fn document_for_identity(identity: &SatyaIdentity, private_key: &[u8], relays: &[String]) -> Result<DidDocument> {
+    document_for_key(&identity.did, &public_key_from_secret(private_key)?, relays)
+}
+
+/// did:satya. Keys come from the vault (own identities) or from published
+/// rotation statements linked to a pinned key; documents from the vault or
+/// from relays. The identifier is a random UUID, so nothing but a pin ties
+/// a foreign DID to a key.
+#[derive(Default, Clone)]
+pub struct SatyaResolver {
+    pub own: HashMap<String, Vec<KeyRecord>>,
+    pub documents: HashMap<String, DidDocument>,
+    pub rotations: Vec<KeyRotation>,
+    /// Pinned key of each foreign DID the user vouched for.
+    pub anchors: HashMap<String, String>,
+    pub revocations: HashMap<String, RevocationState>,
+    pub relays: Vec<String>,
+}
+
+impl DidResolver for SatyaResolver {
+    fn resolve(&self, did: &str) -> Result<DidDocument> {
+        if !did.starts_with("did:satya:") { return Err(anyhow!("Not a did:satya: {}", did)); }
+        let current = self.key_history(did)?.into_iter().rev().find(|k| k.valid_until.is_none());
+        let published = self.documents.get(did);
+        match (current, published) {
+            (Some(key), published) => {
+                let mut document = document_for_key(did, &hex::decode(&key.public_key_hex)?, &self.relays)?;
+                if let Some(published) = published {
+                    document.service = published.service.clone();
+                }
+                Ok(document)
+            },
+            (None, Some(published)) => Ok(published.clone()),
+            (None, None) => Err(anyhow!("Unresolvable DID: {}", did)),
+        }
+    }
+
+    fn key_history(&self, did: &str) -> Result<Vec<KeyRecord>> {
+        if let Some(history) = self.own.get(did) { return Ok(history.clone()); }
+        let revocations = self.revocations.get(did).cloned().unwrap_or_default();
+        if let Some(anchor) = self.anchors.get(did) {
+            return Ok(rotation::history_from_rotations(did, anchor, &self.rotations, &revocations));
+        }
+        // Relay-published documents are unsigned and the DID names no key:
+        // with nothing anchored there is nothing to verify against.
+        Ok(Vec::new())
+    }
+}
+
+/// did:key with Ed25519 keys: the document is derived from the DID itself.
+pub struct KeyResolver;
+
+impl DidResolver for KeyResolver {
+    fn resolve(&self, did: &str) -> Result<DidDocument> {
+        let multibase = did.strip_prefix("did:key:").ok_or_else(|| anyhow!("Not a did:key: {}", did))?;
+        let public_key = decode_multikey(multibase, ED25519_CODEC)?;
+        let mut document = document_for_key(did, &public_key, &[])?;
+        // did:key names its verification method after the key itself.
+        let key_id = format!("{}#{}", did, multibase);
+        document.verification_method[0].id = key_id.clone();
+        document.authentication = vec![key_id.clone()];
+        document.assertion_method = vec![key_id];
+        let agreement = &mut document.key_agreement[0];
+        agreement.id = format!("{}#{}", did, agreement.public_key_multibase);
+        Ok(document)
+    }
+}
+
+/// did:peer with numalgo 0: an inception key, resolved like did:key.
+pub struct PeerResolver;
+
+impl DidResolver for PeerResolver {
+    fn resolve(&self, did: &str) -> Result<DidDocument> {
+        document_for_key(did, &peer_public_key(did)?, &[])
+    }
+}
+
+/// did:web. Documents are read from a local mirror directory laid out like
+/// the web server, or through an injected HTTP stand-in.
+pub struct WebResolver {
+    source: WebSource,
+}
+
+type Fetcher = Box<dyn Fn(&str) -> Result<String> + Send + Sync>;
+
+enum WebSource {
+    Directory(PathBuf),
+    Fetch(Fetcher),
+}
+
+impl WebResolver {
+    pub fn from_directory(root: impl Into<PathBuf>) -> Self {
+        WebResolver { source: WebSource::Directory(root.into()) }
+    }
+
+    /// `fetch` receives the `https://` URL of the document and returns its body.
+    pub fn with_fetcher(fetch: impl Fn(&str) -> Result<String> + Send + Sync + 'static) -> Self {
+        WebResolver { source: WebSource::Fetch(Box::new(fetch)) }
+    }
+
+    /// `did:web:example.com:user:alice` -> `example.com/user/alice/did.json`;
+    /// a bare domain maps to `example.com/.well-known/did.json`.
+    pub fn document_path(did: &str) -> Result<String> {
+        let rest = did.strip_prefix("did:web:").ok_or_else(|| anyhow!("Not a did:web: {}", did))?;
+        let mut segments = rest.split(':').map(|s| s.replace("%3A", ":"));
+        let domain = segments.next().filter(|d| !d.is_empty()).ok_or_else(|| anyhow!("Missing did:web domain"))?;
+        let path: Vec<String> = segments.collect();
+        if path.iter().any(|s| s.is_empty() || s == ".." || s.contains('/')) {
+            return Err(anyhow!("Invalid did:web path: {}", did));
+        }
+        Ok(if path.is_empty() {
+            format!("{}/.well-known/did.json", domain)
+        } else {
+            format!("{}/{}/did.json", domain, path.join("/"))
+        })
+    }
+}
+
+impl DidResolver for WebResolver {
+    fn resolve(&self, did: &str) -> Result<DidDocument> {
+        let path = Self::document_path(did)?;
+        let body = match &self.source {
+            WebSource::Directory(root) => fs::read_to_string(root.join(&path))
+                .map_err(|_| anyhow!("No local document for {}", did))?,
+            WebSource::Fetch(fetch) => fetch(&format!("https://{}", path))?,
+        };
+        let document: DidDocument = serde_json::from_str(&body)?;
+        if document.id != did { return Err(anyhow!("Document id does not match {}", did)); }
+        Ok(document)
+    }
+}
+
+/// Dispatches on the DID method.
+#[derive(Default)]
+pub struct MultiResolver {
+    resolvers: Vec<(String, Box<dyn DidResolver + Send + Sync>)>,
+}
+
+impl MultiResolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, method: &str, resolver: impl DidResolver + Send + Sync + 'static) -> Self {
+        self.resolvers.push((format!("did:{}:", method), Box::new(resolver)));
+        self
+    }
+
+    fn route(&self, did: &str) -> Result<&(dyn DidResolver + Send + Sync)> {
+        self.resolvers.iter()
+            .find(|(prefix, _)| did.starts_with(prefix.as_str()))
+            .map(|(_, resolver)| resolver.as_ref())
+            .ok_or_else(|| anyhow!("Unsupported DID method: {}", did))
+    }
+}
+
+impl DidResolver for MultiResolver {
+    fn resolve(&self, did: &str) -> Result<DidDocument> {
+        self.route(did)?.resolve(did)
+    }
+
+    fn key_history(&self, did: &str) -> Result<Vec<KeyRecord>> {
+        self.route(did)?.key_history(did)
+    }
+}
+
+/// Signs, with a DID's Ed25519 signing key, that the Nostr key
+/// `nostr_public_key` (x-only hex) speaks for the DID on relays. Nostr keys
+/// are derived privately, so verifiers only learn them from such a binding.
+pub fn bind_nostr_key(signing_key: &[u8], did: &str, nostr_public_key: &str) -> Result<String> {
+    Ok(hex::encode(sign_with_key(signing_key, &binding_message(did, nostr_public_key))?))
+}
+
+/// Whether `binding_hex` is `public_key_hex`'s binding of `nostr_public_key` to `did`.
+pub fn verify_nostr_binding(public_key_hex: &str, did: &str, nostr_public_key: &str, binding_hex: &str) -> bool {
+    hex::decode(public_key_hex).ok()
+        .zip(hex::decode(binding_hex).ok())
+        .is_some_and(|(key, signature)| verify_with_key(&key, &binding_message(did, nostr_public_key), &signature).is_ok())
+}
+
+fn binding_message(did: &str, nostr_public_key: &str) -> Vec<u8> {
+    format!("satya_nostr_binding_v1:{}:{}", did, nostr_public_key.to_lowercase()).into_bytes()
+}
+
+/// `did:key` identifier of an Ed25519 public key.
+pub fn did_key(public_key: &[u8]) -> String {
+    format!("did:key:{}", encode_multikey(ED25519_CODEC, public_key))
+}
+
+/// `did:peer:0` identifier of an Ed25519 public key.
+pub fn did_peer(public_key: &[u8]) -> String {
+    format!("did:peer:0{}", encode_multikey(ED25519_CODEC, public_key))
+}
+
+/// Ed25519 key of a numalgo 0 did:peer.
+pub fn peer_public_key(did: &str) -> Result<Vec<u8>> {
+    let multibase = did.strip_prefix("did:peer:0").ok_or_else(|| anyhow!("Not a numalgo 0 did:peer: {}", did))?;
+    decode_multikey(multibase, ED25519_CODEC)
+}
+
+fn encode_multikey(codec: [u8; 2], key: &[u8]) -> String {
+    format!("z{}", bs58::encode([&codec[..], key].concat()).into_string())
+}
+
+fn decode_multikey(multibase: &str, codec: [u8; 2]) -> Result<Vec<u8>> {
+    let encoded = multibase.strip_prefix('z').ok_or_else(|| anyhow!("Only base58btc multibase is supported"))?;
+    let raw = bs58::decode(encoded).into_vec().map_err(|_| anyhow!("Invalid multibase key"))?;
+    match raw.strip_prefix(&codec[..]) {
+        Some(key) if key.len() == 32 => Ok(key.to_vec()),
+        _ => Err(anyhow!("Unexpected key type")),
+    }
+}
+
+/// Birational map from an Ed25519 public key to its X25519 counterpart.
+fn ed25519_to_x25519(public_key: &[u8]) -> Result<[u8; 32]> {
+    let bytes: [u8; 32] = public_key.try_into().map_err(|_| anyhow!("Invalid public key"))?;
+    let point = CompressedEdwardsY(bytes).decompress().ok_or_else(|| anyhow!("Invalid public key"))?;
+    Ok(point.to_montgomery().to_bytes())
+}
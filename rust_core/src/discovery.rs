@@ -0,0 +1,165 @@
+/**
+ * FILE: rust_core/src/discovery.rs
+ * VERSION: 1.0.0
+ * PHASE: Phase 11.2 (Identity Discovery)
+ * PURPOSE: Rebuilds the identity list after a seed restore. Each identity may
+ * publish an encrypted profile (NIP-78 app data) under a locator that only
+ * the seed holder can compute; discovery walks derivation indices against it.
+ */
+use crate::crypto::{VaultKey, seal, open, public_key_from_secret};
+use crate::derivation;
+use crate::domain::{SatyaIdentity, IdentityStatus};
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+
+/// NIP-78 arbitrary application data (parameterized replaceable).
+pub const PROFILE_KIND: u64 = 30078;
+pub const DEFAULT_GAP_LIMIT: u32 = 20;
+const LOCATOR_PREFIX: &str = "satya-id:";
+
+/// Profile content as sealed into the event.
+#[derive(Serialize, Deserialize)]
+struct IdentityProfile {
+    id: String,
+    label: String,
+    did: String,
+    derivation_path: String,
+    #[serde(default)]
+    status: IdentityStatus,
+    /// Set when the identity was deleted; restores keep it as a tombstone.
+    #[serde(default)]
+    retired: bool,
+    /// Set on the profile of a rotated-away key; the live one sits at a later index.
+    #[serde(default)]
+    superseded: bool,
+}
+
+/// Which version of an identity's profile to publish.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ProfileState {
+    Live,
+    /// The key was rotated; discovery ignores this index.
+    Superseded,
+    /// The identity was deleted; restores keep a tombstone.
+    Retired,
+}
+
+/// An identity recovered from relays, with its re-derived secret.
+pub struct DiscoveredIdentity {
+    pub identity: SatyaIdentity,
+    pub private_key: Vec<u8>,
+    pub retired: bool,
+}
+
+/// Derivation schemes scanned, newest first.
+#[derive(Clone, Copy)]
+enum Scheme {
+    Slip10,
+    Legacy,
+}
+
+impl Scheme {
+    fn path(&self, index: u32) -> String {
+        match self {
+            Scheme::Slip10 => derivation::identity_path(index),
+            Scheme::Legacy => derivation::legacy_path(index),
+        }
+    }
+}
+
+fn discovery_key(master_seed: &[u8]) -> Result<VaultKey> {
+    VaultKey::from_bytes(master_seed)?.chain("satya_discovery_v1", b"")
+}
+
+/// Relay-visible `d` tag for an identity key. Unlinkable without the seed.
+pub fn locator(master_seed: &[u8], private_key: &[u8]) -> Result<String> {
+    let public_key = public_key_from_secret(private_key)?;
+    let tag = discovery_key(master_seed)?.chain("locator", &public_key)?;
+    Ok(format!("{}{}", LOCATOR_PREFIX, hex::encode(tag.as_bytes())))
+}
+
+/// Builds the (locator, sealed content) pair for an identity profile event.
+pub fn seal_profile(master_seed: &[u8], identity: &SatyaIdentity, private_key: &[u8], state: ProfileState) -> Result<(String, String)> {
+    let profile = IdentityProfile {
+        id: identity.id.clone(),
+        label: identity.label.clone(),
+        did: identity.did.clone(),
+        derivation_path: identity.derivation_path.clone(),
+        status: identity.status,
+        retired: state == ProfileState::Retired,
+        superseded: state == ProfileState::Superseded,
+    };
+    let (nonce, ciphertext) = seal(&discovery_key(master_seed)?, &serde_json::to_vec(&profile)?)?;
+    Ok((locator(master_seed, private_key)?, hex::encode([nonce, ciphertext].concat())))
+}
+
+fn open_profile(master_seed: &[u8], content: &str) -> Result<IdentityProfile> {
+    let raw = hex::decode(content.trim())?;
+    if raw.len() < 12 { return Err(anyhow!("Profile too short")); }
+    let plain = open(&discovery_key(master_seed)?, &raw[..12], &raw[12..])?;
+    Ok(serde_json::from_slice(&plain)?)
+}
+
+/// Walks derivation indices of both schemes until `gap_limit` consecutive
+/// indices have no profile. `fetch` receives a batch of locators and returns
+/// `(locator, content)` pairs found on the relays.
+pub fn scan(master_seed: &[u8], gap_limit: u32, mut fetch: impl FnMut(&[String]) -> Result<Vec<(String, String)>>) -> Result<Vec<DiscoveredIdentity>> {
+    let gap_limit = gap_limit.max(1);
+    let mut found = Vec::new();
+    for scheme in [Scheme::Slip10, Scheme::Legacy] {
+        let mut index = 0u32;
+        let mut gap = 0u32;
+        while gap < gap_limit {
+            let batch = (index..index + gap_limit)
+                .map(|i| {
+                    let path = scheme.path(i);
+                    let key = derivation::derive_identity_key(master_seed, &path)?;
+                    Ok((path, locator(master_seed, &key)?, key))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let locators: Vec<String> = batch.iter().map(|(_, l, _)| l.clone()).collect();
+            let hits = fetch(&locators)?;
+
+            for (path, loc, key) in batch {
+                if gap >= gap_limit { break; }
+                // Relays may return several versions; a retirement always wins.
+                let mut versions = hits.iter()
+                    .filter(|(l, _)| *l == loc)
+                    .filter_map(|(_, content)| open_profile(master_seed, content).ok())
+                    .filter(|p| p.derivation_path == path)
+                    .collect::<Vec<_>>();
+                let retired = versions.iter().position(|p| p.retired).unwrap_or(0);
+                let profile = (!versions.is_empty()).then(|| versions.swap_remove(retired));
+                match profile {
+                    Some(p) if p.superseded => gap = 0,
+                    Some(p) => {
+                        gap = 0;
+                        found.push(DiscoveredIdentity {
+                            identity: SatyaIdentity { id: p.id, label: p.label, did: p.did, derivation_path: path, status: p.status },
+                            private_key: key,
+                            retired: p.retired,
+                        });
+                    },
+                    None => gap += 1,
+                }
+            }
+            index += gap_limit;
+        }
+    }
+    Ok(found)
+}
+
+/// Index to continue from after discovery: one past the highest hit of either
+/// scheme. Both schemes share the Nostr branch of an index, so a legacy index
+/// must not be handed out again.
+pub fn next_index_after(found: &[DiscoveredIdentity]) -> u32 {
+    found.iter()
+        .filter_map(|d| {
+            let path = &d.identity.derivation_path;
+            path.strip_prefix(derivation::IDENTITY_PATH_PREFIX).or_else(|| path.strip_prefix(derivation::LEGACY_PATH_PREFIX))
+        })
+        .filter_map(|rest| rest.trim_start_matches('/').trim_end_matches('\'').parse::<u32>().ok())
+        .map(|i| i + 1)
+        .max()
+        .unwrap_or(0)
+}
@@ -3,16 +3,155 @@
  * VERSION: 2.0.1
  * PURPOSE: Extended domain for Ledger Verification.
  */
-
 use serde::{Deserialize, Serialize};
 
 pub const PROTOCOL_VERSION: &str = "1.0.0";
+/// Nostr kind carrying signed identity retirement statements.
+pub const KIND_IDENTITY_RETIREMENT: u64 = 7330;
+/// Nostr kind carrying signed key rotation statements, `d`-tagged with the DID.
+pub const KIND_KEY_ROTATION: u64 = 7331;
+/// Replaceable Nostr kind carrying a DID's signed revocation list, `d`-tagged with the DID.
+pub const KIND_REVOCATION_LIST: u64 = 37330;
+/// Replaceable Nostr kind carrying a DID Document, `d`-tagged with the DID.
+pub const KIND_DID_DOCUMENT: u64 = 37331;
+/// Nostr kind carrying a salted hash commitment to an intent payload.
+pub const KIND_INTENT_COMMITMENT: u64 = 7332;
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum IdentityStatus {
+    #[default]
+    Active,
+    /// Hidden from everyday use; keys are kept and it can be restored.
+    Archived,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct SatyaIdentity {
     pub id: String,
     pub label: String,
     pub did: String,
+    /// Persisted derivation path, e.g. `m/44'/7327'/0'` (or `legacy/0`).
+    pub derivation_path: String,
+    pub status: IdentityStatus,
+}
+
+/// What remains of a deleted identity: enough to never hand out its
+/// derivation path or DID again. The key itself is gone.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct IdentityTombstone {
+    pub id: String,
+    pub did: String,
+    pub derivation_path: String,
+    pub deleted_at: u64,
+}
+
+/// One relay of the user's relay set.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RelayConfig {
+    pub url: String,
+    pub read: bool,
+    pub write: bool,
+}
+
+/// An identity the user approved to answer a relay's NIP-42 AUTH challenges.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RelayAuthGrant {
+    pub url: String,
+    pub identity_id: String,
+}
+
+/// A key the user vouched for as belonging to a foreign did:satya, e.g. read
+/// from its DID Document in person. Rotation chains are trusted from it.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PinnedKey {
+    pub did: String,
+    pub public_key_hex: String,
+}
+
+/// A per-counterparty did:peer identity derived below a parent identity.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PairwiseLink {
+    pub identity_id: String,
+    /// Normalized VPA or DID of the counterparty.
+    pub counterparty: String,
+    pub did: String,
+    pub derivation_path: String,
+    pub created_at: u64,
+}
+
+/// Opt-in proof that a pairwise DID and its parent DID share a controller.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct LinkageProof {
+    pub version: String,
+    pub parent_did: String,
+    pub pairwise_did: String,
+    pub counterparty: String,
+    pub issued_at: u64,
+    pub parent_public_key_hex: String,
+    pub parent_signature_hex: String,
+    pub pairwise_signature_hex: String,
+}
+
+/// Public notice that a DID will never sign again, signed with its last key.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct IdentityRetirement {
+    pub version: String,
+    pub did: String,
+    pub public_key_hex: String,
+    pub retired_at: u64,
+    pub signature_hex: String,
+}
+
+/// One key an identity has signed with, and when.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct KeyRecord {
+    pub public_key_hex: String,
+    /// Own keys only; empty for histories rebuilt from public statements.
+    pub derivation_path: String,
+    pub valid_from: u64,
+    /// Set once the key has been rotated away.
+    pub valid_until: Option<u64>,
+}
+
+/// Hands a DID over from one key to the next. Both keys sign the statement
+/// (with empty signature fields), proving control of each.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct KeyRotation {
+    pub version: String,
+    pub did: String,
+    pub old_public_key_hex: String,
+    pub new_public_key_hex: String,
+    pub rotated_at: u64,
+    pub old_signature_hex: String,
+    pub new_signature_hex: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RevokedKey {
+    pub public_key_hex: String,
+    /// Signatures made at or after this time are rejected.
+    pub revoked_at: u64,
+    pub reason: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ListSignature {
+    pub public_key_hex: String,
+    pub signature_hex: String,
+}
+
+/// Revocations issued by a DID. Signed (with empty `signatures`) by an
+/// identity key and/or the DID's recovery key, announced in the list.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RevocationList {
+    pub version: String,
+    pub did: String,
+    pub issued_at: u64,
+    pub revoked_keys: Vec<RevokedKey>,
+    /// The whole DID is revoked from this time on.
+    pub identity_revoked_at: Option<u64>,
+    pub recovery_public_key_hex: String,
+    pub signatures: Vec<ListSignature>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -23,7 +162,7 @@ pub struct UpiIntent {
     pub currency: String,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub enum InteractionType {
     PaymentIntent,
     IdentityVerification,
@@ -42,6 +181,31 @@ pub struct SignedIntent {
     pub payload: IntentPayload,
     pub signature_hex: String, 
     pub signer_did: String,
+    /// Key that produced `signature_hex`; checked against the DID's key history.
+    #[serde(default)]
+    pub signer_public_key_hex: String,
     #[serde(default)]
     pub is_verified: bool, 
-}
\ No newline at end of file
+}
+
+/// Published in place of an intent: proves the payload existed at the event's
+/// time without disclosing it.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct IntentCommitment {
+    pub version: String,
+    pub commitment_hex: String,
+}
+
+/// Reveals a committed intent to a verifier: the salt and the signed intent
+/// behind the commitment published as `event_id`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct IntentOpening {
+    pub version: String,
+    pub event_id: String,
+    pub salt_hex: String,
+    pub intent: SignedIntent,
+    /// The intent's signing key binding the commitment event's author
+    /// (`did::bind_nostr_key`), so the commitment is known to be the signer's.
+    #[serde(default)]
+    pub author_binding_hex: String,
+}
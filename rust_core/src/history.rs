@@ -0,0 +1,102 @@
+/**
+ * FILE: rust_core/src/history.rs
+ * VERSION: 1.0.0
+ * PHASE: Phase 11.14 (History Queries)
+ * PURPOSE: Filters and cursors for paging through interaction history.
+ * Pages run newest first; a cursor names the last event already returned.
+ */
+use crate::domain::{InteractionType, SignedIntent};
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_PAGE_SIZE: u32 = 20;
+pub const MAX_PAGE_SIZE: u32 = 200;
+pub const DEFAULT_TIMEOUT_SECS: u64 = 10;
+
+/// What to fetch. Every filter is optional; the default is the latest page
+/// of everyone's intents.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct HistoryQuery {
+    /// Only intents signed by these own identities or their pairwise DIDs.
+    pub identity_ids: Vec<String>,
+    /// Counterparty DID (matched against the signer) or VPA (the payee).
+    pub counterparty: Option<String>,
+    /// Bounds on the intent timestamp, inclusive.
+    pub since: Option<u64>,
+    pub until: Option<u64>,
+    pub interaction_type: Option<InteractionType>,
+    pub verified: Option<bool>,
+    pub page_size: Option<u32>,
+    /// `next_cursor` of the previous page.
+    pub cursor: Option<String>,
+    pub timeout_secs: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct HistoryItem {
+    pub event_id: String,
+    pub created_at: u64,
+    /// Relays the event was received from.
+    pub seen_on: Vec<String>,
+    pub intent: SignedIntent,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct HistoryPage {
+    pub items: Vec<HistoryItem>,
+    /// Pass back in `HistoryQuery::cursor` for the next page; `None` at the end.
+    pub next_cursor: Option<String>,
+}
+
+/// Position in the newest-first order of (created_at, event id).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cursor {
+    pub created_at: u64,
+    pub event_id: String,
+}
+
+impl Cursor {
+    pub fn parse(cursor: &str) -> Result<Self> {
+        let (created_at, event_id) = cursor.split_once(':').ok_or_else(|| anyhow!("Invalid cursor"))?;
+        Ok(Cursor { created_at: created_at.parse().map_err(|_| anyhow!("Invalid cursor"))?, event_id: event_id.to_string() })
+    }
+
+    pub fn encode(&self) -> String {
+        format!("{}:{}", self.created_at, self.event_id)
+    }
+
+    /// A cursor past every event of `created_at`. Its event id is empty,
+    /// which sorts before any real id.
+    pub fn below(created_at: u64) -> Self {
+        Cursor { created_at, event_id: String::new() }
+    }
+
+    /// Whether an event comes after this cursor, i.e. belongs to a later page.
+    pub fn precedes(&self, created_at: u64, event_id: &str) -> bool {
+        (created_at, event_id) < (self.created_at, self.event_id.as_str())
+    }
+
+    /// Newest timestamp that can still hold events after this cursor.
+    pub fn until(&self) -> u64 {
+        if self.event_id.is_empty() { self.created_at.saturating_sub(1) } else { self.created_at }
+    }
+}
+
+/// Applies the query's filters to a (verified) intent. `own_dids` holds the
+/// DIDs of `identity_ids`; it is empty when the query has none.
+pub fn matches(query: &HistoryQuery, intent: &SignedIntent, own_dids: &[String]) -> bool {
+    if !query.identity_ids.is_empty() && !own_dids.contains(&intent.signer_did) { return false; }
+    if let Some(counterparty) = query.counterparty.as_deref().map(str::trim) {
+        let hit = if counterparty.starts_with("did:") {
+            intent.signer_did == counterparty
+        } else {
+            intent.payload.upi_data.vpa.eq_ignore_ascii_case(counterparty)
+        };
+        if !hit { return false; }
+    }
+    let timestamp = intent.payload.timestamp;
+    query.since.is_none_or(|since| timestamp >= since)
+        && query.until.is_none_or(|until| timestamp <= until)
+        && query.interaction_type.as_ref().is_none_or(|kind| *kind == intent.payload.interaction_type)
+        && query.verified.is_none_or(|verified| verified == intent.is_verified)
+}
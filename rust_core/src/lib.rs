@@ -1,11 +1,32 @@
 // Adding Persistence and Security
 pub mod api;
+pub mod backup;
+pub mod binding;
+pub mod commitment;
 pub mod crypto;
+pub mod derivation;
+pub mod did;
+pub mod discovery;
 pub mod domain;
+pub mod history;
+pub mod lockout;
+pub mod pairwise;
+pub mod outbox;
+pub mod paper;
 pub mod parser;
 pub mod persistence;
+pub mod private;
+pub mod publishing;
+pub mod recovery;
+pub mod relay_server;
+pub mod relays;
+pub mod revocation;
+pub mod rotation;
+pub mod shamir;
+pub mod slip39;
+pub mod subscriptions;
 pub mod telemetry;
 pub mod service;
 
 // The bridge_generated file is managed by flutter_rust_bridge_codegen
-mod bridge_generated;
\ No newline at end of file
+mod bridge_generated;
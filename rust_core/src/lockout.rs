@@ -0,0 +1,236 @@
+/**
+ * FILE: rust_core/src/lockout.rs
+ * VERSION: 1.0.0
+ * PHASE: Phase 10.4 (Brute-Force Guard)
+ * PURPOSE: Failed-attempt counter with exponential backoff and optional
+ * crypto-erase. The counter and wipe limit live in the vault envelope,
+ * MAC'd with a key from the vault's hardware binding.
+ */
+use crate::crypto::constant_time_eq;
+use crate::persistence::VaultManager;
+use anyhow::{Result, anyhow};
+use hmac::Mac;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Failures allowed before any delay kicks in.
+const FREE_ATTEMPTS: u32 = 3;
+const BASE_DELAY_SECS: u64 = 5;
+const MAX_DELAY_SECS: u64 = 24 * 60 * 60;
+/// Failure count assumed when the guard record fails authentication.
+const TAMPER_PENALTY: u32 = FREE_ATTEMPTS + 4;
+const MAC_LEN: usize = 32;
+
+/// Typed unlock failures. Surfaced to Flutter through the error message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnlockError {
+    /// Wrong PIN / factor combination or binding.
+    Mismatch,
+    /// Too many failures; retry once `retry_after_secs` have elapsed.
+    LockedOut { retry_after_secs: u64 },
+    /// The failure limit was reached and the vault was crypto-erased.
+    Wiped,
+}
+
+impl fmt::Display for UnlockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnlockError::Mismatch => write!(f, "Hardware/PIN Mismatch"),
+            UnlockError::LockedOut { retry_after_secs } => write!(f, "LOCKED_OUT: retry after {}s", retry_after_secs),
+            UnlockError::Wiped => write!(f, "VAULT_WIPED: failed attempt limit reached"),
+        }
+    }
+}
+
+impl std::error::Error for UnlockError {}
+
+/// Snapshot of the brute-force guard for the lock screen.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct UnlockStatus {
+    pub failed_attempts: u32,
+    pub retry_after_secs: u64,
+    /// Failures left before a crypto-erase, if one is configured.
+    pub attempts_before_wipe: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone)]
+struct AttemptState {
+    failures: u32,
+    last_failure: u64,
+    wipe_after: Option<u32>,
+    /// The record failed authentication since the last successful unlock.
+    suspect: bool,
+}
+
+impl AttemptState {
+    fn delay_secs(&self) -> u64 {
+        let failures = if self.suspect { self.failures.max(TAMPER_PENALTY) } else { self.failures };
+        if failures < FREE_ATTEMPTS { return 0; }
+        let exponent = (failures - FREE_ATTEMPTS).min(20);
+        (BASE_DELAY_SECS << exponent).min(MAX_DELAY_SECS)
+    }
+
+    fn retry_after(&self, now: u64) -> u64 {
+        // A clock set backwards never shortens the wait.
+        let elapsed = now.saturating_sub(self.last_failure);
+        self.delay_secs().saturating_sub(elapsed)
+    }
+
+    /// What an unauthenticated state is taken to mean: a backoff as if
+    /// several guesses had failed. Only real failures count toward the wipe
+    /// limit, and the limit stays whatever the user configured.
+    fn tampered(&self) -> Self {
+        AttemptState { last_failure: now(), suspect: true, ..self.clone() }
+    }
+}
+
+/// The guard state as kept in the vault envelope. Deleting or swapping a
+/// side file cannot reset it, and its MAC key is never stored next to it.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct GuardRecord {
+    state: AttemptState,
+    tag: Vec<u8>,
+}
+
+impl GuardRecord {
+    /// A clean record (no failures, no wipe limit) for a new vault.
+    pub fn new(binding_secret: &[u8; 32]) -> Result<Self> {
+        Self::seal(AttemptState::default(), binding_secret)
+    }
+
+    /// The same state under the key of a new hardware binding.
+    pub fn retag(&self, binding_secret: &[u8; 32]) -> Result<Self> {
+        Self::seal(self.state.clone(), binding_secret)
+    }
+
+    fn seal(state: AttemptState, key: &[u8; 32]) -> Result<Self> {
+        let tag = mac(key, b"satya_guard_v1", &bincode::serialize(&state)?).to_vec();
+        Ok(Self { state, tag })
+    }
+
+    fn verify(&self, key: &[u8; 32]) -> bool {
+        bincode::serialize(&self.state).is_ok_and(|body| constant_time_eq(&mac(key, b"satya_guard_v1", &body), &self.tag))
+    }
+}
+
+/// State as read, and whether it must be written back (it did not verify).
+struct Loaded {
+    state: AttemptState,
+    rewrite: bool,
+}
+
+pub struct AttemptGuard<'a> {
+    vault: &'a VaultManager,
+    hw_id: String,
+}
+
+impl<'a> AttemptGuard<'a> {
+    pub fn new(vault: &'a VaultManager, hw_id: &str) -> Self {
+        Self { vault, hw_id: hw_id.to_string() }
+    }
+
+    pub fn status(&self) -> UnlockStatus {
+        let state = self.load(false).state;
+        UnlockStatus {
+            failed_attempts: state.failures,
+            retry_after_secs: state.retry_after(now()),
+            attempts_before_wipe: state.wipe_after.map(|n| n.saturating_sub(state.failures)),
+        }
+    }
+
+    /// The wipe limit currently in force.
+    pub fn wipe_after(&self) -> Option<u32> {
+        self.load(false).state.wipe_after
+    }
+
+    /// Runs an unlock attempt under the guard. Mismatches are counted, a
+    /// success resets the counter, and `erase` runs once the wipe limit is hit.
+    pub fn attempt<T>(&self, unlock: impl FnOnce() -> Result<T>, erase: impl FnOnce() -> Result<()>) -> Result<T> {
+        self.run(self.load(false), unlock, erase)
+    }
+
+    /// Like `attempt`, for a PIN plus recovery code re-binding. The record
+    /// was keyed by the binding being replaced, so a failed authentication
+    /// is not held against it; real failures still count. Success re-seals
+    /// the record, counter cleared, under the new binding.
+    pub fn attempt_recovery<T>(&self, unlock: impl FnOnce() -> Result<T>, erase: impl FnOnce() -> Result<()>) -> Result<T> {
+        self.run(self.load(true), unlock, erase)
+    }
+
+    fn run<T>(&self, loaded: Loaded, unlock: impl FnOnce() -> Result<T>, erase: impl FnOnce() -> Result<()>) -> Result<T> {
+        let Loaded { mut state, rewrite } = loaded;
+        let retry_after_secs = state.retry_after(now());
+        if retry_after_secs > 0 {
+            if rewrite { self.store(&state)?; }
+            return Err(UnlockError::LockedOut { retry_after_secs }.into());
+        }
+        match unlock() {
+            Ok(value) => {
+                if state.failures > 0 || state.suspect || rewrite {
+                    state.failures = 0;
+                    state.suspect = false;
+                    self.store(&state)?;
+                }
+                Ok(value)
+            },
+            Err(e) if matches!(e.downcast_ref::<UnlockError>(), Some(UnlockError::Mismatch)) => {
+                state.failures += 1;
+                state.last_failure = now();
+                if state.wipe_after.is_some_and(|limit| state.failures >= limit) {
+                    erase()?;
+                    return Err(UnlockError::Wiped.into());
+                }
+                self.store(&state)?;
+                Err(e)
+            },
+            Err(e) => {
+                if rewrite { self.store(&state)?; }
+                Err(e)
+            },
+        }
+    }
+
+    /// Sets (or clears) the number of failures that triggers a crypto-erase.
+    pub fn set_wipe_after(&self, limit: Option<u32>) -> Result<()> {
+        if limit == Some(0) { return Err(anyhow!("Wipe limit must be at least 1")); }
+        let mut state = self.load(false).state;
+        state.wipe_after = limit;
+        self.store(&state)
+    }
+
+    /// A record that does not verify under this device's binding counts as
+    /// tampering, unless this is a `recovery` attempt. Vaults not yet
+    /// migrated to the envelope format have no record and start clean.
+    fn load(&self, recovery: bool) -> Loaded {
+        let Ok(record) = self.vault.guard_record() else {
+            return Loaded { state: AttemptState::default(), rewrite: false };
+        };
+        let verified = self.vault.guard_secret(&self.hw_id).is_ok_and(|key| record.verify(&key));
+        match (verified, recovery) {
+            (_, true) => Loaded { rewrite: !verified || record.state.suspect, state: AttemptState { suspect: false, ..record.state } },
+            (true, false) => Loaded { state: record.state, rewrite: false },
+            (false, false) => Loaded { state: record.state.tampered(), rewrite: true },
+        }
+    }
+
+    /// No-op until the vault has an envelope to hold the record.
+    fn store(&self, state: &AttemptState) -> Result<()> {
+        if self.vault.guard_record().is_err() { return Ok(()); }
+        let key = self.vault.guard_secret(&self.hw_id)?;
+        self.vault.set_guard_record(GuardRecord::seal(state.clone(), &key)?)
+    }
+}
+
+fn mac(key: &[u8; 32], domain: &[u8], body: &[u8]) -> [u8; MAC_LEN] {
+    let mut mac = <hmac::Hmac<Sha256> as Mac>::new_from_slice(key)
+        .expect("HMAC accepts any key length");
+    mac.update(domain);
+    mac.update(body);
+    mac.finalize().into_bytes().into()
+}
+
+fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default()
+}
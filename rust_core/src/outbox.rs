@@ -0,0 +1,120 @@
+/**
+ * FILE: rust_core/src/outbox.rs
+ * VERSION: 1.0.0
+ * PHASE: Phase 11.13 (Offline Outbox)
+ * PURPOSE: Durable queue of signed events that no relay has accepted yet.
+ * Sealed with a key chained from the vault data key; retried with
+ * exponential backoff and deduplicated by event id.
+ */
+use crate::crypto::{VaultKey, seal, open};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+
+const BASE_RETRY_SECS: u64 = 10;
+const MAX_RETRY_SECS: u64 = 3600;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct OutboxItem {
+    pub event_id: String,
+    pub kind: u64,
+    /// The signed Nostr event, republished unchanged.
+    pub event_json: String,
+    pub queued_at: u64,
+    pub attempts: u32,
+    pub next_attempt_at: u64,
+    pub last_error: Option<String>,
+}
+
+pub struct Outbox {
+    path: PathBuf,
+    key: Option<VaultKey>,
+    items: Vec<OutboxItem>,
+}
+
+impl Outbox {
+    /// Opens the outbox of the vault at `base_path`. An unreadable file is
+    /// treated as empty and replaced on the next write.
+    pub fn new(base_path: &Path, data_key: &VaultKey) -> Result<Self> {
+        let mut path = base_path.to_path_buf();
+        path.push("satya_vault/outbox.bin");
+        let key = data_key.chain("satya_outbox_v1", b"")?;
+        let items = fs::read(&path).ok()
+            .and_then(|raw| {
+                let (nonce, ciphertext) = raw.split_at_checked(12)?;
+                open(&key, nonce, ciphertext).ok()
+            })
+            .and_then(|plain| serde_json::from_slice(&plain).ok())
+            .unwrap_or_default();
+        Ok(Self { path, key: Some(key), items })
+    }
+
+    /// In-memory outbox (nothing is persisted), e.g. for a decoy session.
+    pub fn ephemeral() -> Self {
+        Self { path: PathBuf::new(), key: None, items: Vec::new() }
+    }
+
+    pub fn items(&self) -> &[OutboxItem] {
+        &self.items
+    }
+
+    /// Queues an event. Returns false if it is already queued.
+    pub fn enqueue(&mut self, event_id: &str, kind: u64, event_json: String, error: &str, now: u64) -> Result<bool> {
+        if self.items.iter().any(|i| i.event_id == event_id) { return Ok(false); }
+        self.items.push(OutboxItem {
+            event_id: event_id.to_string(),
+            kind,
+            event_json,
+            queued_at: now,
+            attempts: 1,
+            next_attempt_at: now + BASE_RETRY_SECS,
+            last_error: Some(error.to_string()),
+        });
+        self.store()?;
+        Ok(true)
+    }
+
+    /// Items whose retry time has come.
+    pub fn due(&self, now: u64) -> Vec<OutboxItem> {
+        self.items.iter().filter(|i| i.next_attempt_at <= now).cloned().collect()
+    }
+
+    pub fn record_failure(&mut self, event_id: &str, error: &str, now: u64) -> Result<()> {
+        let Some(item) = self.items.iter_mut().find(|i| i.event_id == event_id) else { return Ok(()) };
+        item.attempts += 1;
+        let delay = BASE_RETRY_SECS.saturating_mul(1 << item.attempts.min(16)).min(MAX_RETRY_SECS);
+        item.next_attempt_at = now + delay;
+        item.last_error = Some(error.to_string());
+        self.store()
+    }
+
+    /// Removes an item (published or dropped). Returns whether it was queued.
+    pub fn remove(&mut self, event_id: &str) -> Result<bool> {
+        let before = self.items.len();
+        self.items.retain(|i| i.event_id != event_id);
+        if self.items.len() == before { return Ok(false); }
+        self.store()?;
+        Ok(true)
+    }
+
+    /// Makes every item due immediately.
+    pub fn expedite(&mut self) {
+        for item in &mut self.items {
+            item.next_attempt_at = 0;
+        }
+    }
+
+    fn store(&self) -> Result<()> {
+        let Some(key) = &self.key else { return Ok(()) };
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let (nonce, ciphertext) = seal(key, &serde_json::to_vec(&self.items)?)?;
+        let tmp_path = self.path.with_extension("tmp");
+        fs::write(&tmp_path, [nonce, ciphertext].concat())?;
+        File::open(&tmp_path)?.sync_all()?;
+        fs::rename(&tmp_path, &self.path)?;
+        Ok(())
+    }
+}
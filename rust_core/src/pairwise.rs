@@ -0,0 +1,89 @@
+/**
+ * FILE: rust_core/src/pairwise.rs
+ * VERSION: 1.0.0
+ * PHASE: Phase 11.9 (Pairwise DIDs)
+ * PURPOSE: One did:peer identity per counterparty, derived below the parent
+ * identity's path, so merchants and relays cannot correlate interactions.
+ * A linkage proof shows common control only when the user hands one out.
+ */
+use crate::crypto::{VaultKey, sign_with_key, verify_with_key, public_key_from_secret};
+use crate::derivation::{self, IDENTITY_PATH_PREFIX};
+use crate::did;
+use crate::domain::{KeyRecord, LinkageProof, PairwiseLink, PROTOCOL_VERSION};
+use crate::revocation::RevocationState;
+use crate::rotation;
+use anyhow::{Result, anyhow};
+
+/// Hardened branch below an identity path that holds its pairwise keys.
+const PAIRWISE_BRANCH: u32 = 1;
+
+/// VPAs are case-insensitive; DIDs are compared verbatim.
+pub fn normalize_counterparty(counterparty: &str) -> String {
+    let trimmed = counterparty.trim();
+    if trimmed.starts_with("did:") { trimmed.to_string() } else { trimmed.to_lowercase() }
+}
+
+/// Derivation path of the pairwise key for `counterparty`. The index is a
+/// seed-keyed hash, so the path reveals nothing about the counterparty.
+/// Rooted at the identity's first SLIP-0010 path so rotations keep it stable.
+pub fn pairwise_path(master_seed: &[u8], history: &[KeyRecord], counterparty: &str) -> Result<String> {
+    let root = history.iter()
+        .map(|k| k.derivation_path.as_str())
+        .find(|p| p.starts_with(IDENTITY_PATH_PREFIX))
+        .ok_or_else(|| anyhow!("Legacy identities must be rotated before using pairwise DIDs"))?;
+    let digest = VaultKey::from_bytes(master_seed)?.chain("satya_pairwise_v1", normalize_counterparty(counterparty).as_bytes())?;
+    let index = u32::from_be_bytes(digest.as_bytes()[..4].try_into()?) & 0x7fff_ffff;
+    Ok(format!("{}/{}'/{}'", root, PAIRWISE_BRANCH, index))
+}
+
+/// Derives the pairwise link of an identity for one counterparty.
+pub fn derive_link(master_seed: &[u8], identity_id: &str, history: &[KeyRecord], counterparty: &str, created_at: u64) -> Result<(PairwiseLink, Vec<u8>)> {
+    let derivation_path = pairwise_path(master_seed, history, counterparty)?;
+    let key = derivation::derive_identity_key(master_seed, &derivation_path)?;
+    let link = PairwiseLink {
+        identity_id: identity_id.to_string(),
+        counterparty: normalize_counterparty(counterparty),
+        did: did::did_peer(&public_key_from_secret(&key)?),
+        derivation_path,
+        created_at,
+    };
+    Ok((link, key))
+}
+
+/// Statement that `parent_did` and the pairwise DID share a controller,
+/// signed by both the parent's current key and the pairwise key.
+pub fn prove_linkage(link: &PairwiseLink, parent_did: &str, parent_key: &[u8], pairwise_key: &[u8], issued_at: u64) -> Result<LinkageProof> {
+    let mut proof = LinkageProof {
+        version: PROTOCOL_VERSION.to_string(),
+        parent_did: parent_did.to_string(),
+        pairwise_did: link.did.clone(),
+        counterparty: link.counterparty.clone(),
+        issued_at,
+        parent_public_key_hex: hex::encode(public_key_from_secret(parent_key)?),
+        parent_signature_hex: String::new(),
+        pairwise_signature_hex: String::new(),
+    };
+    let message = signed_bytes(&proof)?;
+    proof.parent_signature_hex = hex::encode(sign_with_key(parent_key, &message)?);
+    proof.pairwise_signature_hex = hex::encode(sign_with_key(pairwise_key, &message)?);
+    Ok(proof)
+}
+
+/// Checks both signatures. The parent key must be the one valid at
+/// `issued_at` in the parent's history and not revoked; an empty history
+/// (nothing anchors the parent) never verifies.
+pub fn verify_linkage(proof: &LinkageProof, parent_history: &[KeyRecord], revocations: &RevocationState) -> Result<bool> {
+    if parent_history.is_empty() { return Ok(false); }
+    let key = rotation::key_at(parent_history, proof.issued_at).ok_or_else(|| anyhow!("No key valid at signing time"))?;
+    if key.public_key_hex != proof.parent_public_key_hex { return Ok(false); }
+    if revocations.is_revoked(&proof.parent_public_key_hex, proof.issued_at) { return Ok(false); }
+    let pairwise_key = did::peer_public_key(&proof.pairwise_did)?;
+    let message = signed_bytes(proof)?;
+    Ok(verify_with_key(&hex::decode(&proof.parent_public_key_hex)?, &message, &hex::decode(&proof.parent_signature_hex)?).is_ok()
+        && verify_with_key(&pairwise_key, &message, &hex::decode(&proof.pairwise_signature_hex)?).is_ok())
+}
+
+fn signed_bytes(proof: &LinkageProof) -> Result<Vec<u8>> {
+    let unsigned = LinkageProof { parent_signature_hex: String::new(), pairwise_signature_hex: String::new(), ..proof.clone() };
+    Ok(serde_json::to_vec(&unsigned)?)
+}
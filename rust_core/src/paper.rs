@@ -0,0 +1,71 @@
+/**
+ * FILE: rust_core/src/paper.rs
+ * VERSION: 1.0.0
+ * PHASE: Phase 11.4 (Paper Backup)
+ * PURPOSE: Offline backup of the master seed as SLIP-39 mnemonic shares,
+ * each printable as a QR code whose content is the share's words.
+ */
+use crate::slip39::{self, Share};
+use anyhow::{Result, anyhow};
+use qrcode::QrCode;
+use qrcode::render::svg;
+use serde::{Deserialize, Serialize};
+
+/// PBKDF2 rounds = 10000 * 2^e. Matches common SLIP-39 wallets.
+const ITERATION_EXPONENT: u8 = 1;
+
+/// One printable share: the words and an SVG QR code of the same words.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PaperShare {
+    pub index: u32,
+    pub mnemonic: String,
+    pub qr_svg: String,
+}
+
+/// What a scanned share contributes towards a restore.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ShareScan {
+    pub collected: u32,
+    pub required: u32,
+}
+
+/// Splits the seed into `count` SLIP-39 shares of which `threshold` restore it.
+pub fn split(master_seed: &[u8], threshold: u8, count: u8, passphrase: Option<&str>) -> Result<Vec<PaperShare>> {
+    if threshold == 1 && count > 1 {
+        return Err(anyhow!("A 1-of-n scheme is just n copies; print the mnemonic instead"));
+    }
+    let mnemonics = slip39::generate(master_seed, threshold, count, passphrase.unwrap_or(""), ITERATION_EXPONENT)?;
+    mnemonics.into_iter()
+        .enumerate()
+        .map(|(i, mnemonic)| {
+            let qr_svg = render_qr(&mnemonic)?;
+            Ok(PaperShare { index: i as u32 + 1, mnemonic, qr_svg })
+        })
+        .collect()
+}
+
+/// Restores the seed from typed or scanned share mnemonics.
+/// The same share typed and scanned counts once.
+pub fn combine(shares: &[String], passphrase: Option<&str>) -> Result<Vec<u8>> {
+    let mut unique: Vec<String> = shares.iter().map(|s| normalize(s)).collect();
+    unique.sort();
+    unique.dedup();
+    slip39::combine(&unique, passphrase.unwrap_or(""))
+}
+
+/// Parses a share (checksum verified). Returns (backup identifier, threshold).
+pub fn inspect(share: &str) -> Result<(u16, u8)> {
+    let share = Share::from_mnemonic(share)?;
+    Ok((share.identifier, share.member_threshold))
+}
+
+/// Renders `content` as a standalone SVG document, sized for print.
+pub fn render_qr(content: &str) -> Result<String> {
+    let code = QrCode::new(content.as_bytes()).map_err(|e| anyhow!("QR error: {}", e))?;
+    Ok(code.render::<svg::Color>().min_dimensions(256, 256).build())
+}
+
+/// Canonical spelling of a share: lowercase words separated by single spaces.
+pub fn normalize(share: &str) -> String {
+    share.split_whitespace().map(str::to_lowercase).collect::<Vec<_>>().join(" ")
+}
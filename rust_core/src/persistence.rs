@@ -3,26 +3,379 @@
  * VERSION: 1.7.9
  * PHASE: Phase 7
  * DESCRIPTION: Manages the encrypted on-disk storage of identities and keys.
+ * A random data key encrypts the vault; each key slot wraps a copy of it.
+ * A slot requires all of its factors (all-of); any one slot opens the vault (any-of).
+ * The PIN unwraps a random PIN secret, so changing it never touches the slots.
+ * Every envelope carries a fixed-size shadow region: random noise, or a decoy
+ * vault sealed under a duress PIN. The two cases are indistinguishable on disk.
  */
-
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use crate::domain::SatyaIdentity;
-use crate::crypto::{VaultKey, encrypt_with_binding, decrypt_with_binding};
+use crate::domain::{SatyaIdentity, IdentityTombstone, KeyRecord, PairwiseLink, PinnedKey, RelayConfig, RelayAuthGrant};
+use crate::derivation;
+use crate::binding::{self, BindingKind, HardwareBinding, KeyFileBinding, KeystoreBinding};
+use crate::lockout::{GuardRecord, UnlockError};
+use crate::crypto::{VaultKey, KdfParams, seal, open, decrypt_with_binding, normalize_recovery_code, public_key_from_secret};
 use anyhow::{Result, anyhow, Context};
-use std::fs;
-use std::path::PathBuf;
+use std::fs::{self, File};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+const ENVELOPE_MAGIC: &[u8; 4] = b"SATV";
+pub const ENVELOPE_VERSION: u32 = 1;
+const LEGACY_SALT: &[u8] = b"satya_salt_v1";
+/// Payload layout version stored in `SatyaVault::version`.
+pub const VAULT_VERSION: u32 = 1;
+/// Plaintext capacity of the shadow region (length prefix + decoy vault + padding).
+const SHADOW_CAPACITY: usize = 32 * 1024;
+const AEAD_TAG_LEN: usize = 16;
 
-#[derive(Serialize, Deserialize, Default)]
+#[derive(Serialize, Deserialize)]
 pub struct SatyaVault {
     pub version: u32,
     /// Master entropy for HD derivation
     pub master_seed: Vec<u8>,
     pub identities: Vec<SatyaIdentity>,
     pub private_keys: HashMap<String, Vec<u8>>,
+    /// Next unused derivation index. Only ever grows, so indices are never reused.
+    pub next_index: u32,
+    /// Deleted identities; their paths and DIDs stay reserved.
+    pub tombstones: Vec<IdentityTombstone>,
+    /// Keys of identities that were rotated at least once, oldest first.
+    pub key_history: HashMap<String, Vec<KeyRecord>>,
+    /// Pairwise DIDs handed out to counterparties.
+    pub pairwise: Vec<PairwiseLink>,
+    /// User-configured relays; empty means the defaults.
+    pub relays: Vec<RelayConfig>,
+    /// Which identity may authenticate to which relay (NIP-42).
+    pub relay_auth: Vec<RelayAuthGrant>,
+    /// Anchors for verifying foreign did:satya key histories.
+    pub pinned_keys: Vec<PinnedKey>,
+}
+
+impl Default for SatyaVault {
+    fn default() -> Self {
+        SatyaVault {
+            version: VAULT_VERSION,
+            master_seed: Vec::new(),
+            identities: Vec::new(),
+            private_keys: HashMap::new(),
+            next_index: 0,
+            tombstones: Vec::new(),
+            key_history: HashMap::new(),
+            pairwise: Vec::new(),
+            relays: Vec::new(),
+            relay_auth: Vec::new(),
+            pinned_keys: Vec::new(),
+        }
+    }
+}
+
+impl SatyaVault {
+    /// Decodes a payload, upgrading the pre-envelope layout.
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        let version = bytes.get(..4).and_then(|b| b.try_into().ok()).map(u32::from_le_bytes);
+        if version == Some(VAULT_VERSION) {
+            return bincode::deserialize(bytes).context("Vault corruption");
+        }
+        let legacy: LegacyVault = bincode::deserialize(bytes).context("Vault corruption")?;
+        Ok(legacy.upgrade())
+    }
+
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        bincode::serialize(self).context("Serialization error")
+    }
+
+    /// Whether a derivation path was ever handed out, live or deleted.
+    pub fn is_path_used(&self, derivation_path: &str) -> bool {
+        self.identities.iter().any(|i| i.derivation_path == derivation_path)
+            || self.tombstones.iter().any(|t| t.derivation_path == derivation_path)
+            || self.key_history.values().flatten().any(|k| k.derivation_path == derivation_path)
+    }
+
+    /// Key history of an identity. Never-rotated identities have one key,
+    /// valid since the beginning.
+    pub fn key_history_of(&self, identity_id: &str) -> Result<Vec<KeyRecord>> {
+        if let Some(history) = self.key_history.get(identity_id) {
+            return Ok(history.clone());
+        }
+        let identity = self.identities.iter().find(|i| i.id == identity_id).ok_or_else(|| anyhow!("Identity not found"))?;
+        let key = self.private_keys.get(identity_id).ok_or_else(|| anyhow!("Identity not found"))?;
+        Ok(vec![KeyRecord {
+            public_key_hex: hex::encode(public_key_from_secret(key)?),
+            derivation_path: identity.derivation_path.clone(),
+            valid_from: 0,
+            valid_until: None,
+        }])
+    }
+}
+
+#[derive(Deserialize)]
+struct LegacyIdentity {
+    id: String,
+    label: String,
+    did: String,
+}
+
+/// Payload layout of the pre-envelope vault (version 0).
+#[derive(Deserialize)]
+struct LegacyVault {
+    _version: u32,
+    master_seed: Vec<u8>,
+    identities: Vec<LegacyIdentity>,
+    private_keys: HashMap<String, Vec<u8>>,
+}
+
+impl LegacyVault {
+    /// Identities were derived from their position, so position becomes the legacy path.
+    fn upgrade(self) -> SatyaVault {
+        let next_index = self.identities.len() as u32;
+        let identities = self.identities.into_iter().enumerate().map(|(index, old)| SatyaIdentity {
+            id: old.id,
+            label: old.label,
+            did: old.did,
+            derivation_path: derivation::legacy_path(index as u32),
+            ..Default::default()
+        }).collect();
+        SatyaVault {
+            master_seed: self.master_seed,
+            identities,
+            private_keys: self.private_keys,
+            next_index,
+            ..Default::default()
+        }
+    }
+}
+
+/// One ingredient of a slot key. All factors of a slot are required together.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum SlotFactor {
+    Pin,
+    RecoveryCode { salt: Vec<u8> },
+    /// User-held key file; the path is supplied at unlock, `hint` is for display.
+    KeyFile { hint: String },
+    Hardware { binding: BindingKind, reference: String },
+}
+
+impl SlotFactor {
+    pub fn kind(&self) -> FactorKind {
+        match self {
+            SlotFactor::Pin => FactorKind::Pin,
+            SlotFactor::RecoveryCode { .. } => FactorKind::RecoveryCode,
+            SlotFactor::KeyFile { .. } => FactorKind::KeyFile,
+            SlotFactor::Hardware { .. } => FactorKind::Hardware,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FactorKind {
+    Pin,
+    RecoveryCode,
+    KeyFile,
+    Hardware,
+}
+
+impl FromStr for FactorKind {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "pin" => Ok(FactorKind::Pin),
+            "recovery_code" => Ok(FactorKind::RecoveryCode),
+            "key_file" => Ok(FactorKind::KeyFile),
+            "hardware" => Ok(FactorKind::Hardware),
+            other => Err(anyhow!("Unknown unlock factor: {}", other)),
+        }
+    }
+}
+
+impl fmt::Display for FactorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            FactorKind::Pin => "pin",
+            FactorKind::RecoveryCode => "recovery_code",
+            FactorKind::KeyFile => "key_file",
+            FactorKind::Hardware => "hardware",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// How a set of newly enrolled factors combines into key slots.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnlockPolicy {
+    /// One slot per factor: any single factor opens the vault.
+    AnyOf,
+    /// A single slot that needs every factor at once.
+    AllOf,
+}
+
+/// A wrapped copy of the vault data key.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct KeySlot {
+    pub factors: Vec<SlotFactor>,
+    pub nonce: Vec<u8>,
+    pub wrapped_key: Vec<u8>,
+}
+
+impl KeySlot {
+    fn requires(&self, kind: FactorKind) -> bool {
+        self.factors.iter().any(|f| f.kind() == kind)
+    }
+
+    fn is_device_slot(&self) -> bool {
+        self.requires(FactorKind::Hardware)
+    }
+
+    fn is_recovery_slot(&self) -> bool {
+        self.requires(FactorKind::RecoveryCode)
+    }
+
+    /// Whether the presented secrets could possibly open this slot.
+    fn satisfiable(&self, secrets: &UnlockSecrets) -> bool {
+        self.factors.iter().all(|f| match f {
+            SlotFactor::Pin => secrets.pin.is_some(),
+            SlotFactor::RecoveryCode { .. } => secrets.recovery_code.is_some(),
+            SlotFactor::KeyFile { .. } => secrets.key_file.is_some(),
+            SlotFactor::Hardware { .. } => true,
+        })
+    }
+
+    /// Human readable factor combination, e.g. `pin+hardware`.
+    pub fn describe(&self) -> String {
+        self.factors.iter().map(|f| f.kind().to_string()).collect::<Vec<_>>().join("+")
+    }
+}
+
+/// The PIN secret wrapped under Argon2id(PIN). One record per vault.
+#[derive(Serialize, Deserialize, Clone)]
+struct PinRecord {
+    salt: Vec<u8>,
+    params: KdfParams,
+    nonce: Vec<u8>,
+    wrapped_secret: Vec<u8>,
+}
+
+impl PinRecord {
+    fn seal(pin: &str, secret: &VaultKey) -> Result<Self> {
+        let salt = random_salt();
+        let params = KdfParams::recommended();
+        let stretched = VaultKey::from_pin_with(pin, &salt, &params)?;
+        let (nonce, wrapped_secret) = seal(&stretched, secret.as_bytes())?;
+        Ok(PinRecord { salt, params, nonce, wrapped_secret })
+    }
+
+    fn open(&self, pin: &str) -> Result<VaultKey> {
+        let stretched = VaultKey::from_pin_with(pin, &self.salt, &self.params)?;
+        let raw = open(&stretched, &self.nonce, &self.wrapped_secret).map_err(|_| UnlockError::Mismatch)?;
+        VaultKey::from_bytes(&raw)
+    }
+}
+
+/// Fixed-size region that either holds noise or a decoy vault.
+#[derive(Serialize, Deserialize, Clone)]
+struct ShadowRegion {
+    salt: Vec<u8>,
+    params: KdfParams,
+    nonce: Vec<u8>,
+    blob: Vec<u8>,
+}
+
+impl ShadowRegion {
+    fn noise() -> Self {
+        ShadowRegion {
+            salt: random_salt(),
+            params: KdfParams::recommended(),
+            nonce: rand::random::<[u8; 12]>().to_vec(),
+            blob: (0..SHADOW_CAPACITY + AEAD_TAG_LEN).map(|_| rand::random::<u8>()).collect(),
+        }
+    }
+
+    fn key(&self, pin: &str) -> Result<VaultKey> {
+        VaultKey::from_pin_with(pin, &self.salt, &self.params)
+    }
+
+    /// Seals `vault` under a fresh salt derived key for `pin`.
+    fn seal(pin: &str, vault: &SatyaVault) -> Result<(Self, VaultKey)> {
+        let mut region = Self::noise();
+        let key = region.key(pin)?;
+        region.reseal(&key, vault)?;
+        Ok((region, key))
+    }
+
+    fn reseal(&mut self, key: &VaultKey, vault: &SatyaVault) -> Result<()> {
+        let encoded = vault.encode()?;
+        if encoded.len() + 4 > SHADOW_CAPACITY { return Err(anyhow!("Vault capacity exceeded")); }
+        let mut plain = (encoded.len() as u32).to_le_bytes().to_vec();
+        plain.extend(encoded);
+        plain.extend((plain.len()..SHADOW_CAPACITY).map(|_| rand::random::<u8>()));
+        let (nonce, blob) = seal(key, &plain)?;
+        self.nonce = nonce;
+        self.blob = blob;
+        Ok(())
+    }
+
+    fn open(&self, key: &VaultKey) -> Result<SatyaVault> {
+        let plain = open(key, &self.nonce, &self.blob).map_err(|_| UnlockError::Mismatch)?;
+        let len = u32::from_le_bytes(plain[..4].try_into()?) as usize;
+        let body = plain.get(4..4 + len).ok_or_else(|| anyhow!("Vault corruption"))?;
+        SatyaVault::decode(body)
+    }
+}
+
+/// Which of the two vaults in an envelope a session is working on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Compartment {
+    Primary,
+    Decoy,
+}
+
+#[derive(Serialize, Deserialize)]
+struct VaultEnvelope {
+    version: u32,
+    pin: PinRecord,
+    slots: Vec<KeySlot>,
+    shadow: ShadowRegion,
+    /// Brute-force guard state.
+    guard: GuardRecord,
+    nonce: Vec<u8>,
+    ciphertext: Vec<u8>,
+}
+
+impl VaultEnvelope {
+    /// Key of the guard record: the secret of the vault's device binding, or
+    /// a keystore entry when no slot is bound to the device.
+    fn guard_secret(&self, hw_id: &str, base_path: &Path) -> Result<[u8; 32]> {
+        let device = self.slots.iter().flat_map(|s| &s.factors).find_map(|f| match f {
+            SlotFactor::Hardware { binding: kind, reference } if *kind != BindingKind::None => Some((*kind, reference)),
+            _ => None,
+        });
+        match device {
+            Some((kind, reference)) => binding::resolve(kind, reference, hw_id, base_path).secret(),
+            None => KeystoreBinding::new(base_path, "attempts").secret(),
+        }
+    }
+}
+
+/// Secrets presented by the caller when opening a key slot.
+#[derive(Default)]
+pub struct UnlockSecrets<'a> {
+    pub pin: Option<&'a str>,
+    pub recovery_code: Option<&'a str>,
+    pub key_file: Option<&'a str>,
+    pub hw_id: &'a str,
+}
+
+impl<'a> UnlockSecrets<'a> {
+    pub fn pin(pin: &'a str, hw_id: &'a str) -> Self {
+        Self { pin: Some(pin), hw_id, ..Default::default() }
+    }
 }
 
 pub struct VaultManager {
+    base_path: PathBuf,
     storage_path: PathBuf,
 }
 
@@ -33,24 +386,410 @@ impl VaultManager {
         if let Some(parent) = path.parent() {
             let _ = fs::create_dir_all(parent);
         }
-        Self { storage_path: path }
+        Self { base_path: PathBuf::from(base_path), storage_path: path }
     }
 
-    pub fn atomic_save(&self, key: &VaultKey, hw_id: &[u8], vault: &SatyaVault) -> Result<()> {
-        let encoded = bincode::serialize(vault).context("Serialization error")?;
-        let encrypted = encrypt_with_binding(key, hw_id, &encoded)?;
-        let tmp_path = self.storage_path.with_extension("tmp");
-        fs::write(&tmp_path, encrypted)?;
-        fs::rename(&tmp_path, &self.storage_path)?; 
+    pub fn base_path(&self) -> &Path {
+        &self.base_path
+    }
+
+    pub fn exists(&self) -> bool {
+        self.storage_path.exists()
+    }
+
+    /// Writes a brand new vault guarded by a PIN + hardware binding slot.
+    pub fn create(&self, pin: &str, binding: &dyn HardwareBinding, vault: &SatyaVault) -> Result<VaultKey> {
+        let data_key = VaultKey::generate();
+        let pin_secret = VaultKey::generate();
+        let slot = self.wrap_slot(&data_key, &device_factors(binding), &UnlockSecrets::default(), Some(&pin_secret), Some(binding))?;
+        let mut envelope = VaultEnvelope {
+            version: ENVELOPE_VERSION,
+            pin: PinRecord::seal(pin, &pin_secret)?,
+            slots: vec![slot],
+            shadow: ShadowRegion::noise(),
+            guard: GuardRecord::new(&binding.secret()?)?,
+            nonce: Vec::new(),
+            ciphertext: Vec::new(),
+        };
+        Self::seal_payload(&mut envelope, &data_key, vault)?;
+        self.write_envelope(&envelope)?;
+        Ok(data_key)
+    }
+
+    /// Re-encrypts the vault payload, keeping every key slot untouched.
+    pub fn atomic_save(&self, data_key: &VaultKey, vault: &SatyaVault) -> Result<()> {
+        let mut envelope = self.read_envelope()?;
+        Self::seal_payload(&mut envelope, data_key, vault)?;
+        self.write_envelope(&envelope)
+    }
+
+    /// Persists `vault` into the compartment the session was opened on.
+    pub fn save(&self, compartment: Compartment, key: &VaultKey, vault: &SatyaVault) -> Result<()> {
+        match compartment {
+            Compartment::Primary => self.atomic_save(key, vault),
+            Compartment::Decoy => {
+                let mut envelope = self.read_envelope()?;
+                envelope.shadow.reseal(key, vault)?;
+                self.write_envelope(&envelope)
+            },
+        }
+    }
+
+    /// Like `unlock_with`, but a PIN that fails on the primary vault is also
+    /// tried against the shadow region, opening the decoy on a duress PIN.
+    pub fn unlock_compartment(&self, secrets: &UnlockSecrets) -> Result<(SatyaVault, VaultKey, Compartment)> {
+        match self.unlock_with(secrets) {
+            Ok((vault, key)) => Ok((vault, key, Compartment::Primary)),
+            Err(e) if matches!(e.downcast_ref::<UnlockError>(), Some(UnlockError::Mismatch)) => {
+                let pin = secrets.pin.ok_or(e)?;
+                let (vault, key) = self.open_decoy(pin)?;
+                Ok((vault, key, Compartment::Decoy))
+            },
+            Err(e) => Err(e),
+        }
+    }
+
+    fn open_decoy(&self, pin: &str) -> Result<(SatyaVault, VaultKey)> {
+        let envelope = self.read_envelope()?;
+        let key = envelope.shadow.key(pin)?;
+        let vault = envelope.shadow.open(&key)?;
+        Ok((vault, key))
+    }
+
+    /// Seals a fresh decoy vault under `duress_pin`, replacing any previous one.
+    pub fn set_duress_pin(&self, duress_pin: &str) -> Result<()> {
+        let mut envelope = self.read_envelope()?;
+        if envelope.pin.open(duress_pin).is_ok() {
+            return Err(anyhow!("Duress PIN must differ from the vault PIN"));
+        }
+        let decoy = SatyaVault {
+            master_seed: rand::random::<[u8; 32]>().to_vec(),
+            ..Default::default()
+        };
+        envelope.shadow = ShadowRegion::seal(duress_pin, &decoy)?.0;
+        self.write_envelope(&envelope)
+    }
+
+    /// Overwrites the shadow region with fresh noise.
+    pub fn clear_duress_pin(&self) -> Result<()> {
+        let mut envelope = self.read_envelope()?;
+        envelope.shadow = ShadowRegion::noise();
+        self.write_envelope(&envelope)
+    }
+
+    /// Re-seals the decoy under a new duress PIN (PIN change inside a decoy session).
+    pub fn change_decoy_pin(&self, old_pin: &str, new_pin: &str) -> Result<()> {
+        let (vault, _) = self.open_decoy(old_pin)?;
+        let mut envelope = self.read_envelope()?;
+        envelope.shadow = ShadowRegion::seal(new_pin, &vault)?.0;
+        self.write_envelope(&envelope)
+    }
+
+    pub fn verify_decoy_pin(&self, pin: &str) -> Result<()> {
+        self.open_decoy(pin).map(|_| ())
+    }
+
+    /// Opens the vault through whichever device slot matches this launch.
+    /// Vaults written before the envelope format are migrated in place.
+    pub fn unlock(&self, pin: &str, hw_id: &str) -> Result<(SatyaVault, VaultKey)> {
+        self.unlock_with(&UnlockSecrets::pin(pin, hw_id))
+    }
+
+    /// Opens the vault through the first slot whose factors are all satisfied.
+    pub fn unlock_with(&self, secrets: &UnlockSecrets) -> Result<(SatyaVault, VaultKey)> {
+        if !self.exists() { return Err(anyhow!("Vault not found")); }
+        let raw = fs::read(&self.storage_path)?;
+        if !raw.starts_with(ENVELOPE_MAGIC) {
+            let pin = secrets.pin.ok_or_else(|| anyhow!("PIN required"))?;
+            return self.migrate_legacy(&raw, pin, secrets.hw_id);
+        }
+        let envelope = Self::decode_envelope(&raw)?;
+        let data_key = self.open_any(&envelope, secrets, |_| true)
+            .ok_or(UnlockError::Mismatch)?;
+        let vault = Self::open_payload(&envelope, &data_key)?;
+        Ok((vault, data_key))
+    }
+
+    /// Adds (or replaces) the PIN + recovery code slot used for re-binding.
+    pub fn enroll_recovery(&self, data_key: &VaultKey, pin: &str, recovery_code: &str) -> Result<()> {
+        let secrets = UnlockSecrets { pin: Some(pin), recovery_code: Some(recovery_code), ..Default::default() };
+        let mut envelope = self.read_envelope()?;
+        let slots = self.build_slots(&envelope, data_key, &[FactorKind::Pin, FactorKind::RecoveryCode], UnlockPolicy::AllOf, &secrets, None)?;
+        envelope.slots.retain(|s| !s.is_recovery_slot());
+        envelope.slots.extend(slots);
+        self.write_envelope(&envelope)
+    }
+
+    /// Wraps the data key for newly enrolled factors. `AnyOf` adds one slot
+    /// per factor, `AllOf` adds a single slot requiring all of them.
+    pub fn enroll(&self, data_key: &VaultKey, kinds: &[FactorKind], policy: UnlockPolicy, secrets: &UnlockSecrets, binding: Option<&dyn HardwareBinding>) -> Result<()> {
+        let mut envelope = self.read_envelope()?;
+        let slots = self.build_slots(&envelope, data_key, kinds, policy, secrets, binding)?;
+        envelope.slots.extend(slots);
+        self.write_envelope(&envelope)
+    }
+
+    /// Drops every slot that depends on `kind`. At least one slot must remain.
+    pub fn revoke(&self, kind: FactorKind, hw_id: &str) -> Result<()> {
+        let mut envelope = self.read_envelope()?;
+        let remaining: Vec<KeySlot> = envelope.slots.iter().filter(|s| !s.requires(kind)).cloned().collect();
+        if remaining.is_empty() { return Err(anyhow!("Cannot revoke the last unlock factor")); }
+        envelope.slots = remaining;
+        self.retag_guard(&mut envelope, hw_id)?;
+        self.write_envelope(&envelope)
+    }
+
+    /// Factor combinations currently able to open the vault.
+    pub fn slot_descriptions(&self) -> Result<Vec<String>> {
+        Ok(self.read_envelope()?.slots.iter().map(KeySlot::describe).collect())
+    }
+
+    /// Replaces every device slot with one bound to `binding`.
+    /// The caller proves ownership with the PIN plus the recovery code.
+    pub fn rebind(&self, pin: &str, recovery_code: &str, hw_id: &str, binding: &dyn HardwareBinding) -> Result<(SatyaVault, VaultKey)> {
+        let mut envelope = self.read_envelope()?;
+        let secrets = UnlockSecrets { pin: Some(pin), recovery_code: Some(recovery_code), ..Default::default() };
+        let data_key = self.open_any(&envelope, &secrets, KeySlot::is_recovery_slot)
+            .ok_or(UnlockError::Mismatch)?;
+        let pin_secret = envelope.pin.open(pin)?;
+        let slot = self.wrap_slot(&data_key, &device_factors(binding), &secrets, Some(&pin_secret), Some(binding))?;
+        envelope.slots.retain(|s| !s.is_device_slot());
+        envelope.slots.push(slot);
+        // The old binding's key may be gone: the caller proved the PIN and recovery code.
+        self.retag_guard(&mut envelope, hw_id)?;
+        let vault = Self::open_payload(&envelope, &data_key)?;
+        self.write_envelope(&envelope)?;
+        Ok((vault, data_key))
+    }
+
+    /// Replaces the device slots with a new binding while the vault is open.
+    pub fn set_binding(&self, data_key: &VaultKey, pin: &str, hw_id: &str, binding: &dyn HardwareBinding) -> Result<()> {
+        let mut envelope = self.read_envelope()?;
+        let pin_secret = envelope.pin.open(pin)?;
+        let slot = self.wrap_slot(data_key, &device_factors(binding), &UnlockSecrets::default(), Some(&pin_secret), Some(binding))?;
+        envelope.slots.retain(|s| !s.is_device_slot());
+        envelope.slots.push(slot);
+        self.retag_guard(&mut envelope, hw_id)?;
+        self.write_envelope(&envelope)
+    }
+
+    /// Replaces the PIN secret with a fresh one wrapped under the new PIN
+    /// (fresh salt and KDF parameters) and re-wraps every PIN-bound slot, so
+    /// an old PIN record spliced back in opens nothing. `secrets` carries the
+    /// old PIN plus whatever else those slots need (recovery code, key file).
+    /// The payload is re-sealed in the same write; a crash before the rename
+    /// leaves the previous file (and the old PIN) fully intact.
+    pub fn change_pin(&self, data_key: &VaultKey, secrets: &UnlockSecrets, new_pin: &str) -> Result<()> {
+        let mut envelope = self.read_envelope()?;
+        let old_pin = secrets.pin.ok_or_else(|| anyhow!("PIN required"))?;
+        let old_secret = envelope.pin.open(old_pin)?;
+        if envelope.shadow.key(new_pin).and_then(|k| envelope.shadow.open(&k)).is_ok() {
+            return Err(anyhow!("New PIN must differ from the duress PIN"));
+        }
+        let vault = Self::open_payload(&envelope, data_key)?;
+        let new_secret = VaultKey::generate();
+        for slot in envelope.slots.iter_mut().filter(|s| s.requires(FactorKind::Pin)) {
+            if !slot.satisfiable(secrets) {
+                return Err(anyhow!("Changing the PIN needs every factor of the {} slot", slot.describe()));
+            }
+            // Never re-wrap under a wrong recovery code or key file: that slot would be lost.
+            let old_key = self.slot_key(&slot.factors, secrets, Some(&old_secret), None)?;
+            open(&old_key, &slot.nonce, &slot.wrapped_key).map_err(|_| UnlockError::Mismatch)?;
+            *slot = self.wrap_slot(data_key, &slot.factors, secrets, Some(&new_secret), None)?;
+        }
+        envelope.pin = PinRecord::seal(new_pin, &new_secret)?;
+        Self::seal_payload(&mut envelope, data_key, &vault)?;
+        self.write_envelope(&envelope)
+    }
+
+    /// The brute-force guard record.
+    pub fn guard_record(&self) -> Result<GuardRecord> {
+        Ok(self.read_envelope()?.guard)
+    }
+
+    pub fn set_guard_record(&self, record: GuardRecord) -> Result<()> {
+        let mut envelope = self.read_envelope()?;
+        envelope.guard = record;
+        self.write_envelope(&envelope)
+    }
+
+    /// MAC key of the guard record on this device.
+    pub fn guard_secret(&self, hw_id: &str) -> Result<[u8; 32]> {
+        self.read_envelope()?.guard_secret(hw_id, &self.base_path)
+    }
+
+    /// Re-keys the guard record after the device slots changed.
+    fn retag_guard(&self, envelope: &mut VaultEnvelope, hw_id: &str) -> Result<()> {
+        let key = envelope.guard_secret(hw_id, &self.base_path)?;
+        envelope.guard = envelope.guard.retag(&key)?;
+        Ok(())
+    }
+
+    /// Destroys every wrapped copy of the data key. Without the slots the
+    /// payload is undecryptable, so the file is overwritten and removed.
+    pub fn crypto_erase(&self) -> Result<()> {
+        for path in [self.storage_path.clone(), self.storage_path.with_extension("tmp")] {
+            if let Ok(meta) = fs::metadata(&path) {
+                let noise: Vec<u8> = (0..meta.len()).map(|_| rand::random::<u8>()).collect();
+                fs::write(&path, noise)?;
+                File::open(&path)?.sync_all()?;
+                fs::remove_file(&path)?;
+            }
+        }
         Ok(())
     }
 
-    pub fn load(&self, key: &VaultKey, hw_id: &[u8]) -> Result<SatyaVault> {
-        if !self.storage_path.exists() { return Ok(SatyaVault::default()); }
-        let encrypted = fs::read(&self.storage_path)?;
-        let decrypted = decrypt_with_binding(key, hw_id, &encrypted)
-            .map_err(|_| anyhow!("Hardware/PIN Mismatch"))?;
-        let vault: SatyaVault = bincode::deserialize(&decrypted).context("Vault corruption")?;
-        Ok(vault)
+    pub fn has_recovery(&self) -> Result<bool> {
+        Ok(self.read_envelope()?.slots.iter().any(KeySlot::is_recovery_slot))
+    }
+
+    fn migrate_legacy(&self, raw: &[u8], pin: &str, hw_id: &str) -> Result<(SatyaVault, VaultKey)> {
+        let legacy_key = VaultKey::from_pin(pin, LEGACY_SALT)?;
+        let decrypted = decrypt_with_binding(&legacy_key, hw_id.as_bytes(), raw)
+            .map_err(|_| UnlockError::Mismatch)?;
+        let vault = SatyaVault::decode(&decrypted)?;
+        let binding = binding::default_for(hw_id, &self.base_path);
+        let data_key = self.create(pin, binding.as_ref(), &vault)?;
+        Ok((vault, data_key))
+    }
+
+    fn build_slots(&self, envelope: &VaultEnvelope, data_key: &VaultKey, kinds: &[FactorKind], policy: UnlockPolicy, secrets: &UnlockSecrets, binding: Option<&dyn HardwareBinding>) -> Result<Vec<KeySlot>> {
+        if kinds.is_empty() { return Err(anyhow!("No factors to enroll")); }
+        let pin_secret = match (kinds.contains(&FactorKind::Pin), secrets.pin) {
+            (true, Some(pin)) => Some(envelope.pin.open(pin)?),
+            (true, None) => return Err(anyhow!("PIN required")),
+            (false, _) => None,
+        };
+        let groups: Vec<Vec<FactorKind>> = match policy {
+            UnlockPolicy::AnyOf => kinds.iter().map(|k| vec![*k]).collect(),
+            UnlockPolicy::AllOf => vec![kinds.to_vec()],
+        };
+        groups.iter().map(|group| {
+            let factors = group.iter()
+                .map(|kind| new_factor(*kind, secrets, binding))
+                .collect::<Result<Vec<_>>>()?;
+            self.wrap_slot(data_key, &factors, secrets, pin_secret.as_ref(), binding)
+        }).collect()
+    }
+
+    fn open_any(&self, envelope: &VaultEnvelope, secrets: &UnlockSecrets, filter: fn(&KeySlot) -> bool) -> Option<VaultKey> {
+        // One Argon2 run for the PIN, shared by every slot that needs it.
+        let pin_secret = secrets.pin.and_then(|pin| envelope.pin.open(pin).ok());
+        envelope.slots.iter()
+            .filter(|s| filter(s) && s.satisfiable(secrets))
+            .filter(|s| pin_secret.is_some() || !s.requires(FactorKind::Pin))
+            .find_map(|slot| {
+                let slot_key = self.slot_key(&slot.factors, secrets, pin_secret.as_ref(), None).ok()?;
+                let raw = open(&slot_key, &slot.nonce, &slot.wrapped_key).ok()?;
+                VaultKey::from_bytes(&raw).ok()
+            })
+    }
+
+    fn wrap_slot(&self, data_key: &VaultKey, factors: &[SlotFactor], secrets: &UnlockSecrets, pin_secret: Option<&VaultKey>, binding: Option<&dyn HardwareBinding>) -> Result<KeySlot> {
+        let slot_key = self.slot_key(factors, secrets, pin_secret, binding)?;
+        let (nonce, wrapped_key) = seal(&slot_key, data_key.as_bytes())?;
+        Ok(KeySlot { factors: factors.to_vec(), nonce, wrapped_key })
+    }
+
+    /// Chains every factor secret of a slot into a single wrapping key.
+    fn slot_key(&self, factors: &[SlotFactor], secrets: &UnlockSecrets, pin_secret: Option<&VaultKey>, binding: Option<&dyn HardwareBinding>) -> Result<VaultKey> {
+        let mut key = VaultKey::from_bytes(&[0u8; 32])?;
+        for factor in factors {
+            key = match factor {
+                SlotFactor::Pin => {
+                    let secret = pin_secret.ok_or_else(|| anyhow!("PIN required"))?;
+                    key.chain("pin", secret.as_bytes())?
+                },
+                SlotFactor::RecoveryCode { salt } => {
+                    let code = secrets.recovery_code.ok_or_else(|| anyhow!("Recovery code required"))?;
+                    let stretched = VaultKey::from_pin(&normalize_recovery_code(code), salt)?;
+                    key.chain("recovery_code", stretched.as_bytes())?
+                },
+                SlotFactor::KeyFile { .. } => {
+                    let path = secrets.key_file.ok_or_else(|| anyhow!("Key file required"))?;
+                    key.chain("key_file", &KeyFileBinding::new(path).secret()?)?
+                },
+                SlotFactor::Hardware { binding: kind, reference } => {
+                    let secret = match binding {
+                        Some(b) => b.secret()?,
+                        None => binding::resolve(*kind, reference, secrets.hw_id, &self.base_path).secret()?,
+                    };
+                    key.chain("hardware", &secret)?
+                },
+            };
+        }
+        Ok(key)
     }
-}
\ No newline at end of file
+
+    fn seal_payload(envelope: &mut VaultEnvelope, data_key: &VaultKey, vault: &SatyaVault) -> Result<()> {
+        let encoded = vault.encode()?;
+        let (nonce, ciphertext) = seal(data_key, &encoded)?;
+        envelope.nonce = nonce;
+        envelope.ciphertext = ciphertext;
+        Ok(())
+    }
+
+    fn open_payload(envelope: &VaultEnvelope, data_key: &VaultKey) -> Result<SatyaVault> {
+        let decrypted = open(data_key, &envelope.nonce, &envelope.ciphertext)
+            .map_err(|_| anyhow!("Vault corruption"))?;
+        SatyaVault::decode(&decrypted)
+    }
+
+    fn read_envelope(&self) -> Result<VaultEnvelope> {
+        let raw = fs::read(&self.storage_path)?;
+        Self::decode_envelope(&raw)
+    }
+
+    fn decode_envelope(raw: &[u8]) -> Result<VaultEnvelope> {
+        if !raw.starts_with(ENVELOPE_MAGIC) { return Err(anyhow!("Legacy vault format")); }
+        let body = &raw[ENVELOPE_MAGIC.len()..];
+        let version = body.get(..4).and_then(|b| b.try_into().ok()).map(u32::from_le_bytes);
+        match version {
+            Some(ENVELOPE_VERSION) => bincode::deserialize(body).context("Vault corruption"),
+            _ => Err(anyhow!("Unsupported vault version")),
+        }
+    }
+
+    fn write_envelope(&self, envelope: &VaultEnvelope) -> Result<()> {
+        let mut bytes = ENVELOPE_MAGIC.to_vec();
+        bytes.extend(bincode::serialize(envelope).context("Serialization error")?);
+        let tmp_path = self.storage_path.with_extension("tmp");
+        fs::write(&tmp_path, bytes)?;
+        File::open(&tmp_path)?.sync_all()?;
+        fs::rename(&tmp_path, &self.storage_path)?;
+        if let Some(dir) = self.storage_path.parent() {
+            // Directory fsync is best effort; not every platform allows it.
+            let _ = File::open(dir).and_then(|d| d.sync_all());
+        }
+        Ok(())
+    }
+}
+
+fn device_factors(binding: &dyn HardwareBinding) -> Vec<SlotFactor> {
+    vec![
+        SlotFactor::Pin,
+        SlotFactor::Hardware { binding: binding.kind(), reference: binding.reference() },
+    ]
+}
+
+/// Fresh factor metadata for enrollment; provisions a key file if missing.
+fn new_factor(kind: FactorKind, secrets: &UnlockSecrets, binding: Option<&dyn HardwareBinding>) -> Result<SlotFactor> {
+    Ok(match kind {
+        FactorKind::Pin => SlotFactor::Pin,
+        FactorKind::RecoveryCode => SlotFactor::RecoveryCode { salt: random_salt() },
+        FactorKind::KeyFile => {
+            let path = secrets.key_file.ok_or_else(|| anyhow!("Key file required"))?;
+            KeyFileBinding::new(path).provision()?;
+            let hint = Path::new(path).file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+            SlotFactor::KeyFile { hint }
+        },
+        FactorKind::Hardware => {
+            let binding = binding.ok_or_else(|| anyhow!("Hardware binding required"))?;
+            SlotFactor::Hardware { binding: binding.kind(), reference: binding.reference() }
+        },
+    })
+}
+
+fn random_salt() -> Vec<u8> {
+    rand::random::<[u8; 16]>().to_vec()
+}
@@ -0,0 +1,95 @@
+/**
+ * FILE: rust_core/src/private.rs
+ * VERSION: 1.0.0
+ * PHASE: Phase 11.17 (Private Intents)
+ * PURPOSE: NIP-17 style private intents. The signed intent travels as an
+ * unsigned rumor, sealed (NIP-44) by the sender's identity key and gift
+ * wrapped (NIP-59) by a throwaway key, so relays only see the recipient.
+ */
+use crate::did;
+use crate::domain::SignedIntent;
+use anyhow::{Result, anyhow};
+use nostr_sdk::prelude::{nip44, Event, EventBuilder, EventId, JsonUtil, Keys, Kind, Tag, TagKind, Timestamp, UnsignedEvent, XOnlyPublicKey};
+use serde::{Deserialize, Serialize};
+
+/// Rumor kind (NIP-17 direct message) carrying the intent JSON.
+pub const KIND_PRIVATE_INTENT: u64 = 14;
+pub const KIND_SEAL: u64 = 13;
+pub const KIND_GIFT_WRAP: u64 = 1059;
+/// Rumor tag carrying the signer's `did::bind_nostr_key` binding of the seal author.
+const BINDING_TAG: &str = "satya_binding";
+/// Seals and wraps are backdated by up to this much so their timestamps
+/// do not reveal when the intent was sent.
+pub const MAX_BACKDATE_SECS: u64 = 2 * 24 * 3600;
+
+/// An intent received through a gift wrap.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PrivateIntent {
+    /// Id of the gift wrap, the only event relays saw.
+    pub event_id: String,
+    /// Sender's Nostr public key (hex), authenticated by the seal signature
+    /// and bound to the intent's signer key.
+    pub sender_public_key: String,
+    /// When the sender created the message; the wrap's own timestamp is randomized.
+    pub sent_at: u64,
+    pub intent: SignedIntent,
+}
+
+/// Seals `signed_json` from `sender` to `recipient` and wraps it with a fresh
+/// key. `binding_hex` is the intent signer's binding of `sender`.
+pub fn gift_wrap(sender: &Keys, recipient: &XOnlyPublicKey, signed_json: &str, binding_hex: &str, now: u64) -> Result<Event> {
+    let tags = vec![
+        Tag::public_key(*recipient),
+        Tag::Generic(TagKind::Custom(BINDING_TAG.into()), vec![binding_hex.to_string()]),
+    ];
+    let rumor = EventBuilder::new(Kind::from(KIND_PRIVATE_INTENT), signed_json, tags)
+        .to_unsigned_event(sender.public_key());
+    let sealed = nip44::encrypt(&sender.secret_key()?, recipient, rumor.as_json(), nip44::Version::V2)?;
+    let seal = backdated(sender, KIND_SEAL, sealed, Vec::new(), now)?;
+
+    let wrapper = Keys::generate();
+    let wrapped = nip44::encrypt(&wrapper.secret_key()?, recipient, seal.as_json(), nip44::Version::V2)?;
+    backdated(&wrapper, KIND_GIFT_WRAP, wrapped, vec![Tag::public_key(*recipient)], now)
+}
+
+fn backdated(keys: &Keys, kind: u64, content: String, tags: Vec<Tag>, now: u64) -> Result<Event> {
+    let created_at = Timestamp::from(now.saturating_sub(rand::random::<u64>() % MAX_BACKDATE_SECS));
+    let kind = Kind::from(kind);
+    let pubkey = keys.public_key();
+    let id = EventId::new(&pubkey, created_at, &kind, &tags, &content);
+    Ok(UnsignedEvent { id, pubkey, created_at, kind, tags, content }.sign(keys)?)
+}
+
+/// Opens a gift wrap addressed to `receiver`. The seal must be signed by the
+/// rumor's author, whom the intent's signer key must have bound; whether that
+/// key belongs to the signer's DID still has to be verified by the caller.
+pub fn unwrap(receiver: &Keys, wrap: &Event) -> Result<PrivateIntent> {
+    if wrap.kind != Kind::from(KIND_GIFT_WRAP) { return Err(anyhow!("Not a gift wrap")); }
+    wrap.verify()?;
+    let secret = receiver.secret_key()?;
+    let seal = nip44::decrypt(&secret, &wrap.pubkey, &wrap.content)
+        .map_err(|_| anyhow!("Gift wrap not addressed to this key"))?;
+    let seal = Event::from_json(seal)?;
+    if seal.kind != Kind::from(KIND_SEAL) { return Err(anyhow!("Not a seal")); }
+    seal.verify()?;
+    let rumor = nip44::decrypt(&secret, &seal.pubkey, &seal.content)
+        .map_err(|_| anyhow!("Seal could not be opened"))?;
+    let rumor = UnsignedEvent::from_json(rumor)?;
+    if rumor.pubkey != seal.pubkey { return Err(anyhow!("Seal not signed by the sender")); }
+    if rumor.kind != Kind::from(KIND_PRIVATE_INTENT) { return Err(anyhow!("Not a private intent")); }
+    let mut intent: SignedIntent = serde_json::from_str(&rumor.content)?;
+    intent.is_verified = false;
+    let binding = rumor.tags.iter().map(|t| t.as_vec())
+        .find(|t| t.first().map(String::as_str) == Some(BINDING_TAG))
+        .and_then(|t| t.get(1).cloned())
+        .unwrap_or_default();
+    if !did::verify_nostr_binding(&intent.signer_public_key_hex, &intent.signer_did, &seal.pubkey.to_string(), &binding) {
+        return Err(anyhow!("Seal not signed by the intent's signer"));
+    }
+    Ok(PrivateIntent {
+        event_id: wrap.id.to_hex(),
+        sender_public_key: rumor.pubkey.to_string(),
+        sent_at: rumor.created_at.as_u64(),
+        intent,
+    })
+}
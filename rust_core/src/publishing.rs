@@ -0,0 +1,126 @@
+/**
+ * FILE: rust_core/src/publishing.rs
+ * VERSION: 1.0.0
+ * PHASE: Phase 11.12 (Publish Outcomes)
+ * PURPOSE: Per-relay outcomes of a publish, classified from NIP-01 `OK`
+ * replies, and the acknowledgment policy that decides overall success.
+ */
+use std::str::FromStr;
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PublishStatus {
+    Ok,
+    /// The relay already had the event; counts as an acknowledgment.
+    Duplicate,
+    Blocked,
+    RateLimited,
+    Invalid,
+    /// The relay wants NIP-42 AUTH first and no approved identity has answered.
+    AuthRequired,
+    /// Refused for any other reason.
+    Rejected,
+    /// No `OK` within the publish timeout.
+    Timeout,
+    /// The event could not be sent at all.
+    Failed,
+}
+
+impl PublishStatus {
+    pub fn is_ack(self) -> bool {
+        matches!(self, PublishStatus::Ok | PublishStatus::Duplicate)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RelayOutcome {
+    pub url: String,
+    pub status: PublishStatus,
+    /// The relay's `OK` message, or the local error.
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PublishResult {
+    pub event_id: String,
+    pub acknowledgments: u32,
+    /// Whether the acknowledgments satisfy the publish policy.
+    pub success: bool,
+    pub outcomes: Vec<RelayOutcome>,
+    /// Set when the event went to the outbox for a later retry.
+    pub queued: bool,
+}
+
+/// How many relay acknowledgments make a publish successful.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AckPolicy {
+    AtLeast(u32),
+    Majority,
+    All,
+}
+
+impl Default for AckPolicy {
+    fn default() -> Self {
+        AckPolicy::AtLeast(1)
+    }
+}
+
+impl FromStr for AckPolicy {
+    type Err = anyhow::Error;
+
+    /// Parses `majority`, `all` or `at_least:<n>`.
+    fn from_str(s: &str) -> Result<Self> {
+        match s.split_once(':') {
+            None if s == "majority" => Ok(AckPolicy::Majority),
+            None if s == "all" => Ok(AckPolicy::All),
+            Some(("at_least", n)) => Ok(AckPolicy::AtLeast(n.trim().parse()?)),
+            _ => Err(anyhow!("Unknown publish policy: {}", s)),
+        }
+    }
+}
+
+impl AckPolicy {
+    pub fn is_satisfied(self, acknowledgments: u32, relays: u32) -> bool {
+        if relays == 0 { return false; }
+        match self {
+            AckPolicy::AtLeast(required) => acknowledgments >= required.max(1),
+            AckPolicy::Majority => acknowledgments * 2 > relays,
+            AckPolicy::All => acknowledgments == relays,
+        }
+    }
+}
+
+/// Classifies an `OK` reply by its machine-readable prefix.
+pub fn classify(accepted: bool, message: &str) -> PublishStatus {
+    let prefix = message.split(':').next().unwrap_or_default().trim();
+    match (accepted, prefix) {
+        (_, "duplicate") => PublishStatus::Duplicate,
+        (true, _) => PublishStatus::Ok,
+        (false, "blocked" | "restricted") => PublishStatus::Blocked,
+        (false, "rate-limited") => PublishStatus::RateLimited,
+        (false, "invalid" | "pow") => PublishStatus::Invalid,
+        (false, "auth-required") => PublishStatus::AuthRequired,
+        (false, _) => PublishStatus::Rejected,
+    }
+}
+
+/// Why a publish fell short, for the outbox's `last_error`.
+pub fn failure_reason(result: &PublishResult) -> String {
+    result.outcomes.iter()
+        .find(|o| !o.status.is_ack())
+        .map(|o| format!("{}: {:?} {}", o.url, o.status, o.message).trim_end().to_string())
+        .unwrap_or_else(|| format!("{} acknowledgment(s), policy not met", result.acknowledgments))
+}
+
+pub fn summarize(event_id: String, outcomes: Vec<RelayOutcome>, policy: AckPolicy) -> PublishResult {
+    let acknowledgments = outcomes.iter().filter(|o| o.status.is_ack()).count() as u32;
+    PublishResult {
+        success: policy.is_satisfied(acknowledgments, outcomes.len() as u32),
+        event_id,
+        acknowledgments,
+        outcomes,
+        queued: false,
+    }
+}
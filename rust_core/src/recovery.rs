@@ -0,0 +1,139 @@
+/**
+ * FILE: rust_core/src/recovery.rs
+ * VERSION: 1.0.0
+ * PHASE: Phase 11.3 (Social Recovery)
+ * PURPOSE: m-of-n social recovery of the master seed. Shares are sealed
+ * (NIP-44 v2) to each guardian's Nostr key and travel as DMs or exported text.
+ */
+use crate::crypto::{VaultKey, constant_time_eq};
+use crate::shamir;
+use anyhow::{Result, anyhow};
+use nostr_sdk::prelude::{Keys, SecretKey, XOnlyPublicKey, FromBech32, nip44};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::str::FromStr;
+
+/// Marks share envelopes, whether pasted, scanned or received as a DM.
+pub const SHARE_PREFIX: &str = "satya-share:";
+const MAX_GUARDIANS: usize = 16;
+
+/// One guardian's piece of the master seed.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct GuardianShare {
+    /// Random id shared by all shares of one split.
+    pub set_id: String,
+    pub threshold: u8,
+    pub total: u8,
+    pub index: u8,
+    pub data: String,
+    /// Binds the set to the seed, so a corrupted share fails reconstruction.
+    pub check: String,
+}
+
+/// Wire form: the share sealed from `from` to the holder.
+#[derive(Serialize, Deserialize)]
+struct ShareEnvelope {
+    from: String,
+    payload: String,
+}
+
+/// Stable Nostr keys used to receive and release guardian shares.
+pub fn guardian_keys(master_seed: &[u8]) -> Result<Keys> {
+    let secret = VaultKey::from_bytes(master_seed)?.chain("satya_guardian_v1", b"")?;
+    let secret = SecretKey::from_slice(secret.as_bytes()).map_err(|e| anyhow!("Key error: {}", e))?;
+    Ok(Keys::new(secret))
+}
+
+/// Accepts hex or `npub` public keys.
+pub fn parse_public_key(key: &str) -> Result<XOnlyPublicKey> {
+    let key = key.trim();
+    XOnlyPublicKey::from_bech32(key)
+        .or_else(|_| XOnlyPublicKey::from_str(key))
+        .map_err(|_| anyhow!("Invalid public key: {}", key))
+}
+
+pub fn split_seed(master_seed: &[u8], threshold: u8, total: u8) -> Result<Vec<GuardianShare>> {
+    if threshold < 2 { return Err(anyhow!("Threshold must be at least 2")); }
+    if total as usize > MAX_GUARDIANS { return Err(anyhow!("At most {} guardians", MAX_GUARDIANS)); }
+    let set_id = hex::encode(rand::random::<[u8; 8]>());
+    let check = checksum(&set_id, master_seed);
+    Ok(shamir::split(master_seed, threshold, total)?
+        .into_iter()
+        .map(|(index, data)| GuardianShare {
+            set_id: set_id.clone(),
+            threshold,
+            total,
+            index,
+            data: hex::encode(data),
+            check: check.clone(),
+        })
+        .collect())
+}
+
+/// Reconstructs the seed. With surplus shares, a corrupted one is skipped.
+pub fn recover_seed(shares: &[GuardianShare]) -> Result<Vec<u8>> {
+    let first = shares.first().ok_or_else(|| anyhow!("No shares"))?;
+    let mut unique: Vec<&GuardianShare> = Vec::new();
+    for share in shares {
+        if share.set_id != first.set_id || share.check != first.check || share.threshold != first.threshold {
+            return Err(anyhow!("Shares belong to different recovery sets"));
+        }
+        if !unique.iter().any(|s| s.index == share.index) { unique.push(share); }
+    }
+    let threshold = first.threshold as usize;
+    if unique.len() < threshold {
+        return Err(anyhow!("Need {} shares, have {}", threshold, unique.len()));
+    }
+    let points = unique.iter()
+        .map(|s| Ok((s.index, hex::decode(&s.data)?)))
+        .collect::<Result<Vec<_>>>()?;
+
+    for subset in combinations(points.len(), threshold) {
+        let chosen: Vec<_> = subset.iter().map(|&i| points[i].clone()).collect();
+        let seed = shamir::combine(&chosen)?;
+        if constant_time_eq(checksum(&first.set_id, &seed).as_bytes(), first.check.as_bytes()) {
+            return Ok(seed);
+        }
+    }
+    Err(anyhow!("Share integrity check failed"))
+}
+
+/// Seals a share to `recipient` as a printable/DM-able envelope.
+pub fn seal_share(share: &GuardianShare, sender: &Keys, recipient: &XOnlyPublicKey) -> Result<String> {
+    let secret = sender.secret_key().map_err(|e| anyhow!("Key error: {}", e))?;
+    let payload = nip44::encrypt(&secret, recipient, serde_json::to_vec(share)?, nip44::Version::V2)
+        .map_err(|e| anyhow!("Encryption failed: {}", e))?;
+    let envelope = ShareEnvelope { from: sender.public_key().to_string(), payload };
+    Ok(format!("{}{}", SHARE_PREFIX, serde_json::to_string(&envelope)?))
+}
+
+pub fn open_share(envelope: &str, recipient: &Keys) -> Result<GuardianShare> {
+    let json = envelope.trim().strip_prefix(SHARE_PREFIX).ok_or_else(|| anyhow!("Not a Satya share"))?;
+    let envelope: ShareEnvelope = serde_json::from_str(json)?;
+    let secret = recipient.secret_key().map_err(|e| anyhow!("Key error: {}", e))?;
+    let plain = nip44::decrypt(&secret, &parse_public_key(&envelope.from)?, envelope.payload)
+        .map_err(|_| anyhow!("Share is not addressed to this key"))?;
+    Ok(serde_json::from_str(&plain)?)
+}
+
+fn checksum(set_id: &str, master_seed: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(b"satya_social_check_v1");
+    hasher.update(set_id.as_bytes());
+    hasher.update(master_seed);
+    hex::encode(hasher.finalize())
+}
+
+/// All k-element index subsets of 0..n, in lexicographic order.
+fn combinations(n: usize, k: usize) -> Vec<Vec<usize>> {
+    let mut result = Vec::new();
+    let mut current: Vec<usize> = (0..k).collect();
+    loop {
+        result.push(current.clone());
+        let Some(i) = (0..k).rev().find(|&i| current[i] < n - k + i) else { return result; };
+        current[i] += 1;
+        for j in i + 1..k {
+            current[j] = current[j - 1] + 1;
+        }
+    }
+}
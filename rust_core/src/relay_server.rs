@@ -0,0 +1,351 @@
+/**
+ * FILE: rust_core/src/relay_server.rs
+ * VERSION: 1.0.0
+ * PHASE: Phase 11.16 (Embedded Relay)
+ * PURPOSE: Minimal in-process NIP-01 relay (EVENT/REQ/CLOSE/COUNT with OK
+ * and EOSE replies) for integration tests, offline demos and LAN use.
+ * Events are kept in memory, optionally mirrored to a JSON-lines file.
+ * Optionally demands NIP-42 AUTH before serving a connection.
+ */
+use anyhow::Result;
+use futures_util::{SinkExt, StreamExt};
+use nostr_sdk::prelude::{ClientMessage, Event, EventId, Filter, JsonUtil, Kind, RelayMessage, SubscriptionId, Tag, Timestamp, XOnlyPublicKey};
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{broadcast, watch};
+use tokio_tungstenite::tungstenite::Message;
+
+/// Subscriptions a single connection may hold open at once.
+const MAX_SUBSCRIPTIONS: usize = 32;
+/// Events returned per filter when the filter sets no limit.
+const DEFAULT_LIMIT: usize = 500;
+/// How far an AUTH event's timestamp may be from the relay's clock.
+const AUTH_WINDOW_SECS: u64 = 10 * 60;
+
+/// What happened to an event handed to the store.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StoreOutcome {
+    Stored,
+    Duplicate,
+    /// A replaceable event older than the version already stored.
+    Superseded,
+}
+
+/// Events held by the relay. Ephemeral kinds are kept too: Satya intents
+/// use one (29001) and history queries must find them.
+pub struct EventStore {
+    path: Option<PathBuf>,
+    events: Vec<Event>,
+    ids: HashSet<EventId>,
+}
+
+impl EventStore {
+    pub fn in_memory() -> Self {
+        Self { path: None, events: Vec::new(), ids: HashSet::new() }
+    }
+
+    /// Loads (or creates) a JSON-lines file. Lines that do not parse or
+    /// verify are dropped on the next rewrite.
+    pub fn open(path: &Path) -> Result<Self> {
+        let mut store = Self::in_memory();
+        if let Ok(raw) = fs::read_to_string(path) {
+            for line in raw.lines() {
+                if let Ok(event) = Event::from_json(line) {
+                    if event.verify().is_ok() { store.apply(event); }
+                }
+            }
+        }
+        store.path = Some(path.to_path_buf());
+        store.rewrite()?;
+        Ok(store)
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Stores a verified event, replacing older versions of replaceable and
+    /// parameterized replaceable events.
+    pub fn insert(&mut self, event: Event) -> Result<StoreOutcome> {
+        let before = self.events.len();
+        let outcome = self.apply(event.clone());
+        if outcome == StoreOutcome::Stored {
+            if self.events.len() == before + 1 { self.append(&event)?; } else { self.rewrite()?; }
+        }
+        Ok(outcome)
+    }
+
+    fn apply(&mut self, event: Event) -> StoreOutcome {
+        if self.ids.contains(&event.id) { return StoreOutcome::Duplicate; }
+        if event.is_replaceable() || event.is_parameterized_replaceable() {
+            let replaces = |e: &Event| e.pubkey == event.pubkey && e.kind == event.kind
+                && (!event.is_parameterized_replaceable() || e.identifier().unwrap_or_default() == event.identifier().unwrap_or_default());
+            if self.events.iter().any(|e| replaces(e) && e.created_at > event.created_at) {
+                return StoreOutcome::Superseded;
+            }
+            let ids = &mut self.ids;
+            self.events.retain(|e| {
+                let keep = !replaces(e);
+                if !keep { ids.remove(&e.id); }
+                keep
+            });
+        }
+        self.ids.insert(event.id);
+        self.events.push(event);
+        StoreOutcome::Stored
+    }
+
+    /// Events matching any filter, newest first, each filter capped by its limit.
+    pub fn query(&self, filters: &[Filter]) -> Vec<Event> {
+        let mut newest_first: Vec<&Event> = self.events.iter().collect();
+        newest_first.sort_by(|a, b| b.created_at.cmp(&a.created_at).then_with(|| a.id.cmp(&b.id)));
+        let mut seen = HashSet::new();
+        let mut results = Vec::new();
+        for filter in filters {
+            let limit = filter.limit.unwrap_or(DEFAULT_LIMIT);
+            for event in newest_first.iter().filter(|e| filter.match_event(e)).take(limit) {
+                if seen.insert(event.id) { results.push((*event).clone()); }
+            }
+        }
+        results.sort_by_key(|e| Reverse(e.created_at));
+        results
+    }
+
+    /// Number of events matching any filter. Limits do not apply (NIP-45).
+    pub fn count(&self, filters: &[Filter]) -> usize {
+        self.events.iter().filter(|e| filters.iter().any(|f| f.match_event(e))).count()
+    }
+
+    fn append(&self, event: &Event) -> Result<()> {
+        let Some(path) = &self.path else { return Ok(()) };
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{}", event.as_json())?;
+        file.sync_all()?;
+        Ok(())
+    }
+
+    fn rewrite(&self) -> Result<()> {
+        let Some(path) = &self.path else { return Ok(()) };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let tmp_path = path.with_extension("tmp");
+        let mut lines = String::new();
+        for event in &self.events {
+            lines.push_str(&event.as_json());
+            lines.push('\n');
+        }
+        fs::write(&tmp_path, lines)?;
+        File::open(&tmp_path)?.sync_all()?;
+        fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
+}
+
+/// State shared by every connection of one relay.
+struct Shared {
+    store: Mutex<EventStore>,
+    live: broadcast::Sender<Event>,
+    /// Whether connections must answer an AUTH challenge first.
+    require_auth: bool,
+}
+
+/// Per-connection state.
+struct Connection {
+    subscriptions: HashMap<SubscriptionId, Vec<Filter>>,
+    challenge: String,
+    /// Key the connection authenticated with, if any.
+    authenticated: Option<XOnlyPublicKey>,
+}
+
+impl Connection {
+    fn admitted(&self, shared: &Shared) -> bool {
+        !shared.require_auth || self.authenticated.is_some()
+    }
+}
+
+/// A running relay. Stops when `stop` is called or the handle is dropped.
+pub struct LocalRelay {
+    addr: SocketAddr,
+    shutdown: watch::Sender<bool>,
+}
+
+impl LocalRelay {
+    /// Binds `bind_address` (e.g. `127.0.0.1:0` for tests, `0.0.0.0:7447`
+    /// for the LAN) and starts serving on the current Tokio runtime.
+    pub async fn start(bind_address: &str, store: EventStore) -> Result<Self> {
+        Self::serve(bind_address, store, false).await
+    }
+
+    /// Like `start`, but every connection gets a NIP-42 challenge and is
+    /// refused EVENT, REQ and COUNT until it answers with a valid AUTH.
+    pub async fn start_with_auth(bind_address: &str, store: EventStore) -> Result<Self> {
+        Self::serve(bind_address, store, true).await
+    }
+
+    async fn serve(bind_address: &str, store: EventStore, require_auth: bool) -> Result<Self> {
+        let listener = TcpListener::bind(bind_address).await?;
+        let addr = listener.local_addr()?;
+        let (shutdown, stopped) = watch::channel(false);
+        let shared = Arc::new(Shared { store: Mutex::new(store), live: broadcast::channel(1024).0, require_auth });
+        tokio::spawn(accept_connections(listener, shared, stopped));
+        Ok(Self { addr, shutdown })
+    }
+
+    pub fn local_addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// `ws://` URL of the relay. A wildcard bind is reported as loopback;
+    /// LAN peers substitute the host's address.
+    pub fn url(&self) -> String {
+        let mut addr = self.addr;
+        if addr.ip().is_unspecified() {
+            addr.set_ip(if addr.is_ipv4() { [127, 0, 0, 1].into() } else { std::net::Ipv6Addr::LOCALHOST.into() });
+        }
+        format!("ws://{}", addr)
+    }
+
+    pub fn stop(&self) {
+        let _ = self.shutdown.send(true);
+    }
+}
+
+impl Drop for LocalRelay {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+async fn accept_connections(listener: TcpListener, shared: Arc<Shared>, mut stopped: watch::Receiver<bool>) {
+    loop {
+        tokio::select! {
+            _ = stopped.changed() => break,
+            accepted = listener.accept() => {
+                if let Ok((stream, _)) = accepted {
+                    tokio::spawn(serve_connection(stream, shared.clone(), stopped.clone()));
+                }
+            },
+        }
+    }
+}
+
+async fn serve_connection(stream: TcpStream, shared: Arc<Shared>, mut stopped: watch::Receiver<bool>) {
+    let Ok(socket) = tokio_tungstenite::accept_async(stream).await else { return };
+    let (mut outgoing, mut incoming) = socket.split();
+    let mut live = shared.live.subscribe();
+    let mut connection = Connection { subscriptions: HashMap::new(), challenge: uuid::Uuid::new_v4().to_string(), authenticated: None };
+    if shared.require_auth {
+        let challenge = RelayMessage::new_auth(connection.challenge.clone());
+        if outgoing.send(Message::Text(challenge.as_json())).await.is_err() { return; }
+    }
+    loop {
+        let replies = tokio::select! {
+            _ = stopped.changed() => break,
+            message = incoming.next() => match message {
+                Some(Ok(Message::Text(text))) => handle_message(&shared, &mut connection, &text),
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                // Pings are answered by tungstenite on the next write.
+                Some(Ok(_)) => Vec::new(),
+            },
+            event = live.recv() => match event {
+                Ok(event) => connection.subscriptions.iter()
+                    .filter(|(_, filters)| filters.iter().any(|f| f.match_event(&event)))
+                    .map(|(id, _)| RelayMessage::new_event(id.clone(), event.clone()))
+                    .collect(),
+                Err(broadcast::error::RecvError::Lagged(_)) => Vec::new(),
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+        };
+        for reply in replies {
+            if outgoing.send(Message::Text(reply.as_json())).await.is_err() { return; }
+        }
+    }
+    let _ = outgoing.close().await;
+}
+
+/// Applies one client message and returns the replies to send back.
+fn handle_message(shared: &Shared, connection: &mut Connection, text: &str) -> Vec<RelayMessage> {
+    let message = match ClientMessage::from_json(text) {
+        Ok(message) => message,
+        Err(e) => return vec![RelayMessage::new_notice(format!("error: could not parse message: {}", e))],
+    };
+    let admitted = connection.admitted(shared);
+    let subscriptions = &mut connection.subscriptions;
+    match message {
+        ClientMessage::Event(event) if !admitted => vec![RelayMessage::new_ok(event.id, false, "auth-required: answer the AUTH challenge first")],
+        ClientMessage::Req { subscription_id, .. } | ClientMessage::Count { subscription_id, .. } if !admitted => {
+            vec![RelayMessage::new_closed(subscription_id, "auth-required: answer the AUTH challenge first")]
+        },
+        ClientMessage::Event(event) => vec![accept_event(shared, *event)],
+        ClientMessage::Req { subscription_id, filters } => {
+            if !subscriptions.contains_key(&subscription_id) && subscriptions.len() >= MAX_SUBSCRIPTIONS {
+                return vec![RelayMessage::new_closed(subscription_id, "error: too many subscriptions")];
+            }
+            let stored = shared.store.lock().unwrap().query(&filters);
+            let mut replies: Vec<RelayMessage> = stored.into_iter()
+                .map(|event| RelayMessage::new_event(subscription_id.clone(), event))
+                .collect();
+            replies.push(RelayMessage::new_eose(subscription_id.clone()));
+            subscriptions.insert(subscription_id, filters);
+            replies
+        },
+        ClientMessage::Count { subscription_id, filters } => {
+            let count = shared.store.lock().unwrap().count(&filters);
+            vec![RelayMessage::new_count(subscription_id, count)]
+        },
+        ClientMessage::Close(subscription_id) => {
+            subscriptions.remove(&subscription_id);
+            Vec::new()
+        },
+        ClientMessage::Auth(event) => {
+            let id = event.id;
+            match check_auth(&event, &connection.challenge) {
+                Ok(()) => {
+                    connection.authenticated = Some(event.pubkey);
+                    vec![RelayMessage::new_ok(id, true, "")]
+                },
+                Err(reason) => vec![RelayMessage::new_ok(id, false, format!("auth-required: {}", reason))],
+            }
+        },
+        _ => vec![RelayMessage::new_notice("error: unsupported message")],
+    }
+}
+
+/// Checks a NIP-42 AUTH event against the connection's challenge.
+fn check_auth(event: &Event, challenge: &str) -> std::result::Result<(), &'static str> {
+    if event.verify().is_err() { return Err("bad id or signature"); }
+    if event.kind != Kind::Authentication { return Err("not an AUTH event"); }
+    if !event.tags.iter().any(|t| matches!(t, Tag::Challenge(c) if c == challenge)) { return Err("wrong challenge"); }
+    if !event.tags.iter().any(|t| matches!(t, Tag::Relay(_))) { return Err("missing relay tag"); }
+    let skew = Timestamp::now().as_u64().abs_diff(event.created_at.as_u64());
+    if skew > AUTH_WINDOW_SECS { return Err("stale AUTH event"); }
+    Ok(())
+}
+
+fn accept_event(shared: &Shared, event: Event) -> RelayMessage {
+    let id = event.id;
+    if event.verify().is_err() {
+        return RelayMessage::new_ok(id, false, "invalid: bad id or signature");
+    }
+    let outcome = shared.store.lock().unwrap().insert(event.clone());
+    match outcome {
+        Ok(StoreOutcome::Stored) => {
+            let _ = shared.live.send(event);
+            RelayMessage::new_ok(id, true, "")
+        },
+        Ok(StoreOutcome::Duplicate) => RelayMessage::new_ok(id, true, "duplicate: already have this event"),
+        Ok(StoreOutcome::Superseded) => RelayMessage::new_ok(id, true, "duplicate: a newer version is stored"),
+        Err(e) => RelayMessage::new_ok(id, false, format!("error: could not store event: {}", e)),
+    }
+}
@@ -0,0 +1,184 @@
+/**
+ * FILE: rust_core/src/relays.rs
+ * VERSION: 1.0.0
+ * PHASE: Phase 11.11 (Relay Management)
+ * PURPOSE: The user's relay set and per-relay health. Disconnected relays are
+ * retried with exponential backoff; relays that keep failing leave the write
+ * set until they recover. AUTH challenges and the identity that answered
+ * them are tracked per connection.
+ */
+use crate::domain::RelayConfig;
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+pub const DEFAULT_RELAYS: &[&str] = &["wss://relay.damus.io", "wss://relay.nostr.band"];
+const BASE_BACKOFF_SECS: u64 = 5;
+const MAX_BACKOFF_SECS: u64 = 300;
+/// Consecutive failed attempts after which a relay stops receiving writes.
+const DEMOTE_AFTER: u32 = 5;
+
+pub fn default_relays() -> Vec<RelayConfig> {
+    DEFAULT_RELAYS.iter().map(|url| RelayConfig { url: url.to_string(), read: true, write: true }).collect()
+}
+
+/// Trims and checks a relay URL; only websocket schemes are accepted.
+pub fn normalize_url(url: &str) -> Result<String> {
+    let url = url.trim().trim_end_matches('/');
+    let host = url.strip_prefix("wss://").or_else(|| url.strip_prefix("ws://"))
+        .ok_or_else(|| anyhow!("Relay URL must start with wss:// or ws://"))?;
+    if host.is_empty() || host.contains(char::is_whitespace) { return Err(anyhow!("Invalid relay URL: {}", url)); }
+    Ok(url.to_string())
+}
+
+/// Relay URLs as the pool reports them carry a trailing slash.
+pub fn same_relay(a: &str, b: &str) -> bool {
+    a.trim_end_matches('/') == b.trim_end_matches('/')
+}
+
+/// Health of one configured relay, as reported to the app.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RelayHealth {
+    pub url: String,
+    pub read: bool,
+    pub write: bool,
+    pub connected: bool,
+    pub latency_ms: Option<u64>,
+    pub last_error: Option<String>,
+    pub consecutive_failures: u32,
+    /// Unix time of the next reconnect attempt while disconnected.
+    pub next_retry_at: u64,
+    /// False for write relays demoted after repeated failures.
+    pub in_write_set: bool,
+    /// The relay sent a NIP-42 challenge on the current connection.
+    pub auth_requested: bool,
+    /// Identity id the connection is authenticated as.
+    pub authenticated_as: Option<String>,
+}
+
+#[derive(Default, Clone)]
+struct Health {
+    connected: bool,
+    latency_ms: Option<u64>,
+    last_error: Option<String>,
+    failures: u32,
+    backoff_secs: u64,
+    next_retry_at: u64,
+    challenge: Option<String>,
+    authenticated_as: Option<String>,
+}
+
+/// Configured relays and what has been observed about them.
+#[derive(Default)]
+pub struct RelayMonitor {
+    configs: Vec<RelayConfig>,
+    health: HashMap<String, Health>,
+}
+
+impl RelayMonitor {
+    /// Replaces the relay set, keeping the health of relays that stay.
+    pub fn configure(&mut self, configs: Vec<RelayConfig>) {
+        self.health.retain(|url, _| configs.iter().any(|c| same_relay(&c.url, url)));
+        self.configs = configs;
+    }
+
+    /// The configured set, or the defaults when nothing was configured.
+    pub fn configs(&self) -> Vec<RelayConfig> {
+        if self.configs.is_empty() { default_relays() } else { self.configs.clone() }
+    }
+
+    pub fn record_connected(&mut self, url: &str, latency_ms: Option<u64>) {
+        let health = self.entry(url);
+        health.connected = true;
+        health.latency_ms = latency_ms.or(health.latency_ms);
+        health.failures = 0;
+        health.backoff_secs = 0;
+        health.next_retry_at = 0;
+    }
+
+    /// Records a failed connection (or attempt) and schedules the next retry.
+    pub fn record_failure(&mut self, url: &str, error: &str, now: u64) {
+        let health = self.entry(url);
+        health.connected = false;
+        health.challenge = None;
+        health.authenticated_as = None;
+        health.last_error = Some(error.to_string());
+        health.failures += 1;
+        health.backoff_secs = (health.backoff_secs * 2).clamp(BASE_BACKOFF_SECS, MAX_BACKOFF_SECS);
+        health.next_retry_at = now + health.backoff_secs;
+    }
+
+    /// Remembers the latest challenge; a new one voids any earlier AUTH.
+    pub fn record_auth_challenge(&mut self, url: &str, challenge: &str) {
+        let health = self.entry(url);
+        health.challenge = Some(challenge.to_string());
+        health.authenticated_as = None;
+    }
+
+    /// Forgets the challenge and AUTH of a connection that was dropped on purpose.
+    pub fn record_auth_dropped(&mut self, url: &str) {
+        let health = self.entry(url);
+        health.challenge = None;
+        health.authenticated_as = None;
+    }
+
+    pub fn auth_challenge(&self, url: &str) -> Option<String> {
+        self.lookup(url).and_then(|h| h.challenge.clone())
+    }
+
+    /// Records the outcome of answering `challenge`; ignored if the relay
+    /// has issued a newer one since.
+    pub fn record_auth(&mut self, url: &str, challenge: &str, identity_id: &str, result: Result<(), String>) {
+        let health = self.entry(url);
+        if health.challenge.as_deref() != Some(challenge) { return; }
+        match result {
+            Ok(()) => health.authenticated_as = Some(identity_id.to_string()),
+            Err(error) => {
+                health.authenticated_as = None;
+                health.last_error = Some(error);
+            },
+        }
+    }
+
+    pub fn should_retry(&self, url: &str, now: u64) -> bool {
+        self.lookup(url).is_none_or(|h| h.next_retry_at <= now)
+    }
+
+    /// Write-flagged and not failing. If every write relay is failing, all
+    /// of them stay in the set rather than leaving it empty.
+    pub fn is_writable(&self, url: &str) -> bool {
+        let configs = self.configs();
+        let Some(config) = configs.iter().find(|c| same_relay(&c.url, url)) else { return false };
+        if !config.write { return false; }
+        let healthy = |c: &RelayConfig| self.lookup(&c.url).is_none_or(|h| h.failures < DEMOTE_AFTER);
+        healthy(config) || !configs.iter().any(|c| c.write && healthy(c))
+    }
+
+    pub fn report(&self) -> Vec<RelayHealth> {
+        self.configs().into_iter().map(|config| {
+            let health = self.lookup(&config.url).cloned().unwrap_or_default();
+            RelayHealth {
+                in_write_set: self.is_writable(&config.url),
+                url: config.url,
+                read: config.read,
+                write: config.write,
+                connected: health.connected,
+                latency_ms: health.latency_ms,
+                last_error: health.last_error,
+                consecutive_failures: health.failures,
+                next_retry_at: health.next_retry_at,
+                auth_requested: health.challenge.is_some(),
+                authenticated_as: health.authenticated_as,
+            }
+        }).collect()
+    }
+
+    fn lookup(&self, url: &str) -> Option<&Health> {
+        self.health.iter().find(|(known, _)| same_relay(known, url)).map(|(_, h)| h)
+    }
+
+    fn entry(&mut self, url: &str) -> &mut Health {
+        let key = url.trim_end_matches('/').to_string();
+        self.health.entry(key).or_default()
+    }
+}
@@ -0,0 +1,145 @@
+/**
+ * FILE: rust_core/src/revocation.rs
+ * VERSION: 1.0.0
+ * PHASE: Phase 11.7 (Revocation Lists)
+ * PURPOSE: Signed revocation lists and the local cache every verification
+ * consults. Merging is monotonic: a later list can add revocations, never lift them.
+ */
+use crate::crypto::{VaultKey, sign_with_key, verify_with_key, public_key_from_secret};
+use crate::domain::{KeyRecord, ListSignature, RevocationList, RevokedKey, PROTOCOL_VERSION};
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+
+/// What is known to be revoked for one DID.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct RevocationState {
+    pub did: String,
+    /// Pinned from the first accepted list; may sign later lists on its own.
+    pub recovery_public_key_hex: String,
+    pub revoked_keys: Vec<RevokedKey>,
+    pub identity_revoked_at: Option<u64>,
+}
+
+impl RevocationState {
+    /// Whether a signature by `public_key_hex` made at `timestamp` is void.
+    pub fn is_revoked(&self, public_key_hex: &str, timestamp: u64) -> bool {
+        self.identity_revoked_at.is_some_and(|at| timestamp >= at)
+            || self.revoked_keys.iter().any(|k| k.public_key_hex == public_key_hex && timestamp >= k.revoked_at)
+    }
+}
+
+/// Per-DID revocation key, derived from the seed so it survives a
+/// compromised identity key and comes back with a restore.
+pub fn recovery_key(master_seed: &[u8], did: &str) -> Result<Vec<u8>> {
+    Ok(VaultKey::from_bytes(master_seed)?.chain("satya_revocation_v1", did.as_bytes())?.as_bytes().to_vec())
+}
+
+/// Builds and signs a list with every key in `signing_keys`.
+pub fn sign_list(state: &RevocationState, recovery_key: &[u8], signing_keys: &[&[u8]], issued_at: u64) -> Result<RevocationList> {
+    let mut list = RevocationList {
+        version: PROTOCOL_VERSION.to_string(),
+        did: state.did.clone(),
+        issued_at,
+        revoked_keys: state.revoked_keys.clone(),
+        identity_revoked_at: state.identity_revoked_at,
+        recovery_public_key_hex: hex::encode(public_key_from_secret(recovery_key)?),
+        signatures: Vec::new(),
+    };
+    let message = signed_bytes(&list)?;
+    list.signatures = signing_keys.iter()
+        .map(|key| Ok(ListSignature {
+            public_key_hex: hex::encode(public_key_from_secret(key)?),
+            signature_hex: hex::encode(sign_with_key(key, &message)?),
+        }))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(list)
+}
+
+fn signed_bytes(list: &RevocationList) -> Result<Vec<u8>> {
+    let unsigned = RevocationList { signatures: Vec::new(), ..list.clone() };
+    Ok(serde_json::to_vec(&unsigned)?)
+}
+
+/// Revocation states by DID, persisted next to the vault. Public data, so
+/// stored in the clear.
+pub struct RevocationCache {
+    path: PathBuf,
+    states: HashMap<String, RevocationState>,
+}
+
+impl RevocationCache {
+    pub fn new(base_path: &Path) -> Self {
+        let mut path = base_path.to_path_buf();
+        path.push("satya_vault/revocations.json");
+        let states = fs::read(&path).ok()
+            .and_then(|raw| serde_json::from_slice(&raw).ok())
+            .unwrap_or_default();
+        Self { path, states }
+    }
+
+    /// In-memory cache for callers without a vault (nothing is persisted).
+    pub fn ephemeral() -> Self {
+        Self { path: PathBuf::new(), states: HashMap::new() }
+    }
+
+    pub fn state(&self, did: &str) -> RevocationState {
+        self.states.get(did).cloned().unwrap_or_else(|| RevocationState { did: did.to_string(), ..Default::default() })
+    }
+
+    /// Verifies a list and folds it in. It must be signed by the recovery key
+    /// pinned from an earlier list or by a key of `history`, which has to be
+    /// anchored (own, pinned or self-certifying): nothing unanchored is ever
+    /// written. Returns whether anything changed.
+    pub fn merge(&mut self, list: &RevocationList, history: &[KeyRecord]) -> Result<bool> {
+        let mut state = self.state(&list.did);
+        if history.is_empty() && state.recovery_public_key_hex.is_empty() {
+            return Err(anyhow!("No anchored key for {}", list.did));
+        }
+        let message = signed_bytes(list)?;
+        let authorized = list.signatures.iter().any(|sig| {
+            let trusted = if !state.recovery_public_key_hex.is_empty() && sig.public_key_hex == state.recovery_public_key_hex {
+                true
+            } else {
+                history.iter().any(|k| k.public_key_hex == sig.public_key_hex)
+                    && !state.is_revoked(&sig.public_key_hex, list.issued_at)
+            };
+            trusted && hex::decode(&sig.public_key_hex).ok()
+                .zip(hex::decode(&sig.signature_hex).ok())
+                .is_some_and(|(key, signature)| verify_with_key(&key, &message, &signature).is_ok())
+        });
+        if !authorized { return Err(anyhow!("Revocation list not signed by {}", list.did)); }
+
+        let before = serde_json::to_vec(&state)?;
+        if state.recovery_public_key_hex.is_empty() {
+            state.recovery_public_key_hex = list.recovery_public_key_hex.clone();
+        }
+        for revoked in &list.revoked_keys {
+            match state.revoked_keys.iter_mut().find(|k| k.public_key_hex == revoked.public_key_hex) {
+                Some(known) => known.revoked_at = known.revoked_at.min(revoked.revoked_at),
+                None => state.revoked_keys.push(revoked.clone()),
+            }
+        }
+        if let Some(at) = list.identity_revoked_at {
+            state.identity_revoked_at = Some(state.identity_revoked_at.map_or(at, |known| known.min(at)));
+        }
+        if serde_json::to_vec(&state)? == before { return Ok(false); }
+        self.states.insert(list.did.clone(), state);
+        self.store()?;
+        Ok(true)
+    }
+
+    fn store(&self) -> Result<()> {
+        if self.path.as_os_str().is_empty() { return Ok(()); }
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let tmp_path = self.path.with_extension("tmp");
+        fs::write(&tmp_path, serde_json::to_vec(&self.states)?)?;
+        File::open(&tmp_path)?.sync_all()?;
+        fs::rename(&tmp_path, &self.path)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+/**
+ * FILE: rust_core/src/rotation.rs
+ * VERSION: 1.0.0
+ * PHASE: Phase 11.6 (Key Rotation)
+ * PURPOSE: Key rotation statements and key histories. A DID keeps its name
+ * while its signing key changes; verifiers pick the key valid at signing time.
+ */
+use crate::crypto::{sign_with_key, verify_with_key, public_key_from_secret};
+use crate::domain::{KeyRecord, KeyRotation, SignedIntent, PROTOCOL_VERSION};
+use crate::revocation::RevocationState;
+use anyhow::{Result, anyhow};
+
+/// Builds a rotation statement signed by both the outgoing and incoming key.
+pub fn sign_rotation(did: &str, old_key: &[u8], new_key: &[u8], rotated_at: u64) -> Result<KeyRotation> {
+    let mut statement = KeyRotation {
+        version: PROTOCOL_VERSION.to_string(),
+        did: did.to_string(),
+        old_public_key_hex: hex::encode(public_key_from_secret(old_key)?),
+        new_public_key_hex: hex::encode(public_key_from_secret(new_key)?),
+        rotated_at,
+        old_signature_hex: String::new(),
+        new_signature_hex: String::new(),
+    };
+    let message = signed_bytes(&statement)?;
+    statement.old_signature_hex = hex::encode(sign_with_key(old_key, &message)?);
+    statement.new_signature_hex = hex::encode(sign_with_key(new_key, &message)?);
+    Ok(statement)
+}
+
+pub fn verify_rotation(statement: &KeyRotation) -> Result<()> {
+    let message = signed_bytes(statement)?;
+    verify_with_key(&hex::decode(&statement.old_public_key_hex)?, &message, &hex::decode(&statement.old_signature_hex)?)?;
+    verify_with_key(&hex::decode(&statement.new_public_key_hex)?, &message, &hex::decode(&statement.new_signature_hex)?)?;
+    Ok(())
+}
+
+/// Rebuilds a DID's key history from its rotation statements, starting at
+/// `anchor`, a key the verifier already trusts for the DID. Every statement
+/// is signed by both keys, so the chain is followed outwards from the anchor
+/// one endorsed neighbour at a time. Invalid, foreign, revoked-key and
+/// unlinked statements are ignored. Where a key rotated to (or from) two
+/// different keys the chain is forked: it stops there, and a key with two
+/// successors stays valid only until the earlier of them.
+pub fn history_from_rotations(did: &str, anchor: &str, rotations: &[KeyRotation], revocations: &RevocationState) -> Vec<KeyRecord> {
+    let mut statements: Vec<&KeyRotation> = rotations.iter()
+        .filter(|r| r.did == did && r.old_public_key_hex != r.new_public_key_hex && verify_rotation(r).is_ok())
+        .filter(|r| !revocations.is_revoked(&r.old_public_key_hex, r.rotated_at))
+        .collect();
+    statements.sort_by_key(|r| r.rotated_at);
+    // Republished copies of a statement: keep the earliest.
+    let mut links: Vec<&KeyRotation> = Vec::new();
+    for statement in statements {
+        if !links.iter().any(|l| l.old_public_key_hex == statement.old_public_key_hex && l.new_public_key_hex == statement.new_public_key_hex) {
+            links.push(statement);
+        }
+    }
+
+    let mut keys = vec![anchor.to_string()];
+    let mut earlier: Vec<&KeyRotation> = Vec::new();
+    loop {
+        let into: Vec<&KeyRotation> = links.iter().copied().filter(|l| l.new_public_key_hex == keys[0]).collect();
+        let [into] = into[..] else { break };
+        if keys.contains(&into.old_public_key_hex) || earlier.last().is_some_and(|next| into.rotated_at >= next.rotated_at) { break; }
+        keys.insert(0, into.old_public_key_hex.clone());
+        earlier.push(into);
+    }
+    earlier.reverse();
+
+    let mut later: Vec<&KeyRotation> = Vec::new();
+    let mut forked_at = None;
+    loop {
+        let tip = keys[keys.len() - 1].clone();
+        let out: Vec<&KeyRotation> = links.iter().copied().filter(|l| l.old_public_key_hex == tip).collect();
+        match out[..] {
+            [] => break,
+            [next] if !keys.contains(&next.new_public_key_hex)
+                && earlier.iter().chain(later.iter()).last().is_none_or(|prev| next.rotated_at > prev.rotated_at) => {
+                keys.push(next.new_public_key_hex.clone());
+                later.push(next);
+            },
+            _ => {
+                forked_at = out.iter().map(|l| l.rotated_at).min();
+                break;
+            },
+        }
+    }
+
+    let steps: Vec<&KeyRotation> = earlier.into_iter().chain(later).collect();
+    let mut history: Vec<KeyRecord> = keys.iter().enumerate()
+        .map(|(i, key)| record(key, if i == 0 { 0 } else { steps[i - 1].rotated_at }))
+        .collect();
+    for (i, step) in steps.iter().enumerate() {
+        history[i].valid_until = Some(step.rotated_at);
+    }
+    if let Some(last) = history.last_mut() {
+        last.valid_until = last.valid_until.or(forked_at);
+    }
+    history
+}
+
+/// The key that was valid at `timestamp`.
+pub fn key_at(history: &[KeyRecord], timestamp: u64) -> Option<&KeyRecord> {
+    history.iter().find(|k| k.valid_from <= timestamp && k.valid_until.is_none_or(|until| timestamp < until))
+}
+
+/// Checks an intent's signature: the signing key must be the one valid at
+/// the intent's timestamp. An empty history (nothing anchors the signer's
+/// DID) never verifies, and neither do revoked keys.
+pub fn verify_intent(intent: &SignedIntent, history: &[KeyRecord], revocations: &RevocationState) -> Result<bool> {
+    if history.is_empty() { return Ok(false); }
+    let key = key_at(history, intent.payload.timestamp).ok_or_else(|| anyhow!("No key valid at signing time"))?;
+    if !intent.signer_public_key_hex.is_empty() && intent.signer_public_key_hex != key.public_key_hex {
+        return Ok(false);
+    }
+    let key_hex = key.public_key_hex.as_str();
+    if revocations.is_revoked(key_hex, intent.payload.timestamp) { return Ok(false); }
+    let message = serde_json::to_string(&intent.payload)?;
+    Ok(verify_with_key(&hex::decode(key_hex)?, message.as_bytes(), &hex::decode(&intent.signature_hex)?).is_ok())
+}
+
+fn signed_bytes(statement: &KeyRotation) -> Result<Vec<u8>> {
+    let unsigned = KeyRotation { old_signature_hex: String::new(), new_signature_hex: String::new(), ..statement.clone() };
+    Ok(serde_json::to_vec(&unsigned)?)
+}
+
+fn record(public_key_hex: &str, valid_from: u64) -> KeyRecord {
+    KeyRecord { public_key_hex: public_key_hex.to_string(), derivation_path: String::new(), valid_from, valid_until: None }
+}
@@ -4,7 +4,6 @@
  * PHASE: Phase 7
  * DESCRIPTION: Helper logic for creating protocol-compliant payloads.
  */
-
 use crate::domain::{IntentPayload, InteractionType, PROTOCOL_VERSION, UpiIntent};
 use std::time::{SystemTime, UNIX_EPOCH};
 use anyhow::Result;
@@ -0,0 +1,77 @@
+/**
+ * FILE: rust_core/src/shamir.rs
+ * VERSION: 1.0.0
+ * PHASE: Phase 11.3 (Social Recovery)
+ * PURPOSE: Shamir secret sharing over GF(256) with the Rijndael polynomial,
+ * byte-wise. Shares are points (x, y) of a random polynomial with f(0) = secret.
+ */
+use anyhow::{Result, anyhow};
+
+/// Splits `secret` into `count` shares at x = 1..=count; any `threshold` recombine.
+pub fn split(secret: &[u8], threshold: u8, count: u8) -> Result<Vec<(u8, Vec<u8>)>> {
+    if threshold == 0 || threshold > count { return Err(anyhow!("Threshold must be between 1 and {}", count)); }
+    if count == 255 { return Err(anyhow!("At most 254 shares")); }
+    // f(0) = secret plus threshold - 1 random points fix the polynomial.
+    let mut base = vec![(0u8, secret.to_vec())];
+    base.extend((1..threshold).map(|x| (x, (0..secret.len()).map(|_| rand::random::<u8>()).collect())));
+    (1..=count)
+        .map(|x| match base.iter().find(|(bx, _)| *bx == x) {
+            Some(point) => Ok(point.clone()),
+            None => Ok((x, interpolate(&base, x)?)),
+        })
+        .collect()
+}
+
+/// Recovers f(0) from `threshold` or more shares.
+pub fn combine(shares: &[(u8, Vec<u8>)]) -> Result<Vec<u8>> {
+    interpolate(shares, 0)
+}
+
+/// Lagrange interpolation of the polynomial through `points`, evaluated at `x`.
+pub fn interpolate(points: &[(u8, Vec<u8>)], x: u8) -> Result<Vec<u8>> {
+    let len = points.first().map(|(_, y)| y.len()).ok_or_else(|| anyhow!("No shares"))?;
+    if points.iter().any(|(_, y)| y.len() != len) { return Err(anyhow!("Share lengths differ")); }
+    for (i, (xi, _)) in points.iter().enumerate() {
+        if points[..i].iter().any(|(xj, _)| xj == xi) { return Err(anyhow!("Duplicate share index {}", xi)); }
+    }
+    if let Some((_, y)) = points.iter().find(|(xi, _)| *xi == x) { return Ok(y.clone()); }
+
+    let mut result = vec![0u8; len];
+    for (i, (xi, yi)) in points.iter().enumerate() {
+        let mut basis = 1u8;
+        for (j, (xj, _)) in points.iter().enumerate() {
+            if i != j {
+                basis = mul(basis, mul(x ^ xj, inv(xi ^ xj)));
+            }
+        }
+        for (r, y) in result.iter_mut().zip(yi) {
+            *r ^= mul(basis, *y);
+        }
+    }
+    Ok(result)
+}
+
+fn mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0u8;
+    while b != 0 {
+        if b & 1 != 0 { product ^= a; }
+        let carry = a & 0x80;
+        a <<= 1;
+        if carry != 0 { a ^= 0x1b; }
+        b >>= 1;
+    }
+    product
+}
+
+/// a^254 = a^-1 in GF(256).
+fn inv(a: u8) -> u8 {
+    let mut result = 1u8;
+    let mut base = a;
+    let mut exp = 254u8;
+    while exp != 0 {
+        if exp & 1 != 0 { result = mul(result, base); }
+        base = mul(base, base);
+        exp >>= 1;
+    }
+    result
+}
@@ -0,0 +1,278 @@
+/**
+ * FILE: rust_core/src/slip39.rs
+ * VERSION: 1.0.0
+ * PHASE: Phase 11.4 (Paper Backup)
+ * PURPOSE: SLIP-0039 Shamir mnemonic shares. Generation uses a single group;
+ * combination accepts any standard-conformant multi-group share set.
+ */
+use crate::shamir;
+use anyhow::{Result, anyhow};
+use hmac::Mac;
+use once_cell::sync::Lazy;
+use sha2::Sha256;
+use std::num::NonZeroU32;
+
+static WORDLIST: Lazy<Vec<&'static str>> = Lazy::new(|| include_str!("slip39_english.txt").lines().collect());
+
+const RADIX_BITS: usize = 10;
+const ID_BITS: usize = 15;
+const METADATA_BITS: usize = 40;
+const CHECKSUM_WORDS: usize = 3;
+const MIN_WORDS: usize = 20;
+const SECRET_INDEX: u8 = 255;
+const DIGEST_INDEX: u8 = 254;
+const DIGEST_LEN: usize = 4;
+const ROUNDS: u8 = 4;
+const BASE_ITERATIONS: u32 = 10_000;
+const MAX_SHARES: u8 = 16;
+
+/// One decoded SLIP-39 mnemonic.
+#[derive(Clone, Debug)]
+pub struct Share {
+    pub identifier: u16,
+    pub extendable: bool,
+    pub iteration_exponent: u8,
+    pub group_index: u8,
+    pub group_threshold: u8,
+    pub group_count: u8,
+    pub member_index: u8,
+    pub member_threshold: u8,
+    pub value: Vec<u8>,
+}
+
+impl Share {
+    pub fn from_mnemonic(mnemonic: &str) -> Result<Self> {
+        let words = mnemonic.split_whitespace()
+            .map(|w| {
+                let w = w.to_lowercase();
+                WORDLIST.binary_search(&w.as_str()).map(|i| i as u16).map_err(|_| anyhow!("Unknown word: {}", w))
+            })
+            .collect::<Result<Vec<u16>>>()?;
+        if words.len() < MIN_WORDS { return Err(anyhow!("Share too short: {} words", words.len())); }
+
+        let extendable = words[1] & (1 << 4) != 0;
+        if rs1024_polymod(customization(extendable), &words) != 1 {
+            return Err(anyhow!("Invalid share checksum"));
+        }
+
+        let bits = to_bits(&words[..words.len() - CHECKSUM_WORDS]);
+        let value_bits = bits.len() - METADATA_BITS;
+        let padding = value_bits % 16;
+        if padding > 8 || bits[METADATA_BITS..METADATA_BITS + padding].iter().any(|b| *b) {
+            return Err(anyhow!("Invalid share padding"));
+        }
+        let value = bits[METADATA_BITS + padding..]
+            .chunks(8)
+            .map(|byte| read(byte) as u8)
+            .collect();
+
+        let group_threshold = read(&bits[24..28]) as u8 + 1;
+        let group_count = read(&bits[28..32]) as u8 + 1;
+        if group_threshold > group_count { return Err(anyhow!("Group threshold exceeds group count")); }
+        Ok(Share {
+            identifier: read(&bits[..ID_BITS]) as u16,
+            extendable,
+            iteration_exponent: read(&bits[16..20]) as u8,
+            group_index: read(&bits[20..24]) as u8,
+            group_threshold,
+            group_count,
+            member_index: read(&bits[32..36]) as u8,
+            member_threshold: read(&bits[36..40]) as u8 + 1,
+            value,
+        })
+    }
+
+    pub fn to_mnemonic(&self) -> String {
+        let mut bits = Vec::new();
+        write(&mut bits, self.identifier as u32, ID_BITS);
+        write(&mut bits, self.extendable as u32, 1);
+        write(&mut bits, self.iteration_exponent as u32, 4);
+        write(&mut bits, self.group_index as u32, 4);
+        write(&mut bits, self.group_threshold as u32 - 1, 4);
+        write(&mut bits, self.group_count as u32 - 1, 4);
+        write(&mut bits, self.member_index as u32, 4);
+        write(&mut bits, self.member_threshold as u32 - 1, 4);
+        let padding = (RADIX_BITS - (self.value.len() * 8) % RADIX_BITS) % RADIX_BITS;
+        bits.extend(std::iter::repeat_n(false, padding));
+        for byte in &self.value {
+            write(&mut bits, *byte as u32, 8);
+        }
+
+        let mut words: Vec<u16> = bits.chunks(RADIX_BITS).map(|w| read(w) as u16).collect();
+        let mut padded = words.clone();
+        padded.extend([0; CHECKSUM_WORDS]);
+        let checksum = rs1024_polymod(customization(self.extendable), &padded) ^ 1;
+        words.extend((0..CHECKSUM_WORDS).rev().map(|i| ((checksum >> (RADIX_BITS * i)) & 1023) as u16));
+        words.iter().map(|w| WORDLIST[*w as usize]).collect::<Vec<_>>().join(" ")
+    }
+}
+
+/// Splits `master_secret` into one group of `count` shares, `threshold` to recover.
+pub fn generate(master_secret: &[u8], threshold: u8, count: u8, passphrase: &str, iteration_exponent: u8) -> Result<Vec<String>> {
+    if master_secret.len() < 16 || !master_secret.len().is_multiple_of(2) {
+        return Err(anyhow!("Master secret must be an even number of bytes, at least 16"));
+    }
+    if threshold == 0 || threshold > count || count > MAX_SHARES {
+        return Err(anyhow!("Invalid share scheme {}-of-{}", threshold, count));
+    }
+    if iteration_exponent > 15 { return Err(anyhow!("Iteration exponent too large")); }
+
+    let identifier = rand::random::<u16>() & ((1 << ID_BITS) - 1);
+    let extendable = true;
+    let encrypted = feistel(master_secret, passphrase, identifier, extendable, iteration_exponent, false)?;
+    Ok(split_secret(&encrypted, threshold, count)?
+        .into_iter()
+        .map(|(member_index, value)| Share {
+            identifier,
+            extendable,
+            iteration_exponent,
+            group_index: 0,
+            group_threshold: 1,
+            group_count: 1,
+            member_index,
+            member_threshold: threshold,
+            value,
+        }.to_mnemonic())
+        .collect())
+}
+
+/// Recovers the master secret from a sufficient set of share mnemonics.
+pub fn combine(mnemonics: &[String], passphrase: &str) -> Result<Vec<u8>> {
+    let shares = mnemonics.iter().map(|m| Share::from_mnemonic(m)).collect::<Result<Vec<_>>>()?;
+    let first = shares.first().ok_or_else(|| anyhow!("No shares"))?;
+    for share in &shares {
+        let consistent = share.identifier == first.identifier
+            && share.extendable == first.extendable
+            && share.iteration_exponent == first.iteration_exponent
+            && share.group_threshold == first.group_threshold
+            && share.group_count == first.group_count
+            && share.value.len() == first.value.len();
+        if !consistent { return Err(anyhow!("Shares belong to different backups")); }
+    }
+
+    let mut group_indices: Vec<u8> = shares.iter().map(|s| s.group_index).collect();
+    group_indices.sort_unstable();
+    group_indices.dedup();
+    if group_indices.len() < first.group_threshold as usize {
+        return Err(anyhow!("Need shares from {} groups, have {}", first.group_threshold, group_indices.len()));
+    }
+
+    let mut group_secrets = Vec::new();
+    for group_index in group_indices {
+        let members: Vec<&Share> = shares.iter().filter(|s| s.group_index == group_index).collect();
+        let threshold = members[0].member_threshold;
+        if members.iter().any(|m| m.member_threshold != threshold) {
+            return Err(anyhow!("Mismatching member thresholds in group {}", group_index));
+        }
+        if members.len() < threshold as usize { continue; }
+        let points: Vec<(u8, Vec<u8>)> = members.iter().map(|m| (m.member_index, m.value.clone())).collect();
+        group_secrets.push((group_index, recover_secret(threshold, &points)?));
+    }
+    if group_secrets.len() < first.group_threshold as usize {
+        return Err(anyhow!("Not enough complete groups"));
+    }
+
+    let encrypted = recover_secret(first.group_threshold, &group_secrets[..first.group_threshold as usize])?;
+    feistel(&encrypted, passphrase, first.identifier, first.extendable, first.iteration_exponent, true)
+}
+
+fn split_secret(secret: &[u8], threshold: u8, count: u8) -> Result<Vec<(u8, Vec<u8>)>> {
+    if threshold == 1 {
+        return Ok((0..count).map(|x| (x, secret.to_vec())).collect());
+    }
+    let random_part: Vec<u8> = (0..secret.len() - DIGEST_LEN).map(|_| rand::random()).collect();
+    let mut digest = hmac_sha256(&random_part, secret)?[..DIGEST_LEN].to_vec();
+    digest.extend_from_slice(&random_part);
+
+    let mut base: Vec<(u8, Vec<u8>)> = (0..threshold - 2)
+        .map(|x| (x, (0..secret.len()).map(|_| rand::random()).collect()))
+        .collect();
+    let mut shares = base.clone();
+    base.push((DIGEST_INDEX, digest));
+    base.push((SECRET_INDEX, secret.to_vec()));
+    for x in threshold - 2..count {
+        shares.push((x, shamir::interpolate(&base, x)?));
+    }
+    Ok(shares)
+}
+
+fn recover_secret(threshold: u8, points: &[(u8, Vec<u8>)]) -> Result<Vec<u8>> {
+    if threshold == 1 {
+        return points.first().map(|(_, v)| v.clone()).ok_or_else(|| anyhow!("No shares"));
+    }
+    let points = &points[..threshold as usize];
+    let secret = shamir::interpolate(points, SECRET_INDEX)?;
+    let digest = shamir::interpolate(points, DIGEST_INDEX)?;
+    if hmac_sha256(&digest[DIGEST_LEN..], &secret)?[..DIGEST_LEN] != digest[..DIGEST_LEN] {
+        return Err(anyhow!("Invalid digest of the shared secret"));
+    }
+    Ok(secret)
+}
+
+/// Four-round Feistel cipher keyed by PBKDF2-HMAC-SHA256 of the passphrase.
+fn feistel(data: &[u8], passphrase: &str, identifier: u16, extendable: bool, exponent: u8, decrypt: bool) -> Result<Vec<u8>> {
+    let half = data.len() / 2;
+    let (mut left, mut right) = (data[..half].to_vec(), data[half..].to_vec());
+    let mut salt_prefix = Vec::new();
+    if !extendable {
+        salt_prefix.extend_from_slice(b"shamir");
+        salt_prefix.extend_from_slice(&identifier.to_be_bytes());
+    }
+    let iterations = NonZeroU32::new((BASE_ITERATIONS << exponent) / ROUNDS as u32)
+        .ok_or_else(|| anyhow!("Invalid iteration count"))?;
+
+    for step in 0..ROUNDS {
+        let round = if decrypt { ROUNDS - 1 - step } else { step };
+        let mut password = vec![round];
+        password.extend_from_slice(passphrase.as_bytes());
+        let mut salt = salt_prefix.clone();
+        salt.extend_from_slice(&right);
+        let mut f = vec![0u8; half];
+        ring::pbkdf2::derive(ring::pbkdf2::PBKDF2_HMAC_SHA256, iterations, &salt, &password, &mut f);
+        let mixed: Vec<u8> = left.iter().zip(&f).map(|(a, b)| a ^ b).collect();
+        left = std::mem::replace(&mut right, mixed);
+    }
+    Ok([right, left].concat())
+}
+
+fn customization(extendable: bool) -> &'static [u8] {
+    if extendable { b"shamir_extendable" } else { b"shamir" }
+}
+
+fn rs1024_polymod(customization: &[u8], words: &[u16]) -> u32 {
+    const GEN: [u32; 10] = [
+        0xE0E040, 0x1C1C080, 0x3838100, 0x7070200, 0xE0E0009,
+        0x1C0C2412, 0x38086C24, 0x3090FC48, 0x21B1F890, 0x3F3F120,
+    ];
+    let mut chk: u32 = 1;
+    for value in customization.iter().map(|b| *b as u32).chain(words.iter().map(|w| *w as u32)) {
+        let top = chk >> 20;
+        chk = ((chk & 0xFFFFF) << 10) ^ value;
+        for (i, g) in GEN.iter().enumerate() {
+            if (top >> i) & 1 != 0 { chk ^= g; }
+        }
+    }
+    chk
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Result<[u8; 32]> {
+    let mut mac = <hmac::Hmac<Sha256> as Mac>::new_from_slice(key).map_err(|_| anyhow!("Digest Error"))?;
+    mac.update(data);
+    Ok(mac.finalize().into_bytes().into())
+}
+
+fn to_bits(words: &[u16]) -> Vec<bool> {
+    let mut bits = Vec::with_capacity(words.len() * RADIX_BITS);
+    for word in words {
+        write(&mut bits, *word as u32, RADIX_BITS);
+    }
+    bits
+}
+
+fn write(bits: &mut Vec<bool>, value: u32, width: usize) {
+    bits.extend((0..width).rev().map(|i| (value >> i) & 1 != 0));
+}
+
+fn read(bits: &[bool]) -> u32 {
+    bits.iter().fold(0, |acc, b| (acc << 1) | *b as u32)
+}
@@ -0,0 +1,53 @@
+/**
+ * FILE: rust_core/src/subscriptions.rs
+ * VERSION: 1.0.0
+ * PHASE: Phase 11.15 (Live Subscriptions)
+ * PURPOSE: What a live subscription listens for and the verified events it
+ * streams to the app: own intents echoed by relays (receipts), intents
+ * addressed to own identities (in the clear or gift wrapped), and rotations
+ * and revocations of watched DIDs.
+ */
+use crate::private::{PrivateIntent, KIND_GIFT_WRAP, MAX_BACKDATE_SECS};
+use crate::domain::{KeyRotation, RevocationList, SignedIntent, KIND_KEY_ROTATION, KIND_REVOCATION_LIST};
+use nostr_sdk::prelude::{Filter, Kind, Timestamp, XOnlyPublicKey};
+use serde::Serialize;
+
+pub const KIND_INTENT: u64 = 29001;
+
+/// One message on a subscription stream, serialized as JSON with a `type` tag.
+#[derive(Serialize, Clone, Debug)]
+#[serde(tag = "type")]
+pub enum LiveEvent {
+    /// Always first: the handle for `rust_unsubscribe_events`.
+    Subscribed { handle: String },
+    /// `receipt` is set for own intents as stored by a relay.
+    Intent { event_id: String, relay: String, receipt: bool, intent: SignedIntent },
+    /// A gift-wrapped intent opened with an own key.
+    PrivateIntent { relay: String, message: PrivateIntent },
+    Rotation { event_id: String, relay: String, statement: KeyRotation },
+    Revocation { event_id: String, relay: String, list: RevocationList },
+}
+
+/// Filters for events newer than `since`. `own_keys` are the Nostr keys of
+/// own identities and their pairwise DIDs.
+pub fn filters(own_keys: &[XOnlyPublicKey], watched_dids: &[String], since: u64) -> Vec<Filter> {
+    let since = Timestamp::from(since);
+    let mut filters = Vec::new();
+    if !own_keys.is_empty() {
+        let intents = Filter::new().kind(Kind::from(KIND_INTENT)).since(since);
+        filters.push(intents.clone().authors(own_keys.to_vec()));
+        filters.push(intents.pubkeys(own_keys.to_vec()));
+        // Wraps carry a randomized past timestamp.
+        filters.push(Filter::new()
+            .kind(Kind::from(KIND_GIFT_WRAP))
+            .pubkeys(own_keys.to_vec())
+            .since(Timestamp::from(since.as_u64().saturating_sub(MAX_BACKDATE_SECS))));
+    }
+    if !watched_dids.is_empty() {
+        filters.push(Filter::new()
+            .kinds(vec![Kind::from(KIND_KEY_ROTATION), Kind::from(KIND_REVOCATION_LIST)])
+            .identifiers(watched_dids.to_vec())
+            .since(since));
+    }
+    filters
+}
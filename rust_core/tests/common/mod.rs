@@ -0,0 +1,44 @@
+/**
+ * FILE: rust_core/tests/common/mod.rs
+ * VERSION: 1.0.0
+ * PHASE: Phase 11.16 (Embedded Relay)
+ * PURPOSE: Shared setup for the integration tests: a fresh vault in a
+ * temporary directory and a relay set holding only the test relay.
+ */
+use rust_core::api::*;
+use rust_core::relays::same_relay;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+pub const PIN: &str = "1234";
+pub const HW_ID: &str = "test-device-0001";
+
+/// Opens a new vault under a per-process temporary directory.
+pub fn open_vault(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("satya_{}_{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    rust_initialize_vault(PIN.into(), HW_ID.into(), dir.to_string_lossy().into_owned()).unwrap();
+    dir
+}
+
+/// Makes `url` the only relay, so nothing leaves the machine.
+pub fn use_only_relay(url: &str) {
+    rust_add_relay(url.to_string(), true, true).unwrap();
+    for relay in rust_list_relays().unwrap() {
+        if !same_relay(&relay.url, url) { rust_remove_relay(relay.url).unwrap(); }
+    }
+}
+
+/// Polls `done` until it holds, failing the test after ten seconds.
+pub fn wait_for(what: &str, mut done: impl FnMut() -> bool) {
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while !done() {
+        assert!(Instant::now() < deadline, "timed out waiting for {}", what);
+        std::thread::sleep(Duration::from_millis(50));
+    }
+}
+
+pub fn intent_url(amount: u32) -> String {
+    format!("upi://pay?pa=merchant@upi&pn=Merchant&am={}", amount)
+}
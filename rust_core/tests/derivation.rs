@@ -0,0 +1,95 @@
+/**
+ * FILE: rust_core/tests/derivation.rs
+ * VERSION: 1.0.0
+ * PHASE: Phase 11.10 (Identity Nostr Keys)
+ * PURPOSE: SLIP-0010 Ed25519 derivation against the published test vectors,
+ * and identities on legacy paths keeping the keys of the original scheme.
+ */
+use ed25519_dalek::{PublicKey, SecretKey};
+use rust_core::derivation::*;
+
+/// SLIP-0010 test vector 1 for ed25519: (path, private key).
+const VECTOR_1_SEED: &str = "000102030405060708090a0b0c0d0e0f";
+const VECTOR_1: &[(&str, &str)] = &[
+    ("m", "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7"),
+    ("m/0'", "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3"),
+    ("m/0'/1'", "b1d0bad404bf35da785a64ca1ac54b2617211d2777696fbffaf208f746ae84f2"),
+    ("m/0'/1'/2'", "92a5b23c0b8a99e37d07df3fb9966917f5d06e02ddbd909c7e184371463e9fc9"),
+    ("m/0'/1'/2'/2'", "30d1dc7e5fc04c31219ab25a27ae00b50f6fd66622f6e9c913253d6511d1e662"),
+    ("m/0'/1'/2'/2'/1000000000'", "8f94d394a8e8fd6b1bc2f3f49f5c47e385281d5c17e65324b0f62483e37e8793"),
+];
+
+/// SLIP-0010 test vector 2 for ed25519.
+const VECTOR_2_SEED: &str = "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542";
+const VECTOR_2: &[(&str, &str)] = &[
+    ("m", "171cb88b1b3c1db25add599712e36245d75bc65a1a5c9e18d76f9f2b1eab4012"),
+    ("m/0'", "1559eb2bbec5790b0c65d8693e4d0875b1747f4970ae8b650486ed7470845635"),
+    ("m/0'/2147483647'", "ea4f5bfe8694d8bb74b7b59404632fd5968b774ed545e810de9c32a4fb4192f4"),
+    ("m/0'/2147483647'/1'", "3757c7577170179c7868353ada796c839135b3d30554bbb74a4b1e4a5a58505c"),
+    ("m/0'/2147483647'/1'/2147483646'", "5837736c89570de861ebc173b1086da4f505d4adb387c6a1b1342d5e4ac9ec72"),
+    ("m/0'/2147483647'/1'/2147483646'/2'", "551d333177df541ad876a60ea71f00447931c0a9da16f227c11ea080d7391b8d"),
+];
+
+fn check_vector(seed_hex: &str, vector: &[(&str, &str)]) {
+    let seed = hex::decode(seed_hex).unwrap();
+    for (path, private_key) in vector {
+        let (key, _) = slip10_derive(&seed, path).unwrap();
+        assert_eq!(hex::encode(key), *private_key, "private key at {}", path);
+    }
+}
+
+#[test]
+fn slip10_vector_1() {
+    check_vector(VECTOR_1_SEED, VECTOR_1);
+
+    let seed = hex::decode(VECTOR_1_SEED).unwrap();
+    let (key, chain_code) = slip10_derive(&seed, "m").unwrap();
+    assert_eq!(hex::encode(chain_code), "90046a93de5380a72b5e45010748567d5ea02bbf6522f979e05c0d8d8ca9fffb");
+    let public_key = PublicKey::from(&SecretKey::from_bytes(&key).unwrap());
+    assert_eq!(hex::encode(public_key.as_bytes()), "a4b2856bfec510abab89753fac1ac0e1112364e7d250545963f135f2a33188ed");
+    let (_, chain_code) = slip10_derive(&seed, "m/0'").unwrap();
+    assert_eq!(hex::encode(chain_code), "8b59aa11380b624e81507a27fedda59fea6d0b779a778918a2fd3590e16e9c69");
+}
+
+#[test]
+fn slip10_vector_2() {
+    check_vector(VECTOR_2_SEED, VECTOR_2);
+}
+
+#[test]
+fn slip10_rejects_non_hardened_and_malformed_paths() {
+    let seed = hex::decode(VECTOR_1_SEED).unwrap();
+    assert!(slip10_derive(&seed, "m/0").is_err());
+    assert!(slip10_derive(&seed, "m/2147483648'").is_err());
+    assert!(slip10_derive(&seed, "0'/1'").is_err());
+    assert!(slip10_derive(&seed, "m/x'").is_err());
+}
+
+#[test]
+fn identity_paths_use_slip10() {
+    let seed: Vec<u8> = (0u8..64).collect();
+    let path = identity_path(3);
+    assert_eq!(path, "m/44'/7327'/3'");
+    let (expected, _) = slip10_derive(&seed, &path).unwrap();
+    assert_eq!(derive_identity_key(&seed, &path).unwrap(), expected.to_vec());
+    assert_eq!(nostr_path(&path).unwrap(), "m/44'/1237'/3'/0/0");
+}
+
+#[test]
+fn legacy_paths_keep_their_original_keys() {
+    // HMAC-SHA512(seed, "satya_identity_{index}")[..32], as derived before SLIP-0010.
+    let seed: Vec<u8> = (0u8..64).collect();
+    let original = [
+        (0, "9b5864f834f6df9ab9b0e04f5d2cc4eba69ca13c16a944e1d49025c4caf155ca"),
+        (3, "46f293ce0007faa8ac9f17795a73110dc9e4979f9694d5c27c6d0566ad7252da"),
+    ];
+    for (index, key) in original {
+        let path = legacy_path(index);
+        assert_eq!(hex::encode(derive_identity_key(&seed, &path).unwrap()), key);
+        assert_eq!(hex::encode(derive_legacy(&seed, index).unwrap()), key);
+        assert_ne!(derive_identity_key(&seed, &path).unwrap(), derive_identity_key(&seed, &identity_path(index)).unwrap());
+        // The Nostr key follows the index, whichever scheme the identity uses.
+        assert_eq!(nostr_path(&path).unwrap(), nostr_path(&identity_path(index)).unwrap());
+    }
+    assert!(derive_identity_key(&seed, "legacy/x").is_err());
+}
@@ -0,0 +1,64 @@
+/**
+ * FILE: rust_core/tests/discovery.rs
+ * VERSION: 1.0.0
+ * PHASE: Phase 11.10 (Identity Nostr Keys)
+ * PURPOSE: A restore that discovers pre-11.1 identities on `legacy/i` paths
+ * must not hand their indices, and with them their npubs, out again.
+ */
+#[allow(dead_code)]
+mod common;
+
+use common::*;
+use nostr_sdk::prelude::{EventBuilder, Keys, Kind, Tag};
+use rust_core::api::*;
+use rust_core::backup::mnemonic_to_seed;
+use rust_core::derivation::{derive_identity_key, identity_path, legacy_path};
+use rust_core::discovery::{seal_profile, ProfileState, PROFILE_KIND};
+use rust_core::domain::SatyaIdentity;
+use rust_core::relay_server::EventStore;
+
+const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+    abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art";
+
+#[test]
+fn new_identities_skip_the_indices_of_restored_legacy_ones() {
+    let dir = std::env::temp_dir().join(format!("satya_discovery_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let storage_path = dir.to_string_lossy().into_owned();
+
+    // Profiles the pre-11.1 app published for its first two identities.
+    let seed = mnemonic_to_seed(MNEMONIC, None).unwrap();
+    let events_path = dir.join("satya_relay/events.jsonl");
+    std::fs::create_dir_all(events_path.parent().unwrap()).unwrap();
+    let mut store = EventStore::open(&events_path).unwrap();
+    for index in 0..2 {
+        let identity = SatyaIdentity {
+            id: format!("legacy-{}", index),
+            label: format!("Legacy {}", index),
+            did: format!("did:satya:legacy-{}", index),
+            derivation_path: legacy_path(index),
+            ..Default::default()
+        };
+        let key = derive_identity_key(&seed, &identity.derivation_path).unwrap();
+        let (locator, content) = seal_profile(&seed, &identity, &key, ProfileState::Live).unwrap();
+        let event = EventBuilder::new(Kind::from(PROFILE_KIND), content, vec![Tag::Identifier(locator)])
+            .to_event(&Keys::generate()).unwrap();
+        store.insert(event).unwrap();
+    }
+    drop(store);
+
+    let url = rust_start_local_relay("127.0.0.1:0".into(), Some(storage_path.clone())).unwrap();
+    rust_restore_from_mnemonic(MNEMONIC.into(), None, PIN.into(), HW_ID.into(), storage_path.clone()).unwrap();
+    use_only_relay(&url);
+    let restored = rust_discover_identities(Some(3)).unwrap();
+    assert_eq!(restored.len(), 2);
+
+    let created = rust_create_identity("New".into()).unwrap();
+    assert_eq!(created.derivation_path, identity_path(2));
+    let npub = rust_get_nostr_public_key(created.id).unwrap();
+    for legacy in restored {
+        assert_ne!(rust_get_nostr_public_key(legacy.id).unwrap(), npub, "{} shares its npub", legacy.derivation_path);
+    }
+
+    rust_reset_vault(storage_path).unwrap();
+}
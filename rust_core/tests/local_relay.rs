@@ -0,0 +1,140 @@
+/**
+ * FILE: rust_core/tests/local_relay.rs
+ * VERSION: 1.0.0
+ * PHASE: Phase 11.16 (Embedded Relay)
+ * PURPOSE: Drives the API against the embedded relay: publishing, history
+ * paging and live subscriptions, plus the relay's own COUNT handling.
+ */
+#[allow(dead_code)]
+mod common;
+
+use common::*;
+use flutter_rust_bridge::rust2dart::Rust2Dart;
+use flutter_rust_bridge::ffi::ffi::{DartCObject, DartCObjectType};
+use flutter_rust_bridge::StreamSink;
+use nostr_sdk::prelude::{EventBuilder, Filter, Keys, Kind, ToBech32};
+use rust_core::api::*;
+use rust_core::discovery::PROFILE_KIND;
+use rust_core::domain::{SignedIntent, KIND_DID_DOCUMENT, KIND_REVOCATION_LIST};
+use rust_core::history::HistoryQuery;
+use rust_core::relay_server::EventStore;
+use serde_json::Value;
+use std::ffi::CStr;
+use std::sync::Mutex;
+
+/// Strings sent to the "Dart" side of a stream, in order.
+static POSTED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Stands in for `Dart_PostCObject`: keeps the payload of each successful
+/// stream message (`[0, "<json>"]`).
+unsafe extern "C" fn capture_post(_port: i64, message: *mut DartCObject) -> bool {
+    let message = &*message;
+    if !matches!(message.ty, DartCObjectType::DartArray) { return true; }
+    let array = message.value.as_array;
+    let values = std::slice::from_raw_parts(array.values, array.length as usize);
+    if let [action, payload] = values {
+        let (action, payload) = (&**action, &**payload);
+        if matches!(payload.ty, DartCObjectType::DartString) && action.value.as_int32 == 0 {
+            let text = CStr::from_ptr(payload.value.as_string).to_string_lossy().into_owned();
+            POSTED.lock().unwrap().push(text);
+        }
+    }
+    true
+}
+
+fn posted_of_type(kind: &str) -> Vec<Value> {
+    POSTED.lock().unwrap().iter()
+        .filter_map(|text| serde_json::from_str::<Value>(text).ok())
+        .filter(|value| value["type"] == kind)
+        .collect()
+}
+
+#[test]
+fn publishes_pages_and_streams_through_the_local_relay() {
+    let dir = open_vault("local_relay");
+    let url = rust_start_local_relay("127.0.0.1:0".into(), Some(dir.to_string_lossy().into_owned())).unwrap();
+    use_only_relay(&url);
+    let identity = rust_create_identity("Shop".into()).unwrap();
+    assert!(rust_publish_did_document(identity.id.clone()).unwrap());
+
+    // SAFETY: `capture_post` only reads the message it is handed.
+    unsafe { flutter_rust_bridge::store_dart_post_cobject(capture_post) };
+    rust_subscribe_events(Vec::new(), StreamSink::new(Rust2Dart::new(1))).unwrap();
+    let handle = posted_of_type("Subscribed")[0]["handle"].as_str().unwrap().to_string();
+
+    let mut published = Vec::new();
+    for amount in [10, 20, 30] {
+        let signed = rust_sign_intent(identity.id.clone(), intent_url(amount)).unwrap();
+        let result = rust_publish_intent(signed).unwrap();
+        assert!(result.success, "publish failed: {:?}", result.outcomes);
+        assert!(!result.queued);
+        published.push(result.event_id);
+    }
+
+    // Live: every intent arrives once, verified and marked as a relay receipt.
+    wait_for("live intents", || posted_of_type("Intent").len() >= published.len());
+    let live = posted_of_type("Intent");
+    assert_eq!(live.len(), published.len());
+    for message in &live {
+        assert!(published.iter().any(|id| message["event_id"] == id.as_str()));
+        assert_eq!(message["receipt"], true);
+        assert_eq!(message["intent"]["is_verified"], true);
+    }
+    assert!(rust_unsubscribe_events(handle.clone()).unwrap());
+    assert!(!rust_unsubscribe_events(handle).unwrap());
+
+    // History: pages of two, newest first, until the cursor runs out.
+    let mut cursor = None;
+    let mut seen = Vec::new();
+    loop {
+        let query = HistoryQuery { identity_ids: vec![identity.id.clone()], page_size: Some(2), cursor, timeout_secs: Some(5), ..Default::default() };
+        let page = rust_query_interaction_history(query).unwrap();
+        assert!(page.items.len() <= 2);
+        for item in &page.items {
+            assert!(item.intent.is_verified);
+            assert_eq!(item.intent.signer_did, identity.did);
+            seen.push(item.event_id.clone());
+        }
+        cursor = page.next_cursor;
+        if cursor.is_none() { break; }
+        assert!(seen.len() <= published.len(), "history does not terminate");
+    }
+    seen.sort();
+    published.sort();
+    assert_eq!(seen, published);
+
+    let latest: Vec<SignedIntent> = rust_fetch_interaction_history().unwrap().iter()
+        .map(|json| serde_json::from_str(json).unwrap())
+        .collect();
+    assert_eq!(latest.len(), published.len());
+    assert!(latest.iter().all(|intent| intent.is_verified));
+
+    assert!(rust_stop_local_relay().unwrap());
+
+    // The profile, revocation list and DID Document are authored by the identity, like its intents.
+    let npub = rust_get_nostr_public_key(identity.id).unwrap();
+    let store = EventStore::open(&dir.join("satya_relay/events.jsonl")).unwrap();
+    let events = store.query(&[Filter::new()]);
+    for kind in [PROFILE_KIND, KIND_REVOCATION_LIST, KIND_DID_DOCUMENT] {
+        assert!(events.iter().any(|e| e.kind.as_u64() == kind), "no event of kind {}", kind);
+    }
+    for event in events {
+        assert_eq!(event.pubkey.to_bech32().unwrap(), npub, "kind {} not authored by the identity", event.kind.as_u64());
+    }
+}
+
+#[test]
+fn count_is_not_capped_by_the_default_limit() {
+    let keys = Keys::generate();
+    let mut store = EventStore::in_memory();
+    for n in 0..600 {
+        let event = EventBuilder::new_text_note(format!("note {}", n), []).to_event(&keys).unwrap();
+        store.insert(event).unwrap();
+    }
+    let notes = Filter::new().kind(Kind::TextNote);
+    let first_ten = notes.clone().limit(10);
+    assert_eq!(store.count(std::slice::from_ref(&notes)), 600);
+    assert_eq!(store.count(std::slice::from_ref(&first_ten)), 600);
+    assert_eq!(store.query(&[notes]).len(), 500);
+    assert_eq!(store.query(&[first_ten]).len(), 10);
+}
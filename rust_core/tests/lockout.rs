@@ -0,0 +1,37 @@
+/**
+ * FILE: rust_core/tests/lockout.rs
+ * VERSION: 1.0.0
+ * PHASE: Phase 10.4 (Brute-Force Guard)
+ * PURPOSE: A legitimate device change must stay recoverable: the guard
+ * record keyed by the old binding never arms an erase, and a PIN plus
+ * recovery code re-binding clears it.
+ */
+#[allow(dead_code)]
+mod common;
+
+use common::*;
+use rust_core::api::*;
+
+const NEW_HW_ID: &str = "test-device-0002";
+
+#[test]
+fn device_change_is_recoverable_with_the_recovery_code() {
+    let dir = open_vault("lockout");
+    let storage_path = dir.to_string_lossy().into_owned();
+    let code = rust_generate_recovery_code(PIN.into()).unwrap();
+
+    // The device id changed: the old slot and the guard record's key no longer match.
+    for _ in 0..2 {
+        let _ = rust_unlock_vault(storage_path.clone(), NEW_HW_ID.into(), Some(PIN.into()), None, None);
+    }
+    let status = rust_get_unlock_status(storage_path.clone(), NEW_HW_ID.into()).unwrap();
+    assert!(status.retry_after_secs > 0);
+    assert_eq!(status.attempts_before_wipe, None, "no wipe limit was configured");
+
+    rust_rebind_device(PIN.into(), code, NEW_HW_ID.into(), storage_path.clone(), "device_id".into(), None).unwrap();
+    let status = rust_get_unlock_status(storage_path.clone(), NEW_HW_ID.into()).unwrap();
+    assert_eq!(status.failed_attempts, 0);
+    assert_eq!(status.retry_after_secs, 0);
+
+    rust_reset_vault(storage_path).unwrap();
+}
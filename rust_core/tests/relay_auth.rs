@@ -0,0 +1,66 @@
+/**
+ * FILE: rust_core/tests/relay_auth.rs
+ * VERSION: 1.0.0
+ * PHASE: Phase 11.19 (Relay Authentication)
+ * PURPOSE: Drives the API against an embedded relay that demands NIP-42
+ * AUTH: refused publishes land in the outbox and go out once an identity
+ * is approved to answer the challenge.
+ */
+#[allow(dead_code)]
+mod common;
+
+use common::*;
+use rust_core::api::*;
+use rust_core::history::HistoryQuery;
+use rust_core::relay_server::{EventStore, LocalRelay};
+use rust_core::relays::same_relay;
+
+#[test]
+fn queued_intents_go_out_once_the_relay_is_authenticated() {
+    let _dir = open_vault("relay_auth");
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let relay = runtime.block_on(LocalRelay::start_with_auth("127.0.0.1:0", EventStore::in_memory())).unwrap();
+    let url = relay.url();
+    use_only_relay(&url);
+    let identity = rust_create_identity("Shop".into()).unwrap();
+    let status = || rust_get_relay_status().unwrap().into_iter().find(|r| same_relay(&r.url, &url)).unwrap();
+    wait_for("AUTH challenge", || status().auth_requested);
+    assert_eq!(status().authenticated_as, None);
+
+    // Without an approved identity the relay refuses the event.
+    let signed = rust_sign_intent(identity.id.clone(), intent_url(42)).unwrap();
+    let result = rust_publish_intent(signed).unwrap();
+    assert!(!result.success);
+    assert!(result.queued);
+    let outbox = rust_get_outbox().unwrap();
+    assert_eq!(outbox.len(), 1);
+    assert_eq!(outbox[0].event_id, result.event_id);
+
+    // The approval answers the pending challenge and returns once the relay acknowledged it.
+    rust_set_relay_auth(url.clone(), Some(identity.id.clone())).unwrap();
+    assert_eq!(rust_list_relay_auth().unwrap().len(), 1);
+    assert_eq!(status().authenticated_as.as_deref(), Some(identity.id.as_str()));
+
+    // The periodic flush may get there first; either way the outbox drains.
+    rust_retry_outbox().unwrap();
+    assert!(rust_get_outbox().unwrap().is_empty());
+    let query = HistoryQuery { identity_ids: vec![identity.id.clone()], timeout_secs: Some(5), ..Default::default() };
+    let page = rust_query_interaction_history(query).unwrap();
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.items[0].event_id, result.event_id);
+    assert!(page.items[0].intent.is_verified);
+
+    // Withdrawing the approval drops the authenticated connection; the
+    // reconnect brings a new challenge that nobody answers, so the relay
+    // refuses the next event.
+    rust_set_relay_auth(url.clone(), None).unwrap();
+    assert!(rust_list_relay_auth().unwrap().is_empty());
+    assert_eq!(status().authenticated_as, None);
+    wait_for("a new AUTH challenge", || status().auth_requested);
+    let signed = rust_sign_intent(identity.id.clone(), intent_url(43)).unwrap();
+    let result = rust_publish_intent(signed).unwrap();
+    assert!(!result.success);
+    assert!(result.outcomes.iter().all(|o| o.message.starts_with("auth-required")), "{:?}", result.outcomes);
+    assert_eq!(status().authenticated_as, None);
+    relay.stop();
+}
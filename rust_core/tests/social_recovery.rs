@@ -0,0 +1,120 @@
+/**
+ * FILE: rust_core/tests/social_recovery.rs
+ * VERSION: 1.0.0
+ * PHASE: Phase 11.3 (Social Recovery)
+ * PURPOSE: Shamir splitting over GF(256) and m-of-n seed recovery: every
+ * threshold subset reconstructs, fewer shares do not, and a corrupted share
+ * is caught by the set checksum.
+ */
+use rust_core::recovery::{self, GuardianShare};
+use rust_core::shamir;
+
+const SEED: [u8; 32] = [
+    0x5a, 0x01, 0xc3, 0x7e, 0x90, 0x12, 0xff, 0x00, 0x44, 0x9d, 0x23, 0xb8, 0x6f, 0x0e, 0xa1, 0x37,
+    0xd4, 0x58, 0x2b, 0xe9, 0x71, 0x06, 0xcc, 0x3f, 0x80, 0x1a, 0x95, 0x62, 0xfe, 0x4d, 0x08, 0xb3,
+];
+
+/// All k-element subsets of `items`.
+fn subsets<T: Clone>(items: &[T], k: usize) -> Vec<Vec<T>> {
+    if k == 0 { return vec![Vec::new()]; }
+    if items.len() < k { return Vec::new(); }
+    let mut with_first = subsets(&items[1..], k - 1);
+    for subset in &mut with_first { subset.insert(0, items[0].clone()); }
+    with_first.extend(subsets(&items[1..], k));
+    with_first
+}
+
+fn corrupt(share: &GuardianShare) -> GuardianShare {
+    let mut data = hex::decode(&share.data).unwrap();
+    data[0] ^= 0x01;
+    GuardianShare { data: hex::encode(data), ..share.clone() }
+}
+
+#[test]
+fn every_threshold_subset_combines() {
+    for (threshold, count) in [(1, 1), (2, 3), (3, 5), (5, 5), (4, 7)] {
+        let shares = shamir::split(&SEED, threshold, count).unwrap();
+        assert_eq!(shares.len(), count as usize);
+        for size in threshold as usize..=count as usize {
+            for subset in subsets(&shares, size) {
+                assert_eq!(shamir::combine(&subset).unwrap(), SEED, "{}-of-{} with {} shares", threshold, count, size);
+            }
+        }
+    }
+}
+
+#[test]
+fn too_few_shares_do_not_combine() {
+    let shares = shamir::split(&SEED, 3, 5).unwrap();
+    for subset in subsets(&shares, 2) {
+        assert_ne!(shamir::combine(&subset).unwrap(), SEED);
+    }
+}
+
+#[test]
+fn split_rejects_bad_parameters_and_combine_bad_shares() {
+    assert!(shamir::split(&SEED, 0, 3).is_err());
+    assert!(shamir::split(&SEED, 4, 3).is_err());
+    assert!(shamir::split(&SEED, 2, 255).is_err());
+    let shares = shamir::split(&SEED, 2, 3).unwrap();
+    assert!(shamir::combine(&[]).is_err());
+    assert!(shamir::combine(&[shares[0].clone(), shares[0].clone()]).is_err());
+    assert!(shamir::combine(&[shares[0].clone(), (shares[1].0, vec![0u8; 3])]).is_err());
+}
+
+#[test]
+fn seed_recovers_from_every_threshold_subset() {
+    let shares = recovery::split_seed(&SEED, 3, 5).unwrap();
+    for size in 3..=5 {
+        for subset in subsets(&shares, size) {
+            assert_eq!(recovery::recover_seed(&subset).unwrap(), SEED);
+        }
+    }
+}
+
+#[test]
+fn seed_recovery_needs_the_threshold() {
+    let shares = recovery::split_seed(&SEED, 3, 5).unwrap();
+    for subset in subsets(&shares, 2) {
+        let error = recovery::recover_seed(&subset).unwrap_err().to_string();
+        assert_eq!(error, "Need 3 shares, have 2");
+    }
+    // The same share twice counts once.
+    let repeated = [shares[0].clone(), shares[1].clone(), shares[1].clone()];
+    assert!(recovery::recover_seed(&repeated).is_err());
+    assert!(recovery::recover_seed(&[]).is_err());
+    assert!(recovery::split_seed(&SEED, 1, 3).is_err());
+}
+
+#[test]
+fn corrupted_share_fails_the_checksum() {
+    let shares = recovery::split_seed(&SEED, 3, 5).unwrap();
+    let tampered = [corrupt(&shares[0]), shares[1].clone(), shares[2].clone()];
+    let error = recovery::recover_seed(&tampered).unwrap_err().to_string();
+    assert_eq!(error, "Share integrity check failed");
+
+    // With a surplus share the corrupted one is skipped.
+    let surplus = [corrupt(&shares[0]), shares[1].clone(), shares[2].clone(), shares[3].clone()];
+    assert_eq!(recovery::recover_seed(&surplus).unwrap(), SEED);
+}
+
+#[test]
+fn shares_of_different_sets_do_not_mix() {
+    let first = recovery::split_seed(&SEED, 2, 3).unwrap();
+    let second = recovery::split_seed(&SEED, 2, 3).unwrap();
+    assert!(recovery::recover_seed(&[first[0].clone(), second[1].clone()]).is_err());
+}
+
+#[test]
+fn sealed_shares_open_only_for_their_guardian() {
+    let owner = recovery::guardian_keys(&SEED).unwrap();
+    let guardian = recovery::guardian_keys(&[7u8; 32]).unwrap();
+    let stranger = recovery::guardian_keys(&[9u8; 32]).unwrap();
+    let share = recovery::split_seed(&SEED, 2, 2).unwrap().remove(0);
+
+    let envelope = recovery::seal_share(&share, &owner, &guardian.public_key()).unwrap();
+    assert!(envelope.starts_with(recovery::SHARE_PREFIX));
+    let opened = recovery::open_share(&envelope, &guardian).unwrap();
+    assert_eq!((opened.index, &opened.data, &opened.check), (share.index, &share.data, &share.check));
+    assert!(recovery::open_share(&envelope, &stranger).is_err());
+}